@@ -10,12 +10,34 @@ pub const ADMIN_PUBKEY: Pubkey = Pubkey::new_from_array([
     137, 65, 108, 219, 94, 164, 128, 211
 ]);
 
-// Rate limiting constants
+// Logic version stamped into events - increment on every release that changes behavior
+#[constant]
+pub const PROGRAM_VERSION: u16 = 1;
+
+// Rate limiting constants (defaults for Config on initialization)
 // pub const MIN_OFFER_AMOUNT: u64 = 10_000_000;     // 10 USDC minimum offer
 // pub const MIN_FILL_AMOUNT: u64 = 1_000_000;       // 1 USDC minimum fill
 pub const MAX_FILLS_PER_DAY: u16 = 70;            // Max fills per offer per day
-pub const FILL_COOLDOWN_SECS: i64 = 2;            // 5 sec for tests; raise in production
+pub const FILL_COOLDOWN_SECS: i64 = 2;            // 5 sec for tests; raise in production via set_fill_cooldown
 pub const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+pub const FIAT_SIGN_GRACE_SECS: i64 = SECONDS_PER_DAY; // default before CryptoGuy may time out an unsigned ticket
+pub const MAX_TICKET_EXTENSION_SECS: i64 = SECONDS_PER_DAY; // default cap per extend_ticket call
+pub const MIN_FILL_DENOMINATOR: u16 = 0;          // default: off (e.g. 100 via set_min_fill_denominator → tickets >= 1%)
+
+// Sanity cap on fiat_amount (minor units) - larger values indicate a client bug
+pub const MAX_FIAT_AMOUNT: u64 = 1_000_000_000_000_000; // 10^15
+
+// Distinct acceptors an order can record (capacity of UniversalOrder::acceptors; also the default cap)
+pub const MAX_ORDER_ACCEPTORS: usize = 4;
+
+// Takers a private order can whitelist (capacity of UniversalOrder::allowed_takers)
+pub const MAX_ALLOWED_TAKERS: usize = 4;
+
+// Max orders per create_orders_batch (3 PDAs + a vault init and transfer each - compute bound)
+pub const MAX_BATCH_ORDERS: usize = 4;
+
+// Max per-mint fee overrides stored in Config
+pub const MAX_MINT_FEE_OVERRIDES: usize = 8;
 
 // Universal: allow closing order when remaining is negligible (< 1 USDC)
 pub const ORDER_CLOSE_DUST: u64 = 1_000_000; // 1 USDC in base units
\ No newline at end of file
@@ -16,25 +16,66 @@ declare_id!("6u3xMGTW2fuebhGRSSPVP8QuzdAeu4gk9Jh4E24nJM4p");
 pub mod ddd {
     use super::*;
     
-    /// Accept offer and lock crypto (creates order + vault + ticket, locks tokens)
+    /// Accept offer and lock crypto (creates order + vault + ticket, locks tokens); returns the PDAs
     pub fn accept_offer_and_lock(
-        ctx: Context<AcceptOfferAndLock>,
+        mut ctx: Context<AcceptOfferAndLock>,
         order_id: u64,
         ticket_id: u64,
         crypto_amount: u64,
         fiat_amount: u64,
         is_sell_order: bool,
         creator: Pubkey,
-        fiat_guy: Pubkey,
+        options: OfferOptions,
+    ) -> Result<OfferPdas> {
+        accept_offer_and_lock::accept_offer_and_lock(&mut ctx, order_id, ticket_id, crypto_amount, fiat_amount, is_sell_order, creator, options)
+    }
+
+    /// Market-maker ladder: up to MAX_BATCH_ORDERS accept_offer_and_lock calls in one instruction
+    pub fn create_orders_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateOrdersBatch<'info>>,
+        orders: Vec<BatchOrder>,
+        ticket_id: u64,
+        is_sell_order: bool,
+        creator: Pubkey,
+        options: OfferOptions,
     ) -> Result<()> {
-        accept_offer_and_lock::accept_offer_and_lock(ctx, order_id, ticket_id, crypto_amount, fiat_amount, is_sell_order, creator, fiat_guy)
+        create_orders_batch::create_orders_batch(ctx, orders, ticket_id, is_sell_order, creator, options)
+    }
+
+    /// BUY fast path: accept_offer_and_lock + the FiatGuy's (creator's) first signature in one instruction
+    pub fn accept_offer_and_sign(
+        ctx: Context<AcceptOfferAndLock>,
+        order_id: u64,
+        ticket_id: u64,
+        crypto_amount: u64,
+        fiat_amount: u64,
+        is_sell_order: bool,
+        creator: Pubkey,
+    ) -> Result<OfferPdas> {
+        accept_offer_and_lock::accept_offer_and_sign(ctx, order_id, ticket_id, crypto_amount, fiat_amount, is_sell_order, creator)
     }
 
     /// Sign a specific ticket; settles on second signature; auto-closes on completion
     pub fn sign_universal_ticket(
         ctx: Context<SignTicket>,
+        client_nonce: Option<u64>,
+        create_receipt: bool,
+    ) -> Result<()> {
+        sign_ticket(ctx, client_nonce, create_receipt)
+    }
+
+    /// Record FiatGuy's first signature from an off-chain ed25519 authorization (admin submits)
+    pub fn sign_ticket_with_ed25519(
+        ctx: Context<SignTicketWithEd25519>,
     ) -> Result<()> {
-        sign_ticket(ctx)
+        universal::instructions::sign_ticket_with_ed25519(ctx)
+    }
+
+    /// Withdraw a signature while the other party hasn't signed yet
+    pub fn unsign_ticket(
+        ctx: Context<UnsignTicket>,
+    ) -> Result<()> {
+        universal::instructions::unsign_ticket(ctx)
     }
 
     /// Cancel a ticket (FiatGuy only, before signing); refunds to CryptoGuy; auto-closes order
@@ -44,12 +85,314 @@ pub mod ddd {
         cancel_ticket(ctx)
     }
 
+    /// Withdraw a BUY-order ticket (CryptoGuy only, before FiatGuy signs); refunds to CryptoGuy; auto-closes order
+    pub fn withdraw_universal_ticket(
+        ctx: Context<WithdrawTicket>,
+    ) -> Result<()> {
+        withdraw_ticket(ctx)
+    }
+
+    /// CryptoGuy reclaims a ticket FiatGuy never signed once config.fiat_sign_grace_secs has passed; auto-closes order
+    pub fn sign_or_timeout(
+        ctx: Context<SignOrTimeout>,
+    ) -> Result<()> {
+        universal::instructions::sign_or_timeout(ctx)
+    }
+
+    /// FiatGuy (or admin) pushes an unexpired, unsigned ticket's expiry out, capped by config.max_ticket_extension_secs
+    pub fn extend_ticket(
+        ctx: Context<ExtendTicket>,
+        new_expires_at: i64,
+    ) -> Result<()> {
+        universal::instructions::extend_ticket(ctx, new_expires_at)
+    }
+
+    /// FiatGuy chooses to receive a WSOL payout as native SOL (applied by sign_universal_ticket)
+    pub fn set_unwrap_to_sol(
+        ctx: Context<SetUnwrapToSol>,
+        unwrap_to_sol: bool,
+    ) -> Result<()> {
+        universal::instructions::set_unwrap_to_sol(ctx, unwrap_to_sol)
+    }
+
+    /// Creator of a SELL order cancels every unsigned ticket in remaining_accounts; refunds to creator; auto-closes order
+    pub fn cancel_all_unsigned<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelAllUnsigned<'info>>,
+        ticket_count: u8,
+    ) -> Result<()> {
+        cancel_all_unsigned::cancel_all_unsigned(ctx, ticket_count)
+    }
+
     /// Admin resolve specific ticket - force settle to fiat or refund to crypto
     pub fn admin_resolve_universal_ticket(
         ctx: Context<AdminResolveTicket>,
         release_to_fiat_guy: bool,
+        reason_code: u8,
+    ) -> Result<()> {
+        admin_resolve_ticket(ctx, release_to_fiat_guy, reason_code)
+    }
+
+    /// Compliance variant of admin_resolve_universal_ticket: pay/refund to an admin-chosen token account (mint-checked only)
+    pub fn admin_resolve_ticket_to(
+        ctx: Context<AdminResolveTicket>,
+        destination: Pubkey,
+        release_to_fiat_guy: bool,
+        reason_code: u8,
+    ) -> Result<()> {
+        admin_resolve_ticket::admin_resolve_ticket_to(ctx, destination, release_to_fiat_guy, reason_code)
+    }
+
+    /// Admin split resolve - settle part of a ticket to fiat, refund the rest to crypto
+    pub fn admin_split_resolve_universal_ticket(
+        ctx: Context<AdminResolveTicket>,
+        settle_amount: u64,
+        reason_code: u8,
+    ) -> Result<()> {
+        admin_split_resolve_ticket(ctx, settle_amount, reason_code)
+    }
+
+    /// Admin three-way resolve - part to fiat, part refunded to crypto, penalty to the treasury
+    pub fn admin_three_way_resolve(
+        ctx: Context<AdminResolveTicket>,
+        to_fiat: u64,
+        to_crypto: u64,
+        penalty: u64,
+        reason_code: u8,
+    ) -> Result<()> {
+        admin_three_way_resolve::admin_three_way_resolve(ctx, to_fiat, to_crypto, penalty, reason_code)
+    }
+
+    /// Reassign an unsigned SELL-order ticket to a new FiatGuy (admin only)
+    pub fn reassign_universal_ticket(
+        ctx: Context<ReassignTicket>,
+        new_acceptor: Pubkey,
+    ) -> Result<()> {
+        reassign_ticket(ctx, new_acceptor)
+    }
+
+    /// Split an unsigned ticket into `keep_amount` + a new change ticket (admin only)
+    pub fn split_universal_ticket(
+        ctx: Context<SplitTicket>,
+        keep_amount: u64,
+        new_ticket_id: u64,
+    ) -> Result<()> {
+        split_ticket(ctx, keep_amount, new_ticket_id)
+    }
+
+    /// Reset reserved_amount to the sum of the open tickets in remaining_accounts (admin only)
+    pub fn reconcile_reserved(
+        ctx: Context<ReconcileReserved>,
+        ticket_count: u8,
+    ) -> Result<()> {
+        reconcile_reserved::reconcile_reserved(ctx, ticket_count)
+    }
+
+    /// Close an order whose vault account no longer exists (admin only)
+    pub fn admin_force_close_order(
+        ctx: Context<AdminForceCloseOrder>,
+    ) -> Result<()> {
+        admin_force_close_order::admin_force_close_order(ctx)
+    }
+
+    /// Close a settlement receipt; rent to config.rent_receiver (admin only)
+    pub fn close_receipt(
+        ctx: Context<CloseReceipt>,
+    ) -> Result<()> {
+        close_receipt::close_receipt(ctx)
+    }
+
+    /// Close a fully settled order left open with an empty vault; rent to config.rent_receiver (anyone)
+    pub fn reclaim_rent(
+        ctx: Context<ReclaimRent>,
     ) -> Result<()> {
-        admin_resolve_ticket(ctx, release_to_fiat_guy)
+        reclaim_rent::reclaim_rent(ctx)
+    }
+
+    /// Move tokens sent directly to a vault (beyond reserved_amount) to a recipient account (admin only)
+    pub fn admin_recover_excess(
+        ctx: Context<AdminRecoverExcess>,
+    ) -> Result<()> {
+        admin_recover_excess::admin_recover_excess(ctx)
+    }
+
+    /// Create the global Config PDA (admin only, defaults from constants)
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+    ) -> Result<()> {
+        initialize_config::initialize_config(ctx)
+    }
+
+    /// Update the minimum interval between ticket signatures (admin only)
+    pub fn set_fill_cooldown(
+        ctx: Context<UpdateConfig>,
+        fill_cooldown_secs: i64,
+    ) -> Result<()> {
+        update_config::set_fill_cooldown(ctx, fill_cooldown_secs)
+    }
+
+    /// Update the max fills per order per day window (admin only)
+    pub fn set_max_fills_per_day(
+        ctx: Context<UpdateConfig>,
+        max_fills_per_day: u16,
+    ) -> Result<()> {
+        update_config::set_max_fills_per_day(ctx, max_fills_per_day)
+    }
+
+    /// Toggle canonical-ATA enforcement for locker token accounts (admin only)
+    pub fn set_require_ata(
+        ctx: Context<UpdateConfig>,
+        require_ata: bool,
+    ) -> Result<()> {
+        update_config::set_require_ata(ctx, require_ata)
+    }
+
+    /// Toggle the creator co-signature requirement for BUY orders (admin only)
+    pub fn set_require_creator_sig(
+        ctx: Context<UpdateConfig>,
+        require_creator_sig: bool,
+    ) -> Result<()> {
+        update_config::set_require_creator_sig(ctx, require_creator_sig)
+    }
+
+    /// Set the wallet that receives rent when tickets/vaults/orders auto-close (admin only)
+    pub fn set_rent_receiver(
+        ctx: Context<UpdateConfig>,
+        rent_receiver: Pubkey,
+    ) -> Result<()> {
+        update_config::set_rent_receiver(ctx, rent_receiver)
+    }
+
+    /// Require Token-2022 fee accounts to carry the ImmutableOwner extension (admin only)
+    pub fn set_require_immutable_fee_owner(
+        ctx: Context<UpdateConfig>,
+        require_immutable_fee_owner: bool,
+    ) -> Result<()> {
+        update_config::set_require_immutable_fee_owner(ctx, require_immutable_fee_owner)
+    }
+
+    /// Set how long FiatGuy has to sign before CryptoGuy may time a ticket out (admin only)
+    pub fn set_fiat_sign_grace(
+        ctx: Context<UpdateConfig>,
+        fiat_sign_grace_secs: i64,
+    ) -> Result<()> {
+        update_config::set_fiat_sign_grace(ctx, fiat_sign_grace_secs)
+    }
+
+    /// Set how far a single extend_ticket call may push a ticket's expiry (admin only)
+    pub fn set_max_ticket_extension(
+        ctx: Context<UpdateConfig>,
+        max_ticket_extension_secs: i64,
+    ) -> Result<()> {
+        update_config::set_max_ticket_extension(ctx, max_ticket_extension_secs)
+    }
+
+    /// Set the minimum ticket size as a fraction of its order, 0 = off (admin only)
+    pub fn set_min_fill_denominator(
+        ctx: Context<UpdateConfig>,
+        min_fill_denominator: u16,
+    ) -> Result<()> {
+        update_config::set_min_fill_denominator(ctx, min_fill_denominator)
+    }
+
+    /// Set the max distinct acceptors per order (admin only)
+    pub fn set_max_distinct_acceptors(
+        ctx: Context<UpdateConfig>,
+        max_distinct_acceptors: u8,
+    ) -> Result<()> {
+        update_config::set_max_distinct_acceptors(ctx, max_distinct_acceptors)
+    }
+
+    /// Set the minimum fiat_amount a new offer must carry, 0 = off (admin only)
+    pub fn set_min_fiat_amount(
+        ctx: Context<UpdateConfig>,
+        min_fiat_amount: u64,
+    ) -> Result<()> {
+        update_config::set_min_fiat_amount(ctx, min_fiat_amount)
+    }
+
+    /// Make order consistency warnings fail the instruction (admin only)
+    pub fn set_strict_invariants(
+        ctx: Context<UpdateConfig>,
+        strict_invariants: bool,
+    ) -> Result<()> {
+        update_config::set_strict_invariants(ctx, strict_invariants)
+    }
+
+    /// Set the default fee floor for mints without an override (admin only)
+    pub fn set_min_fee(
+        ctx: Context<UpdateConfig>,
+        min_fee: u64,
+    ) -> Result<()> {
+        update_config::set_min_fee(ctx, min_fee)
+    }
+
+    /// Set a per-mint fee bps and fee floor override (admin only)
+    pub fn set_mint_fee(
+        ctx: Context<UpdateMintFee>,
+        fee_bps: u16,
+        min_fee: u64,
+    ) -> Result<()> {
+        update_config::set_mint_fee(ctx, fee_bps, min_fee)
+    }
+
+    /// Remove a per-mint fee bps override (admin only)
+    pub fn remove_mint_fee(
+        ctx: Context<UpdateConfig>,
+        mint: Pubkey,
+    ) -> Result<()> {
+        update_config::remove_mint_fee(ctx, mint)
+    }
+
+    /// Create the per-mint protocol fee vault (admin only)
+    pub fn initialize_protocol_fee_vault(
+        ctx: Context<InitializeProtocolFeeVault>,
+    ) -> Result<()> {
+        initialize_protocol_fee_vault::initialize_protocol_fee_vault(ctx)
+    }
+
+    /// Sweep residual vault dust of an idle order into the protocol fee vault, then close it (admin only)
+    pub fn sweep_fees(
+        ctx: Context<SweepFees>,
+    ) -> Result<()> {
+        sweep_fees::sweep_fees(ctx)
+    }
+
+    /// Quote (fee_amount, net_amount) for a settlement of `amount` in `mint` (read-only, via return data)
+    pub fn preview_settlement(
+        ctx: Context<PreviewSettlement>,
+        amount: u64,
+        mint: Pubkey,
+    ) -> Result<SettlementPreview> {
+        preview_settlement::preview_settlement(ctx, amount, mint)
+    }
+
+    /// Remaining settlements an order can take today under config.max_fills_per_day (read-only, via return data)
+    pub fn fill_budget(
+        ctx: Context<FillBudgetView>,
+    ) -> Result<FillBudget> {
+        fill_budget::fill_budget(ctx)
+    }
+
+    /// Rent returned when the order closes out: order + vault + ticket (read-only, via return data)
+    pub fn rent_estimate(
+        ctx: Context<RentEstimateView>,
+    ) -> Result<RentEstimate> {
+        rent_estimate::rent_estimate(ctx)
+    }
+
+    /// Snapshot an order plus the tickets in remaining_accounts (read-only, via return data)
+    pub fn get_order_with_tickets(
+        ctx: Context<GetOrderWithTickets>,
+        ticket_count: u8,
+    ) -> Result<OrderWithTickets> {
+        get_order_with_tickets::get_order_with_tickets(ctx, ticket_count)
+    }
+
+    /// Monitoring no-op: admin, default fee bps and program version (read-only, via return data)
+    pub fn health_check(
+        ctx: Context<HealthCheck>,
+    ) -> Result<HealthStatus> {
+        health_check::health_check(ctx)
     }
 }
 
@@ -43,4 +43,115 @@ pub enum UniversalOrderError {
     
     #[msg("Token account required for this operation")]
     TokenAccountRequired,
+    
+    #[msg("Action too frequent, please wait")]
+    ActionTooFrequent,
+    
+    #[msg("Order is already being processed - reentrant call rejected")]
+    Reentrancy,
+    
+    #[msg("Ticket already signed - cannot be modified")]
+    TicketAlreadySigned,
+    
+    #[msg("Order still has active tickets")]
+    OrderHasActiveTickets,
+    
+    #[msg("Unexpected number of remaining accounts")]
+    UnexpectedAccounts,
+    
+    #[msg("Vault authority is not the order PDA")]
+    InvalidVaultAuthority,
+    
+    #[msg("Ticket passed more than once")]
+    DuplicateTicket,
+    
+    #[msg("Per-mint fee table is full")]
+    MintFeeTableFull,
+    
+    #[msg("Order creator must sign order creation")]
+    CreatorSignatureRequired,
+    
+    #[msg("Token program does not own the mint")]
+    InvalidTokenProgram,
+    
+    #[msg("Vault account still exists")]
+    VaultStillOpen,
+    
+    #[msg("Mint does not match the order's crypto mint")]
+    InvalidMint,
+    
+    #[msg("Acceptor must be a system-owned wallet that can sign")]
+    AcceptorNotSignable,
+    
+    #[msg("FiatGuy signing grace period has not elapsed")]
+    SignGracePeriodActive,
+    
+    #[msg("Fee account owner is not immutable")]
+    FeeAccountOwnerMutable,
+    
+    #[msg("Missing or malformed ed25519 signature instruction")]
+    InvalidEd25519Instruction,
+    
+    #[msg("Ticket has already expired")]
+    TicketExpired,
+    
+    #[msg("Extension exceeds config.max_ticket_extension_secs")]
+    ExtensionTooLong,
+    
+    #[msg("Fill is below the minimum fraction of the order")]
+    FillTooSmall,
+    
+    #[msg("Unknown resolution reason code")]
+    InvalidReasonCode,
+    
+    #[msg("Batch must contain between 1 and MAX_BATCH_ORDERS orders")]
+    InvalidBatchSize,
+    
+    #[msg("Order id appears more than once in the batch")]
+    DuplicateOrder,
+    
+    #[msg("Unwrapping to SOL requires the payout wallet, unwrap account and system program")]
+    UnwrapAccountsRequired,
+    
+    #[msg("Order already has the maximum number of distinct acceptors")]
+    TooManyAcceptors,
+    
+    #[msg("Vault balance after locking doesn't match the locked amount (transfer-fee mint?)")]
+    LockAmountMismatch,
+    
+    #[msg("Signer hasn't signed this ticket")]
+    NotSigned,
+    
+    #[msg("Fiat amount is below config.min_fiat_amount")]
+    NotionalTooSmall,
+    
+    #[msg("Order counters are inconsistent (config.strict_invariants)")]
+    InvariantViolation,
+    
+    #[msg("Order still has an amount left to fill")]
+    OrderNotComplete,
+    
+    #[msg("Vault still holds tokens (use sweep_fees for dust)")]
+    VaultNotEmpty,
+    
+    #[msg("Request already processed (same client nonce) - treat as success")]
+    DuplicateRequest,
+    
+    #[msg("Ticket amount exceeds the order's crypto_amount")]
+    TicketExceedsOrder,
+    
+    #[msg("create_receipt needs the receipt account and the system program")]
+    ReceiptAccountRequired,
+    
+    #[msg("creator_signer is for BUY orders only (SELL creators sign as locker)")]
+    UnexpectedCreatorSigner,
+    
+    #[msg("Too many allowed takers")]
+    TooManyAllowedTakers,
+    
+    #[msg("Invalid argument")]
+    InvalidArgument,
+    
+    #[msg("New expiry must be later than the current one")]
+    ExtensionNotLater,
 }
\ No newline at end of file
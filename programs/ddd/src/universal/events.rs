@@ -14,6 +14,7 @@ pub struct OfferAccepted {
     pub is_sell_order: bool,
     pub crypto_amount: u64,
     pub fiat_amount: u64,
+    pub crypto_first: bool,      // true = CryptoGuy must sign first
     
     // Ticket info
     pub ticket: Pubkey,
@@ -23,8 +24,12 @@ pub struct OfferAccepted {
     // Parties
     pub crypto_guy: Pubkey,      // Who locks tokens
     pub fiat_guy: Pubkey,        // Who pays fiat
+    pub locker: Pubkey,          // Signer who locked tokens (always CryptoGuy)
+    pub acceptor: Pubkey,        // Who accepted the offer (ticket.acceptor)
     
+    pub version: u16,            // PROGRAM_VERSION that emitted this event
     pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
 }
 
 #[event]
@@ -35,6 +40,39 @@ pub struct TicketSigned {
     pub is_crypto_guy: bool,
     pub is_fiat_guy: bool,
     pub both_signed: bool,
+    pub filled_amount: u64,   // Order progress at signing time (before this ticket settles)
+    pub reserved_amount: u64,
+    pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
+}
+
+#[event]
+pub struct TicketUnsigned {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub signer: Pubkey,
+    pub is_crypto_guy: bool,
+    pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
+}
+
+#[event]
+pub struct TicketReassigned {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub old_acceptor: Pubkey,
+    pub new_acceptor: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketSplit {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub new_ticket: Pubkey,
+    pub keep_amount: u64,
+    pub change_amount: u64,
     pub timestamp: i64,
 }
 
@@ -46,9 +84,15 @@ pub struct TicketSettled {
     pub fee_amount: u64,      // Fee amount (0.25%)
     pub net_amount: u64,      // Net to fiat_guy (99.75%)
     pub fiat_guy: Pubkey,
+    pub payout_destination: Pubkey, // Owner of the account that received net_amount
+    pub fee_destination: Pubkey, // Owner of the account that received fee_amount (admin unless set on the order)
+    pub unwrapped: bool,      // net_amount delivered as native SOL to payout_destination
     pub crypto_guy: Pubkey,
     pub total_filled: u64,
+    pub reference: [u8; 32],  // Ticket's off-chain payment reference
+    pub version: u16,         // PROGRAM_VERSION that emitted this event
     pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
 }
 
 #[event]
@@ -58,7 +102,9 @@ pub struct TicketCancelled {
     pub canceller: Pubkey,
     pub amount: u64,
     pub refunded: bool, // true if Buy order refund happened
+    pub reference: [u8; 32], // Ticket's off-chain payment reference
     pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
 }
 
 #[event]
@@ -69,6 +115,7 @@ pub struct OrderCancelled {
     pub is_sell_order: bool,
     pub remaining_after: u64,
     pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
 }
 
 #[event]
@@ -76,10 +123,42 @@ pub struct OrderClosed {
     pub order: Pubkey,
     pub creator: Pubkey,
     pub dust_amount: u64,
+    pub total_fees_collected: u64,
+    pub settle_count: u16,
+    pub refund_count: u16,
     pub rent_returned_to: Pubkey,
     pub timestamp: i64,
 }
 
+#[event]
+pub struct ReservedReconciled {
+    pub order: Pubkey,
+    pub old_reserved: u64,
+    pub new_reserved: u64,
+    pub ticket_count: u8,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FeesSwept {
+    pub order: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub protocol_fee_vault: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExcessRecovered {
+    pub order: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub admin: Pubkey,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct UniversalAdminResolved {
     pub order: Pubkey,
@@ -87,6 +166,50 @@ pub struct UniversalAdminResolved {
     pub admin: Pubkey,
     pub amount: u64,
     pub recipient: Pubkey,
-    pub resolution_type: String, // "order_refund", "ticket_settle", "ticket_refund"
+    pub resolution_type: String, // "order_refund", "ticket_settle", "ticket_refund" (+ "_to" when redirected), "ticket_split", "ticket_three_way"
+    pub reason_code: u8,         // ResolutionReason
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TicketSplitResolved {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub settle_amount: u64,   // Settled part of the ticket (fee included)
+    pub fee_amount: u64,      // Fee on the settled part, paid to the order's fee owner
+    pub net_amount: u64,      // Paid to FiatGuy
+    pub refund_amount: u64,   // Refunded to CryptoGuy (prepaid fee share included)
+    pub reason_code: u8,      // ResolutionReason
+    pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
+}
+
+#[event]
+pub struct TicketThreeWayResolved {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub to_fiat: u64,         // Paid to FiatGuy
+    pub to_crypto: u64,       // Refunded to CryptoGuy (excluding the prepaid fee)
+    pub prepaid_fee_refunded: u64, // Fee-on-top prepaid fee returned with to_crypto
+    pub penalty: u64,         // Paid to the order's fee owner
+    pub reason_code: u8,      // ResolutionReason
+    pub timestamp: i64,
+    pub seq: u64,            // Program-wide event sequence (Config.event_seq)
+}
+
+#[event]
+pub struct TicketExtended {
+    pub order: Pubkey,
+    pub ticket: Pubkey,
+    pub old_expires_at: i64,
+    pub new_expires_at: i64,
+    pub extended_by: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RateWindowReset {
+    pub order: Pubkey,
+    pub previous_count: u16, // settlements in the lapsed daily window
     pub timestamp: i64,
-}
\ No newline at end of file
+}
@@ -1,12 +1,44 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
-use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::associated_token::{AssociatedToken, get_associated_token_address_with_program_id};
 use crate::universal::state::*;
 use crate::universal::errors::UniversalOrderError;
-use crate::universal::events::OfferAccepted;
+use crate::universal::events::{OfferAccepted, TicketSigned};
+use crate::universal::utils::validate::{ticket_roles, offer_acceptor};
+use crate::universal::utils::fees::calculate_fee;
+use crate::universal::utils::guard::debug_assert_consistency;
+use crate::constants::MAX_ORDER_ACCEPTORS;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Addresses created by accept_offer_and_lock, returned via return data
+/// so CPI callers don't need to re-derive the PDAs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct OfferPdas {
+    pub order: Pubkey,
+    pub vault: Pubkey,
+    pub ticket: Pubkey,
+}
+
+/// Per-offer settings of accept_offer_and_lock beyond the order terms; `Default` is an open,
+/// fee-inclusive, fiat-first order with no overrides (see accept_offer_and_lock for each field)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Eq)]
+pub struct OfferOptions {
+    /// SELL orders: the accepting FiatGuy (ignored for BUY, where the creator is the FiatGuy)
+    pub fiat_guy: Pubkey,
+    pub crypto_first: bool,
+    pub vault_authority: Option<Pubkey>,
+    pub payout_destination: Option<Pubkey>,
+    pub reference: [u8; 32],
+    pub fee_destination: Option<Pubkey>,
+    pub fee_on_top: bool,
+    pub crypto_guy_signer: Option<Pubkey>,
+    pub fiat_guy_signer: Option<Pubkey>,
+    pub allowed_takers: Vec<Pubkey>,
+}
 
 /// Accept an offer from DB and lock crypto for the first ticket
 /// This replaces the old create_order + lock_crypto_for_ticket flow
+/// Everything past the order terms comes in `options` (OfferOptions)
 /// 
 /// Flow:
 /// 1. Creates order PDA with offer details
@@ -14,72 +46,132 @@ use crate::universal::events::OfferAccepted;
 /// 3. Creates first ticket PDA
 /// 4. CryptoGuy locks tokens into vault
 /// 5. Emits OfferAccepted event
-pub fn accept_offer_and_lock(
-    ctx: Context<AcceptOfferAndLock>,
+/// 6. Returns the order/vault/ticket addresses (OfferPdas) as return data
+///
+/// `vault_authority` defaults to the order PDA. A custom authority (e.g. another program's PDA
+/// for custody overlays) would need that program to co-sign every settlement, so for now
+/// anything other than the order PDA is rejected (InvalidVaultAuthority).
+///
+/// `payout_destination` (optional) is the wallet that must own the FiatGuy payout token account
+/// on settlement, e.g. a custodial account; defaults to the FiatGuy.
+///
+/// `fee_destination` (optional) is the wallet that must own the fee token account on settlement,
+/// for orders with a negotiated fee recipient (e.g. a rebate pool); defaults to the admin.
+///
+/// `fee_on_top` = true makes the locker prepay the fee: the vault takes
+/// `crypto_amount + calculate_fee(crypto_amount).0` (fee fixed at today's config), and settlement
+/// delivers the full `crypto_amount` to the FiatGuy. Refunds return the prepaid fee too.
+///
+/// `fiat_amount` must be at least `config.min_fiat_amount` (NotionalTooSmall). The program can't
+/// verify fiat, so this floor is advisory: it only filters what the client reports.
+///
+/// `crypto_guy_signer` / `fiat_guy_signer` (optional) delegate signing for a party to another key
+/// (e.g. an institution's operations key): when set, that key signs the ticket instead of the fund
+/// wallet, while funds still move to/from the fund wallets.
+///
+/// `allowed_takers` (up to MAX_ALLOWED_TAKERS, empty = open to anyone) makes the order private:
+/// only those wallets can be a ticket's acceptor, here and on reassign_ticket (Unauthorized).
+///
+/// `reference` is an opaque 32-byte payment-processor reference (e.g. a hash) stored on the
+/// ticket and echoed in TicketSettled/TicketCancelled for dispute evidence; zeroes = none.
+///
+/// `ticket_id` must be > 0. It is a client-assigned reference only: the ticket PDA is seeded by
+/// its sequential index (always 0 for the first ticket), so tickets can be enumerated by
+/// deriving [TICKET_SEED, order, 0..order.ticket_index]. Ids are not tracked after close:
+/// once a ticket (and its order) auto-closes, the same order_id may be reused and will
+/// `init` fresh PDAs at the same addresses. This is permitted by design.
+pub(crate) fn accept_offer_and_lock(
+    ctx: &mut Context<AcceptOfferAndLock>,
     order_id: u64,
     ticket_id: u64,
     crypto_amount: u64,
     fiat_amount: u64,
     is_sell_order: bool,
     creator: Pubkey,
-    fiat_guy: Pubkey,
-) -> Result<()> {
-    let order = &mut ctx.accounts.order;
-    let ticket = &mut ctx.accounts.ticket;
-    let locker = &ctx.accounts.locker;
+    options: OfferOptions,
+) -> Result<OfferPdas> {
+    let OfferOptions {
+        fiat_guy,
+        crypto_first,
+        vault_authority,
+        payout_destination,
+        reference,
+        fee_destination,
+        fee_on_top,
+        crypto_guy_signer,
+        fiat_guy_signer,
+        allowed_takers,
+    } = options;
     let clock = Clock::get()?;
+    let pdas = OfferPdas {
+        order: ctx.accounts.order.key(),
+        vault: ctx.accounts.vault.key(),
+        ticket: ctx.accounts.ticket.key(),
+    };
 
-    // Validate amounts
-    require!(crypto_amount > 0, UniversalOrderError::InvalidAmount);
-    require!(fiat_amount > 0, UniversalOrderError::InvalidAmount);
-    require!(ticket_id > 0, UniversalOrderError::InvalidAmount);
+    // CHECK: Settlement signs vault CPIs with the order seeds, so only the order PDA works for now
+    let vault_authority = vault_authority.unwrap_or(pdas.order);
+    require_keys_eq!(vault_authority, pdas.order, UniversalOrderError::InvalidVaultAuthority);
 
-    // CryptoGuy is always the one who locks tokens
-    let crypto_guy = locker.key();
-    
-    // Determine actual fiat_guy based on order type
-    let actual_fiat_guy = if is_sell_order {
-        fiat_guy    // SELL: fiat_guy parameter is the buyer
-    } else {
-        creator     // BUY: creator is the buyer (FiatGuy)
-    };
+    validate_locker_ata(
+        &ctx.accounts.config,
+        ctx.accounts.locker.key(),
+        ctx.accounts.locker_token_account.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.token_program.key(),
+    )?;
+    let parties = validate_offer_parties(
+        &ctx.accounts.config,
+        &ctx.accounts.locker.to_account_info(),
+        ctx.accounts.creator_signer.as_ref().map(|s| s.key()),
+        is_sell_order,
+        creator,
+        fiat_guy,
+    )?;
 
-    // CHECK: CryptoGuy cannot lock for themselves
-    require!(crypto_guy != actual_fiat_guy, UniversalOrderError::Unauthorized);
+    validate_delegates(crypto_guy_signer, fiat_guy_signer, &parties)?;
 
-    // CHECK: For SELL orders, locker must be creator
-    // For BUY orders, locker must NOT be creator
-    if is_sell_order {
-        require!(crypto_guy == creator, UniversalOrderError::Unauthorized);
-    } else {
-        require!(crypto_guy != creator, UniversalOrderError::Unauthorized);
-    }
+    // CHECK: Notional floor (advisory: fiat_amount is client-reported reference data)
+    require!(fiat_amount >= ctx.accounts.config.min_fiat_amount, UniversalOrderError::NotionalTooSmall);
 
-    // Initialize order
-    order.creator = creator;
-    order.crypto_mint = ctx.accounts.mint.key();
-    order.crypto_amount = crypto_amount;
-    order.fiat_amount = fiat_amount;
-    order.is_sell_order = is_sell_order;
-    order.filled_amount = 0;
-    order.reserved_amount = crypto_amount; // First ticket reserves full amount
-    order.order_id = order_id;
-    order.created_at = clock.unix_timestamp;
-    order.updated_at = clock.unix_timestamp;
-    order.bump = ctx.bumps.order;
+    let (prepaid_fee, locked_amount) = offer_locked_amount(
+        &ctx.accounts.config,
+        &ctx.accounts.mint.key(),
+        crypto_amount,
+        fee_on_top,
+    )?;
 
-    // Initialize ticket
-    ticket.order = order.key();
-    // Acceptor is the one who accepts the offer:
-    // SELL: acceptor = FiatGuy (buyer accepts seller's offer)
-    // BUY: acceptor = CryptoGuy (seller accepts buyer's offer)
-    ticket.acceptor = if is_sell_order { actual_fiat_guy } else { crypto_guy };
-    ticket.amount = crypto_amount;
-    ticket.crypto_guy_signed = false;
-    ticket.fiat_guy_signed = false;
-    ticket.ticket_id = ticket_id;
-    ticket.created_at = clock.unix_timestamp;
-    ticket.bump = ctx.bumps.ticket;
+    // CHECK: Locker can fund the amount plus any prepaid fee
+    require!(ctx.accounts.locker_token_account.amount >= locked_amount, UniversalOrderError::InsufficientBalance);
+
+    let args = OfferArgs {
+        order_id,
+        ticket_id,
+        crypto_amount,
+        fiat_amount,
+        is_sell_order,
+        creator,
+        crypto_mint: ctx.accounts.mint.key(),
+        crypto_first,
+        vault_authority,
+        payout_destination,
+        reference,
+        fee_destination,
+        fee_on_top,
+        prepaid_fee,
+        crypto_guy_signer,
+        fiat_guy_signer,
+        allowed_takers,
+    };
+    let bumps = OfferBumps {
+        order: ctx.bumps.order,
+        vault: ctx.bumps.vault,
+        ticket: ctx.bumps.ticket,
+    };
+    init_offer(&mut ctx.accounts.order, &mut ctx.accounts.ticket, &pdas, &args, &parties, &bumps, clock.unix_timestamp)?;
+
+    // CHECK: Vault holds the order's mint (re-asserted, not just implied by `token::mint` at init)
+    require_keys_eq!(ctx.accounts.vault.mint, args.crypto_mint, UniversalOrderError::InvalidTokenAccount);
 
     // Transfer tokens from CryptoGuy to vault
     let transfer_ctx = CpiContext::new(
@@ -87,31 +179,315 @@ pub fn accept_offer_and_lock(
         TransferChecked {
             from: ctx.accounts.locker_token_account.to_account_info(),
             to: ctx.accounts.vault.to_account_info(),
-            authority: locker.to_account_info(),
+            authority: ctx.accounts.locker.to_account_info(),
             mint: ctx.accounts.mint.to_account_info(),
         },
     );
-    transfer_checked(transfer_ctx, crypto_amount, ctx.accounts.mint.decimals)?;
+    transfer_checked(transfer_ctx, locked_amount, ctx.accounts.mint.decimals)?;
+
+    // CHECK: Reservations never exceed the order, and the vault holds exactly what it accounts for
+    // (a transfer-fee mint would deliver less than was sent and strand the last settlement)
+    require!(
+        ctx.accounts.order.reserved_amount <= ctx.accounts.order.crypto_amount,
+        UniversalOrderError::InvalidAmount
+    );
+    ctx.accounts.vault.reload()?;
+    require!(ctx.accounts.vault.amount == locked_amount, UniversalOrderError::LockAmountMismatch);
+    debug_assert_consistency(&ctx.accounts.order, ctx.accounts.config.strict_invariants)?;
+
+    let seq = ctx.accounts.config.next_event_seq();
+    emit_offer_accepted(&ctx.accounts.order, &ctx.accounts.ticket, &pdas, &parties, seq, clock.unix_timestamp);
+
+    Ok(pdas)
+}
+
+/// Offer arguments shared by accept_offer_and_lock and create_orders_batch
+pub(crate) struct OfferArgs {
+    pub order_id: u64,
+    pub ticket_id: u64,
+    pub crypto_amount: u64,
+    pub fiat_amount: u64,
+    pub is_sell_order: bool,
+    pub creator: Pubkey,
+    pub crypto_mint: Pubkey,
+    pub crypto_first: bool,
+    pub vault_authority: Pubkey,
+    pub payout_destination: Option<Pubkey>,
+    pub reference: [u8; 32],
+    pub fee_destination: Option<Pubkey>,
+    pub fee_on_top: bool,
+    /// Fee locked on top of crypto_amount (fee-on-top only)
+    pub prepaid_fee: u64,
+    pub crypto_guy_signer: Option<Pubkey>,
+    pub fiat_guy_signer: Option<Pubkey>,
+    /// Taker whitelist (empty = open order)
+    pub allowed_takers: Vec<Pubkey>,
+}
+
+/// Bumps of the PDAs created for one offer
+pub(crate) struct OfferBumps {
+    pub order: u8,
+    pub vault: u8,
+    pub ticket: u8,
+}
+
+/// Trading roles of a new offer, as checked by `validate_offer_parties`
+pub(crate) struct OfferParties {
+    pub crypto_guy: Pubkey,
+    pub fiat_guy: Pubkey,
+    pub acceptor: Pubkey,
+}
+
+/// Locker token account must be the canonical ATA when enabled in Config
+pub(crate) fn validate_locker_ata(
+    config: &Config,
+    locker: Pubkey,
+    locker_token_account: Pubkey,
+    mint: Pubkey,
+    token_program: Pubkey,
+) -> Result<()> {
+    if config.require_ata {
+        let expected_ata = get_associated_token_address_with_program_id(&locker, &mint, &token_program);
+        require_keys_eq!(locker_token_account, expected_ata, UniversalOrderError::InvalidTokenAccount);
+    }
+    Ok(())
+}
+
+/// Delegated signers can sign like the parties they stand in for, so the same rules apply:
+/// never the admin, and never one key for both sides
+pub(crate) fn validate_delegates(
+    crypto_guy_signer: Option<Pubkey>,
+    fiat_guy_signer: Option<Pubkey>,
+    parties: &OfferParties,
+) -> Result<()> {
+    for delegate in [crypto_guy_signer, fiat_guy_signer].into_iter().flatten() {
+        require_keys_neq!(delegate, crate::constants::ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+    }
+    require_keys_neq!(
+        crypto_guy_signer.unwrap_or(parties.crypto_guy),
+        fiat_guy_signer.unwrap_or(parties.fiat_guy),
+        UniversalOrderError::Unauthorized
+    );
+    Ok(())
+}
+
+/// (prepaid_fee, locked_amount) of a new offer: fee-on-top orders lock the settlement fee now,
+/// on top of the amount (fixed at today's config); fee-inclusive orders lock just the amount
+pub(crate) fn offer_locked_amount(
+    config: &Config,
+    mint: &Pubkey,
+    crypto_amount: u64,
+    fee_on_top: bool,
+) -> Result<(u64, u64)> {
+    let prepaid_fee = if fee_on_top {
+        calculate_fee(crypto_amount, config.fee_bps_for(mint), config.min_fee_for(mint))?.0
+    } else {
+        0
+    };
+    let locked_amount = crypto_amount.checked_add(prepaid_fee).ok_or(UniversalOrderError::InvalidAmount)?;
+    Ok((prepaid_fee, locked_amount))
+}
+
+/// Resolve CryptoGuy / FiatGuy / acceptor for a new offer locked by `locker`
+pub(crate) fn validate_offer_parties(
+    config: &Config,
+    locker: &AccountInfo,
+    creator_signer: Option<Pubkey>,
+    is_sell_order: bool,
+    creator: Pubkey,
+    fiat_guy: Pubkey,
+) -> Result<OfferParties> {
+    // CryptoGuy is always the one who locks tokens
+    let crypto_guy = locker.key();
+
+    // CHECK: The order PDA is derived from `creator`, which is only an argument. SELL creators
+    // sign as locker; for BUY orders the creator must co-sign when enabled in Config, so nobody
+    // (even with admin co-signing) can squat an order address on the creator's behalf.
+    // A co-signer that is passed must be the creator either way - never silently ignored.
+    // SELL creators already sign as locker, so a co-signer there means a confused client
+    if let Some(creator_signer) = creator_signer {
+        require!(!is_sell_order, UniversalOrderError::UnexpectedCreatorSigner);
+        require_keys_eq!(creator_signer, creator, UniversalOrderError::CreatorSignatureRequired);
+    } else if !is_sell_order && config.require_creator_sig {
+        return err!(UniversalOrderError::CreatorSignatureRequired);
+    }
+    
+    // Acceptor is the one who accepts the offer (SELL: the `fiat_guy` parameter, BUY: the locker)
+    let acceptor = offer_acceptor(is_sell_order, fiat_guy, crypto_guy);
+    let (expected_crypto_guy, actual_fiat_guy) = ticket_roles(is_sell_order, creator, acceptor);
+
+    // CHECK: BUY acceptor (the locker) must sign again later - best effort: reject program-owned
+    // accounts, which can't be wallets and would strand the ticket
+    if !is_sell_order {
+        require_keys_eq!(*locker.owner, System::id(), UniversalOrderError::AcceptorNotSignable);
+    }
+    // CHECK: The admin pays fees/rent and can never sign as a trading party, so it can't be the
+    // acceptor in either direction (SELL: the FiatGuy, BUY: the locker)
+    require_keys_neq!(acceptor, crate::constants::ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Locker must be the CryptoGuy (SELL: the creator) and not also the FiatGuy
+    // (BUY: locker must NOT be the creator)
+    require!(crypto_guy == expected_crypto_guy, UniversalOrderError::Unauthorized);
+    require!(crypto_guy != actual_fiat_guy, UniversalOrderError::Unauthorized);
+
+    Ok(OfferParties { crypto_guy, fiat_guy: actual_fiat_guy, acceptor })
+}
+
+/// Validate the offer amounts and write a fresh order + its first ticket (index 0)
+pub(crate) fn init_offer(
+    order: &mut UniversalOrder,
+    ticket: &mut FillTicket,
+    pdas: &OfferPdas,
+    args: &OfferArgs,
+    parties: &OfferParties,
+    bumps: &OfferBumps,
+    now: i64,
+) -> Result<()> {
+    // Validate amounts
+    require!(args.crypto_amount > 0, UniversalOrderError::InvalidAmount);
+    require!(args.fiat_amount > 0, UniversalOrderError::InvalidAmount);
+    require!(args.fiat_amount <= crate::constants::MAX_FIAT_AMOUNT, UniversalOrderError::InvalidAmount);
+    require!(args.ticket_id > 0, UniversalOrderError::InvalidAmount);
+
+    // Initialize order
+    order.creator = args.creator;
+    order.crypto_mint = args.crypto_mint;
+    order.crypto_amount = args.crypto_amount;
+    order.fiat_amount = args.fiat_amount;
+    order.is_sell_order = args.is_sell_order;
+    order.filled_amount = 0;
+    order.reserved_amount = args.crypto_amount; // First ticket reserves full amount
+    order.order_id = args.order_id;
+    order.created_at = now;
+    order.updated_at = now;
+    order.vault = pdas.vault;
+    order.bump = bumps.order;
+    order.vault_bump = bumps.vault;
+    order.locked = false;
+    order.crypto_first = args.crypto_first;
+    order.total_fees_collected = 0;
+    order.settle_count = 0;
+    order.refund_count = 0;
+    order.vault_authority = args.vault_authority;
+    order.ticket_index = 1;
+    order.fee_destination = args.fee_destination;
+    order.acceptors = [Pubkey::default(); MAX_ORDER_ACCEPTORS];
+    order.acceptors[0] = parties.acceptor;
+    order.acceptor_count = 1;
+    order.fee_on_top = args.fee_on_top;
+    order.reserved_fees = args.prepaid_fee;
+    order.set_allowed_takers(&args.allowed_takers)?;
+
+    // CHECK: Private orders only open for a whitelisted taker
+    order.check_taker(&parties.acceptor)?;
+
+    // Initialize ticket
+    ticket.order = pdas.order;
+    ticket.acceptor = parties.acceptor;
+    ticket.amount = args.crypto_amount;
+    ticket.crypto_guy_signed = false;
+    ticket.fiat_guy_signed = false;
+    ticket.ticket_id = args.ticket_id;
+    ticket.created_at = now;
+    ticket.last_sign_ts = 0;
+    ticket.bump = bumps.ticket;
+    ticket.payout_destination = args.payout_destination;
+    ticket.reference = args.reference;
+    ticket.index = 0;
+    ticket.extended_until = 0;
+    ticket.unwrap_to_sol = false;
+    ticket.prepaid_fee = args.prepaid_fee;
+    ticket.crypto_guy_signer = args.crypto_guy_signer;
+    ticket.fiat_guy_signer = args.fiat_guy_signer;
+    ticket.client_nonce = None;
+
+    // CHECK: Ticket within the order total
+    order.check_ticket_amount(ticket.amount)?;
+
+    Ok(())
+}
 
-    // Emit event with all data
+/// Emit OfferAccepted for a freshly locked offer
+pub(crate) fn emit_offer_accepted(
+    order: &UniversalOrder,
+    ticket: &FillTicket,
+    pdas: &OfferPdas,
+    parties: &OfferParties,
+    seq: u64,
+    now: i64,
+) {
     emit!(OfferAccepted {
-        order: order.key(),
-        order_id,
-        creator,
-        crypto_mint: ctx.accounts.mint.key(),
-        vault: ctx.accounts.vault.key(),
-        is_sell_order,
-        crypto_amount,
-        fiat_amount,
-        ticket: ticket.key(),
-        ticket_id,
-        locked_amount: crypto_amount,
-        crypto_guy,
-        fiat_guy: actual_fiat_guy,
+        order: pdas.order,
+        order_id: order.order_id,
+        creator: order.creator,
+        crypto_mint: order.crypto_mint,
+        vault: pdas.vault,
+        is_sell_order: order.is_sell_order,
+        crypto_amount: order.crypto_amount,
+        fiat_amount: order.fiat_amount,
+        crypto_first: order.crypto_first,
+        ticket: pdas.ticket,
+        ticket_id: ticket.ticket_id,
+        locked_amount: ticket.amount.saturating_add(ticket.prepaid_fee),
+        crypto_guy: parties.crypto_guy,
+        fiat_guy: parties.fiat_guy,
+        locker: parties.crypto_guy,
+        acceptor: ticket.acceptor,
+        version: crate::constants::PROGRAM_VERSION,
+        timestamp: now,
+        seq,
+    });
+}
+
+/// Fast BUY path: accept_offer_and_lock plus the FiatGuy's (creator's) first signature, atomically
+/// The creator co-signs as `creator_signer`; CryptoGuy's later sign_ticket settles as usual
+/// Only for fiat-first orders (crypto_first = false); the sign cooldown starts from this signature
+///
+/// Args mirror accept_offer_and_lock's prefix (AcceptOfferAndLock reads them via #[instruction]),
+/// so `is_sell_order` is kept and must be false.
+pub(crate) fn accept_offer_and_sign(
+    mut ctx: Context<AcceptOfferAndLock>,
+    order_id: u64,
+    ticket_id: u64,
+    crypto_amount: u64,
+    fiat_amount: u64,
+    is_sell_order: bool,
+    creator: Pubkey,
+) -> Result<OfferPdas> {
+    // CHECK: BUY orders only - the FiatGuy is the creator
+    require!(!is_sell_order, UniversalOrderError::InvalidOrderType);
+
+    // CHECK: The FiatGuy (BUY creator) must sign this transaction to give their signature
+    let creator_signer = ctx.accounts.creator_signer.as_ref()
+        .ok_or(UniversalOrderError::SignatureRequired)?;
+    require_keys_eq!(creator_signer.key(), creator, UniversalOrderError::Unauthorized);
+
+    // CHECK: Admin is never a trading party
+    require_keys_neq!(creator, crate::constants::ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // BUY order: fiat_guy is the creator, fiat signs first
+    let options = OfferOptions { fiat_guy: creator, ..OfferOptions::default() };
+    let pdas = accept_offer_and_lock(&mut ctx, order_id, ticket_id, crypto_amount, fiat_amount, false, creator, options)?;
+
+    let clock = Clock::get()?;
+    let ticket = &mut ctx.accounts.ticket;
+    ticket.fiat_guy_signed = true;
+    ticket.last_sign_ts = clock.unix_timestamp;
+
+    emit!(TicketSigned {
+        order: pdas.order,
+        ticket: pdas.ticket,
+        signer: creator,
+        is_crypto_guy: false,
+        is_fiat_guy: true,
+        both_signed: false,
+        filled_amount: ctx.accounts.order.filled_amount,
+        reserved_amount: ctx.accounts.order.reserved_amount,
         timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
     });
 
-    Ok(())
+    Ok(pdas)
 }
 
 #[derive(Accounts)]
@@ -128,12 +504,23 @@ pub struct AcceptOfferAndLock<'info> {
     #[account(mut)]
     pub locker: Signer<'info>,
 
+    /// Order creator co-signature (BUY orders only, required when config.require_creator_sig)
+    pub creator_signer: Option<Signer<'info>>,
+
+    /// Global Config PDA (min_fiat_amount, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     /// New order PDA (created here)
     #[account(
         init,
         payer = fee_payer,
         space = UniversalOrder::SPACE,
-        seeds = [b"universal_order", creator.as_ref(), mint.key().as_ref(), order_id.to_le_bytes().as_ref()],
+        seeds = [ORDER_SEED, creator.as_ref(), mint.key().as_ref(), order_id.to_le_bytes().as_ref()],
         bump
     )]
     pub order: Account<'info, UniversalOrder>,
@@ -145,7 +532,7 @@ pub struct AcceptOfferAndLock<'info> {
     #[account(
         init,
         payer = fee_payer,
-        seeds = [b"vault", order.key().as_ref()],
+        seeds = [VAULT_SEED, order.key().as_ref()],
         bump,
         token::mint = mint,
         token::authority = order,
@@ -153,12 +540,13 @@ pub struct AcceptOfferAndLock<'info> {
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
 
-    /// New ticket PDA (created here)
+    /// New ticket PDA (created here, index 0). Seeded by the new order's key, so a ticket derived
+    /// from another order (e.g. mismatched client ids) fails here with ConstraintSeeds
     #[account(
         init,
         payer = fee_payer,
         space = FillTicket::SPACE,
-        seeds = [b"ticket", order.key().as_ref(), ticket_id.to_le_bytes().as_ref()],
+        seeds = [TICKET_SEED, order.key().as_ref(), 0u64.to_le_bytes().as_ref()],
         bump
     )]
     pub ticket: Account<'info, FillTicket>,
@@ -167,8 +555,7 @@ pub struct AcceptOfferAndLock<'info> {
     #[account(
         mut,
         constraint = locker_token_account.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount,
-        constraint = locker_token_account.owner == locker.key() @ UniversalOrderError::Unauthorized,
-        constraint = locker_token_account.amount >= crypto_amount @ UniversalOrderError::InsufficientBalance
+        constraint = locker_token_account.owner == locker.key() @ UniversalOrderError::Unauthorized
     )]
     pub locker_token_account: InterfaceAccount<'info, TokenAccount>,
 
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::AccountsClose;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::OrderClosed;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, CONFIG_SEED};
+
+/// Admin recovery: close an order whose vault account no longer exists (rent → config.rent_receiver)
+/// The seeds-checked vault must already be closed and the order must have nothing reserved
+pub(crate) fn admin_force_close_order(
+    ctx: Context<AdminForceCloseOrder>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // CHECK: Never orphan locked tokens - the vault must be gone and the order can't have
+    // anything (amount or prepaid fees) still reserved for open tickets
+    let vault = &ctx.accounts.vault;
+    require!(
+        vault.owner == &System::id() && vault.data_is_empty(),
+        UniversalOrderError::VaultStillOpen
+    );
+    let order = &ctx.accounts.order;
+    require!(
+        order.reserved_amount == 0 && order.reserved_fees == 0,
+        UniversalOrderError::OrderHasActiveTickets
+    );
+
+    emit!(OrderClosed {
+        order: order.key(),
+        creator: order.creator,
+        dust_amount: 0,
+        total_fees_collected: order.total_fees_collected,
+        settle_count: order.settle_count,
+        refund_count: order.refund_count,
+        rent_returned_to: ctx.accounts.admin_rent_receiver.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
+    msg!("Order force-closed, rent returned to rent receiver");
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AdminForceCloseOrder<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (rent_receiver)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Orphaned order (will be closed)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// CHECK: Order's vault PDA address; must already be closed, so not deserialized
+    #[account(
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+}
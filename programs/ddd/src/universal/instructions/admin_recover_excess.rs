@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::ExcessRecovered;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED};
+
+/// Admin recovery of tokens sent straight to a vault address (outside any ticket)
+/// Only `vault.amount - (order.reserved_amount + order.reserved_fees)` moves - funds locked by
+/// tickets (prepaid fees included) are never touched
+pub(crate) fn admin_recover_excess(
+    ctx: Context<AdminRecoverExcess>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let order = &ctx.accounts.order;
+
+    let order_key = order.key();
+    let order_creator = order.creator;
+    let order_mint = order.crypto_mint;
+    let order_id_le = order.order_id.to_le_bytes();
+    let order_bump = order.bump;
+
+    // Every settled/refunded amount leaves the vault when it's accounted,
+    // so whatever exceeds the active reservations was never locked by a ticket
+    let locked = order.reserved_amount
+        .checked_add(order.reserved_fees)
+        .ok_or(UniversalOrderError::InvalidAmount)?;
+    let excess = ctx.accounts.vault.amount
+        .checked_sub(locked)
+        .ok_or(UniversalOrderError::InsufficientBalance)?;
+    require!(excess > 0, UniversalOrderError::InvalidAmount);
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.recipient_token_account.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        },
+        signer,
+    );
+    transfer_checked(cpi, excess, ctx.accounts.mint.decimals)?;
+
+    ctx.accounts.order.updated_at = clock.unix_timestamp;
+
+    emit!(ExcessRecovered {
+        order: order_key,
+        mint: order_mint,
+        amount: excess,
+        recipient: ctx.accounts.recipient_token_account.key(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Recovered {} excess tokens from vault", excess);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AdminRecoverExcess<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Order PDA owning the vault
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Mint account - needed for transfer_checked (must be the order's mint)
+    #[account(
+        constraint = mint.key() == order.crypto_mint @ UniversalOrderError::InvalidMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Order vault PDA holding the excess
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token account receiving the excess (same mint)
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
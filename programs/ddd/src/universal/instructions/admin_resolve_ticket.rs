@@ -1,19 +1,70 @@
 use anchor_lang::prelude::*;
-use anchor_lang::prelude::AccountsClose; // for account close
-use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked, close_account, CloseAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
 use crate::universal::state::*;
 use crate::universal::errors::UniversalOrderError;
-use crate::universal::utils::fees::calculate_fee;
+use crate::universal::events::UniversalAdminResolved;
+use crate::universal::utils::fees::settlement_split;
+use crate::universal::utils::fee_transfer::pay_fee;
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock, debug_assert_consistency};
 use crate::constants::ADMIN_PUBKEY;
+use crate::universal::utils::validate::{ticket_roles, validate_ata};
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED};
 
 /// Admin resolve for a specific ticket: either settle to FiatGuy or refund to CryptoGuy
+/// `reason_code` is a ResolutionReason, recorded in UniversalAdminResolved for the audit trail
 pub fn admin_resolve_ticket(
     ctx: Context<AdminResolveTicket>,
     release_to_fiat_guy: bool,
+    reason_code: u8,
+) -> Result<()> {
+    resolve_ticket(ctx, release_to_fiat_guy, None, reason_code)
+}
+
+/// Compliance variant: same resolution, but the funds go to `destination` (passed in the
+/// fiat_guy/crypto_guy token account slot for the chosen direction) instead of the party's own
+/// account, e.g. when a counterparty's wallet is flagged. Only the mint is validated
+pub(crate) fn admin_resolve_ticket_to(
+    ctx: Context<AdminResolveTicket>,
+    destination: Pubkey,
+    release_to_fiat_guy: bool,
+    reason_code: u8,
+) -> Result<()> {
+    resolve_ticket(ctx, release_to_fiat_guy, Some(destination), reason_code)
+}
+
+/// Recipient must be the party's account, or exactly the admin-chosen `destination`
+fn validate_recipient(
+    ata: &InterfaceAccount<TokenAccount>,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    destination: Option<Pubkey>,
+) -> Result<()> {
+    match destination {
+        Some(destination) => {
+            require_keys_eq!(ata.key(), destination, UniversalOrderError::InvalidTokenAccount);
+            require!(ata.mint == *mint, UniversalOrderError::InvalidTokenAccount);
+            Ok(())
+        }
+        None => validate_ata(ata, mint, owner),
+    }
+}
+
+fn resolve_ticket(
+    ctx: Context<AdminResolveTicket>,
+    release_to_fiat_guy: bool,
+    destination: Option<Pubkey>,
+    reason_code: u8,
 ) -> Result<()> {
     // Auth
     require_keys_eq!(ctx.accounts.admin.key(), ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
 
+    // CHECK: Structured reason only
+    require!(ResolutionReason::from_code(reason_code).is_some(), UniversalOrderError::InvalidReasonCode);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
     // Immutable snapshots to avoid borrow conflicts during CPI
     let order_key = ctx.accounts.order.key();
     let is_sell = ctx.accounts.order.is_sell_order;
@@ -28,300 +79,146 @@ pub fn admin_resolve_ticket(
     require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
 
     // Identify roles
-    let crypto_guy = if is_sell { order_creator } else { ticket.acceptor };
-    let fiat_guy   = if is_sell { ticket.acceptor } else { order_creator };
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell, order_creator, ticket.acceptor);
 
     let amount = ticket.amount;
     require!(amount > 0, UniversalOrderError::InvalidAmount);
 
+    // Get mint decimals
+    let decimals = ctx.accounts.mint.decimals;
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let recipient;
     if release_to_fiat_guy {
         // Payout path: 99.75% to FiatGuy + 0.25% to Admin
         let fiat_ata = ctx.accounts.fiat_guy_token_account.as_ref()
             .ok_or(UniversalOrderError::TokenAccountRequired)?;
-        require!(fiat_ata.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-        require!(fiat_ata.owner == fiat_guy, UniversalOrderError::Unauthorized);
+        validate_recipient(fiat_ata, &order_mint, &ticket.payout_owner(fiat_guy), destination)?;
+        recipient = fiat_ata.key();
+
+        // Calculate fee (per-mint bps, floored at the mint's min_fee; fee-on-top orders use the prepaid fee)
+        let (fee_amount, net_amount) = settlement_split(
+            amount,
+            ticket.prepaid_fee,
+            ctx.accounts.order.fee_on_top,
+            ctx.accounts.config.fee_bps_for(&order_mint),
+            ctx.accounts.config.min_fee_for(&order_mint),
+        )?;
+
+        // Transfer 1: 99.75% to FiatGuy - skipped when min_fee consumes the whole amount
+        if net_amount > 0 {
+            let cpi = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: fiat_ata.to_account_info(),
+                    authority: ctx.accounts.order.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                signer,
+            );
+            transfer_checked(cpi, net_amount, decimals)?;
+        }
 
-        // CHECK: Admin fee account provided
-        let admin_fee_account = ctx.accounts.admin_fee_account.as_ref()
+        // Transfer 2: 0.25% to Admin, or the order's fee_destination (fee)
+        pay_fee(
+            &ctx.accounts.order,
+            &ctx.accounts.config,
+            ctx.accounts.admin_fee_account.as_ref(),
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program.to_account_info(),
+            fee_amount,
+        )?;
+
+        {
+            let order = &mut ctx.accounts.order;
+            order.filled_amount = order.filled_amount.saturating_add(amount);
+            order.reserved_amount = order.reserved_amount.checked_sub(amount)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.reserved_fees = order.reserved_fees.checked_sub(ticket.prepaid_fee)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.total_fees_collected = order.total_fees_collected.saturating_add(fee_amount);
+            order.settle_count = order.settle_count.saturating_add(1);
+        }
+        // Mark ticket as settled
+        ticket.crypto_guy_signed = true;
+        ticket.fiat_guy_signed = true;
+        ticket.amount = 0;
+        ticket.prepaid_fee = 0;
+    } else {
+        // Refund path: SELL refunds creator, BUY refunds ticket.acceptor (both CryptoGuy), prepaid fee included
+        let crypto_ata = ctx.accounts.crypto_guy_token_account.as_ref()
             .ok_or(UniversalOrderError::TokenAccountRequired)?;
-        require!(admin_fee_account.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-        require!(admin_fee_account.owner == ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
-
-        // Calculate 0.25% fee
-        let (fee_amount, net_amount) = calculate_fee(amount)?;
-        
-        // Get mint decimals
-        let decimals = ctx.accounts.mint.decimals;
-
-        let seeds = &[
-            b"universal_order",
-            order_creator.as_ref(),
-            order_mint.as_ref(),
-            order_id_le.as_ref(),
-            &[order_bump],
-        ];
-        let signer = &[&seeds[..]];
-
-        // Transfer 1: 99.75% to FiatGuy
-        let cpi = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                to: fiat_ata.to_account_info(),
-                authority: ctx.accounts.order.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-            },
-            signer,
-        );
-        transfer_checked(cpi, net_amount, decimals)?;
+        validate_recipient(crypto_ata, &order_mint, &crypto_guy, destination)?;
+        recipient = crypto_ata.key();
 
-        // Transfer 2: 0.25% to Admin (fee)
-        let fee_cpi = CpiContext::new_with_signer(
+        let cpi = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             TransferChecked {
                 from: ctx.accounts.vault.to_account_info(),
-                to: admin_fee_account.to_account_info(),
+                to: crypto_ata.to_account_info(),
                 authority: ctx.accounts.order.to_account_info(),
                 mint: ctx.accounts.mint.to_account_info(),
             },
             signer,
         );
-        transfer_checked(fee_cpi, fee_amount, decimals)?;
-
+        transfer_checked(cpi, ticket.locked_amount()?, decimals)?;
         {
             let order = &mut ctx.accounts.order;
-            order.filled_amount = order.filled_amount.saturating_add(amount);
-            order.reserved_amount = order.reserved_amount.saturating_sub(amount);
-        }
-        // Mark ticket as settled
-        ticket.crypto_guy_signed = true;
-        ticket.fiat_guy_signed = true;
-        ticket.amount = 0;
-
-        // Read vault balance after transfers
-        let vault_account = ctx.accounts.vault.to_account_info();
-        let vault_data = vault_account.try_borrow_data()?;
-        let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
-        drop(vault_data);
-
-        // AUTO-CLOSE if vault empty and order completed
-        if vault_balance == 0 {
-            let order = &ctx.accounts.order;
-            let remaining = order.remaining_amount();
-            let should_close = remaining == 0 && order.reserved_amount == 0;
-            
-            if should_close {
-                msg!("Auto-closing vault and order after admin payout");
-                
-                let seeds = &[
-                    b"universal_order".as_ref(),
-                    order_creator.as_ref(),
-                    order_mint.as_ref(),
-                    order_id_le.as_ref(),
-                    &[order_bump],
-                ];
-                let signer = &[&seeds[..]];
-
-                let close_vault_accounts = CloseAccount {
-                    account: ctx.accounts.vault.to_account_info(),
-                    destination: ctx.accounts.admin_rent_receiver.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                };
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    close_vault_accounts,
-                    signer,
-                );
-
-                close_account(cpi_ctx)?;
-                msg!("Vault closed");
-
-                ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Order closed");
-
-                ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Ticket closed");
-                
-                return Ok(());
-            }
-        }
-        
-        // If not closing everything, just close ticket
-        ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-    } else {
-        // Refund path
-        if is_sell {
-            // Refund to creator (CryptoGuy)
-            let creator_ata = ctx.accounts.crypto_guy_token_account.as_ref()
-                .ok_or(UniversalOrderError::TokenAccountRequired)?;
-            require!(creator_ata.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-            require!(creator_ata.owner == crypto_guy, UniversalOrderError::Unauthorized);
-            
-            // Get mint decimals
-            let decimals = ctx.accounts.mint.decimals;
-
-            let seeds = &[
-                b"universal_order",
-                order_creator.as_ref(),
-                order_mint.as_ref(),
-                order_id_le.as_ref(),
-                &[order_bump],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: creator_ata.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                    mint: ctx.accounts.mint.to_account_info(),
-                },
-                signer,
-            );
-            transfer_checked(cpi, amount, decimals)?;
+            order.reserved_amount = order.reserved_amount.checked_sub(amount)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.reserved_fees = order.reserved_fees.checked_sub(ticket.prepaid_fee)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.refund_count = order.refund_count.saturating_add(1);
             // Reduce target to reflect refund out of the order
-            {
-                let order = &mut ctx.accounts.order;
-                order.reserved_amount = order.reserved_amount.saturating_sub(amount);
-                order.crypto_amount = order.crypto_amount.saturating_sub(amount);
-            }
-            // Ticket refunded/voided
-            ticket.crypto_guy_signed = false;
-            ticket.fiat_guy_signed = false;
-            ticket.amount = 0;
-
-            // Read vault balance after refund transfer
-            let vault_account = ctx.accounts.vault.to_account_info();
-            let vault_data = vault_account.try_borrow_data()?;
-            let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
-            drop(vault_data);
-
-            // AUTO-CLOSE: Refund means order is cancelled, close if vault empty
-            if vault_balance == 0 {
-                msg!("Auto-closing vault and order after admin refund (SELL)");
-                
-                let seeds = &[
-                    b"universal_order".as_ref(),
-                    order_creator.as_ref(),
-                    order_mint.as_ref(),
-                    order_id_le.as_ref(),
-                    &[order_bump],
-                ];
-                let signer = &[&seeds[..]];
-
-                let close_vault_accounts = CloseAccount {
-                    account: ctx.accounts.vault.to_account_info(),
-                    destination: ctx.accounts.admin_rent_receiver.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                };
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    close_vault_accounts,
-                    signer,
-                );
-
-                close_account(cpi_ctx)?;
-                msg!("Vault closed");
-
-                ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Order closed");
-
-                ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Ticket closed");
-                
-                return Ok(());
-            }
-            
-            // If not closing, just close ticket
-            ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-        } else {
-            // Buy order: refund to ticket.acceptor (CryptoGuy)
-            let acceptor_ata = ctx.accounts.crypto_guy_token_account.as_ref()
-                .ok_or(UniversalOrderError::TokenAccountRequired)?;
-            require!(acceptor_ata.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-            require!(acceptor_ata.owner == crypto_guy, UniversalOrderError::Unauthorized);
-            
-            // Get mint decimals
-            let decimals = ctx.accounts.mint.decimals;
-
-            let seeds = &[
-                b"universal_order",
-                order_creator.as_ref(),
-                order_mint.as_ref(),
-                order_id_le.as_ref(),
-                &[order_bump],
-            ];
-            let signer = &[&seeds[..]];
-
-            let cpi = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                TransferChecked {
-                    from: ctx.accounts.vault.to_account_info(),
-                    to: acceptor_ata.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                    mint: ctx.accounts.mint.to_account_info(),
-                },
-                signer,
-            );
-            transfer_checked(cpi, amount, decimals)?;
-            {
-                let order = &mut ctx.accounts.order;
-                order.reserved_amount = order.reserved_amount.saturating_sub(amount);
-            }
-            // Ticket refunded/voided
-            ticket.crypto_guy_signed = false;
-            ticket.fiat_guy_signed = false;
-            ticket.amount = 0;
-
-            // Read vault balance after refund transfer
-            let vault_account = ctx.accounts.vault.to_account_info();
-            let vault_data = vault_account.try_borrow_data()?;
-            let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
-            drop(vault_data);
-
-            // AUTO-CLOSE: Refund means order is cancelled, close if vault empty
-            if vault_balance == 0 {
-                msg!("Auto-closing vault and order after admin refund (BUY)");
-                
-                let seeds = &[
-                    b"universal_order".as_ref(),
-                    order_creator.as_ref(),
-                    order_mint.as_ref(),
-                    order_id_le.as_ref(),
-                    &[order_bump],
-                ];
-                let signer = &[&seeds[..]];
-
-                let close_vault_accounts = CloseAccount {
-                    account: ctx.accounts.vault.to_account_info(),
-                    destination: ctx.accounts.admin_rent_receiver.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                };
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    close_vault_accounts,
-                    signer,
-                );
-
-                close_account(cpi_ctx)?;
-                msg!("Vault closed");
-
-                ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Order closed");
-
-                ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Ticket closed");
-            } else {
-                // If not closing, just close ticket
-                ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-            }
+            order.crypto_amount = order.crypto_amount.checked_sub(amount)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
         }
+        // Ticket refunded/voided
+        ticket.crypto_guy_signed = false;
+        ticket.fiat_guy_signed = false;
+        ticket.amount = 0;
+        ticket.prepaid_fee = 0;
     }
 
+    release_order_lock(&mut ctx.accounts.order);
+    debug_assert_consistency(&ctx.accounts.order, ctx.accounts.config.strict_invariants)?;
+
+    emit!(UniversalAdminResolved {
+        order: order_key,
+        ticket: Some(ticket.key()),
+        admin: ctx.accounts.admin.key(),
+        amount,
+        recipient,
+        resolution_type: match (release_to_fiat_guy, destination.is_some()) {
+            (true, false) => "ticket_settle",
+            (false, false) => "ticket_refund",
+            (true, true) => "ticket_settle_to",
+            (false, true) => "ticket_refund_to",
+        }.to_string(),
+        reason_code,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    // AUTO-CLOSE: ticket always; vault + order if empty and done
+    close_ticket_and_maybe_order(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        ticket,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        !release_to_fiat_guy,
+    )?;
+
     Ok(())
 }
 
@@ -332,17 +229,25 @@ pub struct AdminResolveTicket<'info> {
     /// CHECK: compared to constant
     pub admin: AccountInfo<'info>,
 
-    /// CHECK: Admin wallet receives rent back (hardcoded address)
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
     #[account(
         mut,
-        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
     )]
     pub admin_rent_receiver: UncheckedAccount<'info>,
 
+    /// Global Config PDA (fee floor, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     /// Parent order PDA
     #[account(
         mut,
-        seeds = [b"universal_order", order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
         bump = order.bump
     )]
     pub order: Account<'info, UniversalOrder>,
@@ -353,9 +258,10 @@ pub struct AdminResolveTicket<'info> {
     /// Vault PDA - supports both SPL Token and Token-2022
     #[account(
         mut,
-        seeds = [b"vault", order.key().as_ref()],
-        bump,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
         constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority,
         constraint = vault.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
@@ -363,7 +269,7 @@ pub struct AdminResolveTicket<'info> {
     /// Ticket PDA to resolve
     #[account(
         mut,
-        seeds = [b"ticket", order.key().as_ref(), ticket.ticket_id.to_le_bytes().as_ref()],
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
         bump = ticket.bump
     )]
     pub ticket: Account<'info, FillTicket>,
@@ -374,7 +280,7 @@ pub struct AdminResolveTicket<'info> {
     #[account(mut)]
     pub crypto_guy_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Admin's token account (for 0.25% fee on payouts only)
+    /// Fee token account owned by Admin or order.fee_destination (fee on payouts only; may be omitted when the fee rounds to 0)
     #[account(mut)]
     pub admin_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
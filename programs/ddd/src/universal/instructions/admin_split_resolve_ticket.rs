@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer_checked, TransferChecked};
+use crate::universal::state::ResolutionReason;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::{UniversalAdminResolved, TicketSplitResolved};
+use crate::universal::utils::fees::split_resolve_amounts;
+use crate::universal::utils::fee_transfer::pay_fee;
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock, debug_assert_consistency};
+use crate::constants::ADMIN_PUBKEY;
+use super::admin_resolve_ticket::AdminResolveTicket;
+use crate::universal::utils::validate::{ticket_roles, validate_ata};
+use crate::universal::utils::seeds::ORDER_SEED;
+
+/// Admin split resolve for partial-delivery disputes: settle `settle_amount` (minus fee)
+/// to FiatGuy and refund the rest of the ticket to CryptoGuy
+/// Fee-on-top orders: FiatGuy gets all of `settle_amount`, the settled share of the prepaid fee
+/// goes to the fee owner (admin or `fee_destination`) and the rest of it is refunded with the remainder
+/// `reason_code` is a ResolutionReason, recorded in UniversalAdminResolved / TicketSplitResolved
+pub fn admin_split_resolve_ticket(
+    ctx: Context<AdminResolveTicket>,
+    settle_amount: u64,
+    reason_code: u8,
+) -> Result<()> {
+    // Auth
+    require_keys_eq!(ctx.accounts.admin.key(), ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Structured reason only
+    require!(ResolutionReason::from_code(reason_code).is_some(), UniversalOrderError::InvalidReasonCode);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
+    // Immutable snapshots to avoid borrow conflicts during CPI
+    let order_key = ctx.accounts.order.key();
+    let is_sell = ctx.accounts.order.is_sell_order;
+    let order_creator = ctx.accounts.order.creator;
+    let order_mint = ctx.accounts.order.crypto_mint;
+    let order_id_le = ctx.accounts.order.order_id.to_le_bytes();
+    let order_bump = ctx.accounts.order.bump;
+
+    let ticket = &mut ctx.accounts.ticket;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
+
+    // Identify roles
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell, order_creator, ticket.acceptor);
+
+    // CHECK: A real split - both sides get something (use admin_resolve_ticket otherwise)
+    let amount = ticket.amount;
+    require!(settle_amount > 0 && settle_amount < amount, UniversalOrderError::InvalidAmount);
+    let refund_amount = amount - settle_amount;
+
+    // CHECK: Both ATAs required
+    let fiat_ata = ctx.accounts.fiat_guy_token_account.as_ref()
+        .ok_or(UniversalOrderError::TokenAccountRequired)?;
+    validate_ata(fiat_ata, &order_mint, &ticket.payout_owner(fiat_guy))?;
+    let fiat_ata_key = fiat_ata.key();
+
+    let crypto_ata = ctx.accounts.crypto_guy_token_account.as_ref()
+        .ok_or(UniversalOrderError::TokenAccountRequired)?;
+    validate_ata(crypto_ata, &order_mint, &crypto_guy)?;
+
+    // Fee only on the settled part (per-mint bps, floored at the mint's min_fee); fee-on-top orders
+    // charge their prorated prepaid fee and refund the unused share with the remainder
+    let prepaid_fee = ticket.prepaid_fee;
+    let (fee_amount, net_amount, refund_total) = split_resolve_amounts(
+        amount,
+        settle_amount,
+        prepaid_fee,
+        ctx.accounts.order.fee_on_top,
+        ctx.accounts.config.fee_bps_for(&order_mint),
+        ctx.accounts.config.min_fee_for(&order_mint),
+    )?;
+
+    let decimals = ctx.accounts.mint.decimals;
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Transfer 1: settled part (net) to FiatGuy - skipped when min_fee consumes the settled part
+    if net_amount > 0 {
+        let cpi = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: fiat_ata.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer,
+        );
+        transfer_checked(cpi, net_amount, decimals)?;
+    }
+
+    // Transfer 2: fee on settled part to the order's fee owner
+    pay_fee(
+        &ctx.accounts.order,
+        &ctx.accounts.config,
+        ctx.accounts.admin_fee_account.as_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program.to_account_info(),
+        fee_amount,
+    )?;
+
+    // Transfer 3: remainder back to CryptoGuy
+    let refund_cpi = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            to: crypto_ata.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        },
+        signer,
+    );
+    transfer_checked(refund_cpi, refund_total, decimals)?;
+
+    {
+        let order = &mut ctx.accounts.order;
+        order.filled_amount = order.filled_amount.saturating_add(settle_amount);
+        order.reserved_amount = order.reserved_amount.checked_sub(amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.reserved_fees = order.reserved_fees.checked_sub(prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.total_fees_collected = order.total_fees_collected.saturating_add(fee_amount);
+        // Split counts as both a settlement and a refund
+        order.settle_count = order.settle_count.saturating_add(1);
+        order.refund_count = order.refund_count.saturating_add(1);
+        // Reduce target to reflect refund out of the order
+        order.crypto_amount = order.crypto_amount.checked_sub(refund_amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+    }
+    // Ticket resolved
+    ticket.crypto_guy_signed = true;
+    ticket.fiat_guy_signed = true;
+    ticket.amount = 0;
+    ticket.prepaid_fee = 0;
+
+    msg!("Ticket {} split: settled {} (fee {}), refunded {}", ticket.ticket_id, settle_amount, fee_amount, refund_total);
+
+    release_order_lock(&mut ctx.accounts.order);
+    debug_assert_consistency(&ctx.accounts.order, ctx.accounts.config.strict_invariants)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    emit!(UniversalAdminResolved {
+        order: order_key,
+        ticket: Some(ticket.key()),
+        admin: ctx.accounts.admin.key(),
+        amount,
+        recipient: fiat_ata_key,
+        resolution_type: "ticket_split".to_string(),
+        reason_code,
+        timestamp,
+    });
+    emit!(TicketSplitResolved {
+        order: order_key,
+        ticket: ticket.key(),
+        settle_amount,
+        fee_amount,
+        net_amount,
+        refund_amount: refund_total,
+        reason_code,
+        timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
+    });
+
+    // AUTO-CLOSE: ticket always; vault + order once nothing remains reserved
+    close_ticket_and_maybe_order(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        ticket,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        true,
+    )?;
+
+    Ok(())
+}
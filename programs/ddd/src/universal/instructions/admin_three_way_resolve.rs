@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, transfer_checked, TransferChecked};
+use crate::universal::state::ResolutionReason;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::{UniversalAdminResolved, TicketThreeWayResolved};
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::fee_transfer::pay_fee;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock, debug_assert_consistency};
+use crate::constants::ADMIN_PUBKEY;
+use super::admin_resolve_ticket::AdminResolveTicket;
+use crate::universal::utils::validate::{ticket_roles, validate_ata};
+use crate::universal::utils::seeds::ORDER_SEED;
+
+/// Admin three-way resolve for disputes settled with a penalty: `to_fiat` to FiatGuy,
+/// `to_crypto` back to CryptoGuy and `penalty` to the fee owner (admin or `fee_destination`), summing to the ticket amount
+/// Portions are paid exactly - no settlement fee is taken on top of the penalty.
+/// Fee-on-top orders: the prepaid fee is refunded to CryptoGuy with `to_crypto`
+/// `reason_code` is a ResolutionReason, recorded in UniversalAdminResolved / TicketThreeWayResolved
+pub(crate) fn admin_three_way_resolve(
+    ctx: Context<AdminResolveTicket>,
+    to_fiat: u64,
+    to_crypto: u64,
+    penalty: u64,
+    reason_code: u8,
+) -> Result<()> {
+    // Auth
+    require_keys_eq!(ctx.accounts.admin.key(), ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Structured reason only
+    require!(ResolutionReason::from_code(reason_code).is_some(), UniversalOrderError::InvalidReasonCode);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
+    // Immutable snapshots to avoid borrow conflicts during CPI
+    let order_key = ctx.accounts.order.key();
+    let is_sell = ctx.accounts.order.is_sell_order;
+    let order_creator = ctx.accounts.order.creator;
+    let order_mint = ctx.accounts.order.crypto_mint;
+    let order_id_le = ctx.accounts.order.order_id.to_le_bytes();
+    let order_bump = ctx.accounts.order.bump;
+
+    let ticket = &mut ctx.accounts.ticket;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
+
+    // Identify roles
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell, order_creator, ticket.acceptor);
+
+    // CHECK: Portions account for the whole ticket, no more and no less
+    let amount = ticket.amount;
+    require!(amount > 0, UniversalOrderError::InvalidAmount);
+    let total = to_fiat.checked_add(to_crypto)
+        .and_then(|sum| sum.checked_add(penalty))
+        .ok_or(UniversalOrderError::InvalidAmount)?;
+    require!(total == amount, UniversalOrderError::InvalidAmount);
+
+    let prepaid_fee = ticket.prepaid_fee;
+    let refund_total = to_crypto + prepaid_fee;
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Each recipient account is only required when its portion is non-zero (no zero-value CPIs)
+    let portions = [
+        (to_fiat, ctx.accounts.fiat_guy_token_account.as_ref(), ticket.payout_owner(fiat_guy)),
+        (refund_total, ctx.accounts.crypto_guy_token_account.as_ref(), crypto_guy),
+    ];
+    // UniversalAdminResolved.recipient: first account paid (FiatGuy, then CryptoGuy, then the fee owner)
+    let mut recipient = None;
+    for (portion, ata, owner) in portions {
+        if portion == 0 {
+            continue;
+        }
+        let ata: &InterfaceAccount<TokenAccount> = ata.ok_or(UniversalOrderError::TokenAccountRequired)?;
+        validate_ata(ata, &order_mint, &owner)?;
+        recipient.get_or_insert(ata.key());
+
+        let cpi = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ata.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer,
+        );
+        transfer_checked(cpi, portion, ctx.accounts.mint.decimals)?;
+    }
+
+    // Penalty goes where settlement fees go: the order's fee owner
+    pay_fee(
+        &ctx.accounts.order,
+        &ctx.accounts.config,
+        ctx.accounts.admin_fee_account.as_ref(),
+        &ctx.accounts.vault,
+        &ctx.accounts.mint,
+        &ctx.accounts.token_program.to_account_info(),
+        penalty,
+    )?;
+    let recipient = match recipient {
+        Some(recipient) => recipient,
+        None => ctx.accounts.admin_fee_account.as_ref().ok_or(UniversalOrderError::TokenAccountRequired)?.key(),
+    };
+
+    {
+        let order = &mut ctx.accounts.order;
+        order.filled_amount = order.filled_amount.saturating_add(to_fiat);
+        order.reserved_amount = order.reserved_amount.checked_sub(amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.reserved_fees = order.reserved_fees.checked_sub(prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        // Penalty is the treasury's take for this ticket
+        order.total_fees_collected = order.total_fees_collected.saturating_add(penalty);
+        if to_fiat > 0 {
+            order.settle_count = order.settle_count.saturating_add(1);
+        }
+        if to_crypto > 0 {
+            order.refund_count = order.refund_count.saturating_add(1);
+        }
+        // Reduce target by everything that left the order other than the fill
+        order.crypto_amount = order.crypto_amount.checked_sub(to_crypto + penalty)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+    }
+    // Ticket resolved
+    ticket.crypto_guy_signed = true;
+    ticket.fiat_guy_signed = true;
+    ticket.amount = 0;
+    ticket.prepaid_fee = 0;
+
+    msg!("Ticket {} three-way: fiat {}, crypto {}, penalty {}", ticket.ticket_id, to_fiat, refund_total, penalty);
+
+    release_order_lock(&mut ctx.accounts.order);
+    debug_assert_consistency(&ctx.accounts.order, ctx.accounts.config.strict_invariants)?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+    emit!(UniversalAdminResolved {
+        order: order_key,
+        ticket: Some(ticket.key()),
+        admin: ctx.accounts.admin.key(),
+        amount,
+        recipient,
+        resolution_type: "ticket_three_way".to_string(),
+        reason_code,
+        timestamp,
+    });
+    emit!(TicketThreeWayResolved {
+        order: order_key,
+        ticket: ticket.key(),
+        to_fiat,
+        to_crypto,
+        prepaid_fee_refunded: prepaid_fee,
+        penalty,
+        reason_code,
+        timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
+    });
+
+    // AUTO-CLOSE: ticket always; vault + order once nothing remains reserved
+    close_ticket_and_maybe_order(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        ticket,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        true,
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,195 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::AccountsClose;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::TicketCancelled;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock, require_remaining_accounts};
+use crate::universal::utils::auto_close::close_order_and_vault;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, CONFIG_SEED};
+
+/// Maker cleanup: refund and close every unsigned ticket passed in remaining_accounts (writable)
+/// SELL orders only - the creator is the CryptoGuy, so all refunds go to their token account
+/// Tickets with any signature are skipped; the order + vault auto-close once nothing is reserved
+pub(crate) fn cancel_all_unsigned<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelAllUnsigned<'info>>,
+    ticket_count: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // CHECK: Exactly `ticket_count` tickets passed
+    require_remaining_accounts(ctx.remaining_accounts, ticket_count as usize)?;
+
+    // Snapshot order fields
+    let order_key = ctx.accounts.order.key();
+    let order_creator = ctx.accounts.order.creator;
+    let order_mint = ctx.accounts.order.crypto_mint;
+    let order_id_le = ctx.accounts.order.order_id.to_le_bytes();
+    let order_bump = ctx.accounts.order.bump;
+
+    // CHECK: Only the creator of a SELL order (the CryptoGuy maker)
+    require!(ctx.accounts.creator.key() == order_creator, UniversalOrderError::Unauthorized);
+    require!(ctx.accounts.order.is_sell_order, UniversalOrderError::InvalidOrderType);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
+    let rent_receiver = ctx.accounts.admin_rent_receiver.to_account_info();
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut refund_total: u64 = 0;
+    let mut refund_fees: u64 = 0;
+    let mut cancelled: u16 = 0;
+    for info in ctx.remaining_accounts.iter() {
+        // CHECK: Each ticket processed once
+        require!(!seen.contains(info.key), UniversalOrderError::DuplicateTicket);
+        seen.push(*info.key);
+
+        // CHECK: Program-owned FillTicket of this order
+        let ticket: Account<'info, FillTicket> = Account::try_from(info)?;
+        require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
+
+        if ticket.crypto_guy_signed || ticket.fiat_guy_signed {
+            msg!("Skipping signed ticket {}", ticket.ticket_id);
+            continue;
+        }
+
+        refund_total = refund_total
+            .checked_add(ticket.amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        refund_fees = refund_fees
+            .checked_add(ticket.prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        cancelled = cancelled.saturating_add(1);
+
+        emit!(TicketCancelled {
+            order: order_key,
+            ticket: ticket.key(),
+            canceller: order_creator,
+            amount: ticket.amount,
+            refunded: true,
+            reference: ticket.reference,
+            timestamp: clock.unix_timestamp,
+            seq: ctx.accounts.config.next_event_seq(),
+        });
+
+        ticket.close(rent_receiver.clone())?;
+    }
+
+    let signer_seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&signer_seeds[..]];
+
+    // Refund all cancelled amounts (and prepaid fees) to the creator in one transfer
+    let refund_locked = refund_total.checked_add(refund_fees).ok_or(UniversalOrderError::InvalidAmount)?;
+    if refund_locked > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.order.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+            signer,
+        );
+        transfer_checked(transfer_ctx, refund_locked, ctx.accounts.mint.decimals)?;
+    }
+    {
+        let order = &mut ctx.accounts.order;
+        order.reserved_amount = order.reserved_amount.checked_sub(refund_total)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.reserved_fees = order.reserved_fees.checked_sub(refund_fees)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.refund_count = order.refund_count.saturating_add(cancelled);
+        // Refunds leave the order - shrink target so remaining tickets can still complete it
+        order.crypto_amount = order.crypto_amount.checked_sub(refund_total)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.updated_at = clock.unix_timestamp;
+        release_order_lock(order);
+    }
+    msg!("Cancelled {} unsigned tickets, refunded {}", cancelled, refund_total);
+
+    // Read vault balance directly after transfer
+    let vault_account = ctx.accounts.vault.to_account_info();
+    let vault_data = vault_account.try_borrow_data()?;
+    let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
+    drop(vault_data);
+
+    // AUTO-CLOSE: nothing reserved and vault empty -> close vault + order, rent to the rent receiver
+    if vault_balance == 0 && ctx.accounts.order.is_cancellable() {
+        close_order_and_vault(
+            &mut ctx.accounts.order,
+            &ctx.accounts.vault,
+            &rent_receiver,
+            &ctx.accounts.token_program.to_account_info(),
+            0,
+        )?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelAllUnsigned<'info> {
+    /// Admin pays transaction fee (first signer = pays transaction fee)
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub fee_payer: Signer<'info>,
+
+    /// Order creator (SELL maker / CryptoGuy)
+    pub creator: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (rent_receiver, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Parent order (unsigned tickets go in remaining_accounts)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Mint account - needed for transfer_checked
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault (closed once empty and nothing is reserved)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority,
+        constraint = vault.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Creator's token account (receives refunds)
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = creator_token_account.owner == creator.key() @ UniversalOrderError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_lang::prelude::AccountsClose;
-use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked, close_account, CloseAccount};
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
 use crate::universal::state::*;
 use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock};
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::validate::{ticket_roles, validate_ata};
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED};
 
 /// Cancel a ticket - ONLY FiatGuy can cancel, ONLY before they sign
 /// Always refunds tokens to CryptoGuy and auto-closes order + vault
@@ -23,11 +26,13 @@ pub fn cancel_ticket(
 
     require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
 
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
     // Identify parties
-    let crypto_guy = if is_sell { order_creator } else { ticket.acceptor };
-    let fiat_guy = if is_sell { ticket.acceptor } else { order_creator };
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell, order_creator, ticket.acceptor);
 
-    // CHECK: Only FiatGuy can cancel
+    // CHECK: Only FiatGuy can cancel (never the admin: offers reject it as FiatGuy)
     require!(canceller.key() == fiat_guy, UniversalOrderError::Unauthorized);
     
     // CHECK: Can only cancel before FiatGuy signs
@@ -36,15 +41,14 @@ pub fn cancel_ticket(
     // Get CryptoGuy's token account for refund
     let crypto_guy_ata = ctx.accounts.crypto_guy_token_account.as_ref()
         .ok_or(UniversalOrderError::TokenAccountRequired)?;
-    require!(crypto_guy_ata.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-    require!(crypto_guy_ata.owner == crypto_guy, UniversalOrderError::Unauthorized);
+    validate_ata(crypto_guy_ata, &order_mint, &crypto_guy)?;
     
     // Get mint decimals
     let decimals = ctx.accounts.mint.decimals;
 
     // Prepare PDA signer
     let signer_seeds = &[
-        b"universal_order",
+        ORDER_SEED,
         order_creator.as_ref(),
         order_mint.as_ref(),
         order_id_le.as_ref(),
@@ -52,7 +56,7 @@ pub fn cancel_ticket(
     ];
     let signer = &[&signer_seeds[..]];
 
-    // Refund tokens from vault to CryptoGuy
+    // Refund tokens (and any prepaid fee) from vault to CryptoGuy
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         TransferChecked {
@@ -63,7 +67,19 @@ pub fn cancel_ticket(
         },
         signer,
     );
-    transfer_checked(transfer_ctx, ticket.amount, decimals)?;
+    transfer_checked(transfer_ctx, ticket.locked_amount()?, decimals)?;
+    {
+        let order = &mut ctx.accounts.order;
+        order.reserved_amount = order.reserved_amount.checked_sub(ticket.amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.reserved_fees = order.reserved_fees.checked_sub(ticket.prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.refund_count = order.refund_count.saturating_add(1);
+        // Refund leaves the order - shrink target so remaining tickets can still complete it
+        order.crypto_amount = order.crypto_amount.checked_sub(ticket.amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        release_order_lock(order);
+    }
 
     // Emit cancellation event
     emit!(crate::universal::events::TicketCancelled {
@@ -72,60 +88,20 @@ pub fn cancel_ticket(
         canceller: canceller.key(),
         amount: ticket.amount,
         refunded: true,
+        reference: ticket.reference,
         timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
     });
 
-    // Read vault balance directly after transfer
-    let vault_account = ctx.accounts.vault.to_account_info();
-    let vault_data = vault_account.try_borrow_data()?;
-    let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
-    drop(vault_data);
-
-    // AUTO-CLOSE: Cancel means order is cancelled, close if vault is empty
-    if vault_balance == 0 {
-        let order = &ctx.accounts.order;
-        msg!("Auto-closing vault and order after cancel, returning rent to admin.");
-        
-        let order_creator = order.creator;
-        let order_mint = order.crypto_mint;
-        let order_id_le = order.order_id.to_le_bytes();
-        let order_bump = order.bump;
-
-        let seeds = &[
-            b"universal_order".as_ref(),
-            order_creator.as_ref(),
-            order_mint.as_ref(),
-            order_id_le.as_ref(),
-            &[order_bump],
-        ];
-        let signer = &[&seeds[..]];
-
-        let close_vault_accounts = CloseAccount {
-            account: ctx.accounts.vault.to_account_info(),
-            destination: ctx.accounts.admin_rent_receiver.to_account_info(),
-            authority: ctx.accounts.order.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            close_vault_accounts,
-            signer,
-        );
-
-        close_account(cpi_ctx)?;
-        msg!("Vault closed, rent returned to admin");
-
-        // Close order account and return rent to admin
-        ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-        msg!("Order closed, rent returned to admin");
-
-        // Close ticket last
-        ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-        msg!("Ticket closed, rent returned to admin");
-    } else {
-        // If vault not empty, just close ticket
-        ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-    }
+    // AUTO-CLOSE: ticket always; vault + order if empty and no reservations left
+    close_ticket_and_maybe_order(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        &mut ctx.accounts.ticket,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        true,
+    )?;
 
     Ok(())
 }
@@ -143,30 +119,42 @@ pub struct CancelTicket<'info> {
     #[account(mut)]
     pub canceller: Signer<'info>,
 
-    /// CHECK: Admin wallet receives rent back (validated by address constraint)
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
     #[account(
         mut,
-        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
     )]
     pub admin_rent_receiver: UncheckedAccount<'info>,
 
+    /// Global Config PDA (rent_receiver, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     /// Parent order (will be closed)
     #[account(
         mut,
-        seeds = [b"universal_order", order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
         bump = order.bump
     )]
     pub order: Account<'info, UniversalOrder>,
     
-    /// Mint account - needed for transfer_checked
+    /// Mint account - needed for transfer_checked (must be the order's mint, checked before the vault)
+    #[account(
+        constraint = mint.key() == order.crypto_mint @ UniversalOrderError::InvalidMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// Vault (will be closed after refund) - supports both SPL Token and Token-2022
     #[account(
         mut,
-        seeds = [b"vault", order.key().as_ref()],
-        bump,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
         constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority,
         constraint = vault.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
@@ -174,7 +162,7 @@ pub struct CancelTicket<'info> {
     /// Ticket to cancel (will be closed)
     #[account(
         mut,
-        seeds = [b"ticket", order.key().as_ref(), ticket.ticket_id.to_le_bytes().as_ref()],
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
         bump = ticket.bump
     )]
     pub ticket: Account<'info, FillTicket>,
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_lang::prelude::AccountsClose;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::seeds::{RECEIPT_SEED, CONFIG_SEED};
+
+/// Close a SettlementReceipt once it is no longer needed (e.g. after the retention period)
+/// Rent goes back to config.rent_receiver - the admin paid it at settlement
+pub(crate) fn close_receipt(
+    ctx: Context<CloseReceipt>,
+) -> Result<()> {
+    let ticket = ctx.accounts.receipt.ticket;
+    ctx.accounts.receipt.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
+    msg!("Receipt for ticket {} closed", ticket);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseReceipt<'info> {
+    /// Admin only
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (rent_receiver)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Receipt PDA to close
+    #[account(
+        mut,
+        seeds = [RECEIPT_SEED, receipt.ticket.as_ref()],
+        bump = receipt.bump
+    )]
+    pub receipt: Account<'info, SettlementReceipt>,
+}
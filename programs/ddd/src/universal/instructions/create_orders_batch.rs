@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked,
+    initialize_account3, InitializeAccount3,
+};
+use crate::constants::MAX_BATCH_ORDERS;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::guard::{require_remaining_accounts, debug_assert_consistency};
+use crate::universal::utils::init_pda::create_pda_account;
+use crate::universal::utils::token_checks::token_account_space;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED};
+use super::accept_offer_and_lock::{
+    OfferArgs, OfferBumps, OfferOptions, OfferPdas, init_offer, emit_offer_accepted,
+    offer_locked_amount, validate_delegates, validate_locker_ata, validate_offer_parties,
+};
+
+/// One rung of a batch: the per-order arguments of accept_offer_and_lock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOrder {
+    pub order_id: u64,
+    pub crypto_amount: u64,
+    pub fiat_amount: u64,
+}
+
+/// Market-maker ladder: accept_offer_and_lock for up to MAX_BATCH_ORDERS orders in one transaction
+/// All orders share the locker, creator, direction, ticket_id and `options` (OfferOptions, as in
+/// accept_offer_and_lock); each gets its own order/vault/ticket PDAs, passed in remaining_accounts
+/// as [order, vault, ticket] per entry (writable). Fee-on-top prepaid fees are computed per rung.
+/// `options.vault_authority` can only be left unset: each rung's vault authority is its own order PDA
+pub(crate) fn create_orders_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateOrdersBatch<'info>>,
+    orders: Vec<BatchOrder>,
+    ticket_id: u64,
+    is_sell_order: bool,
+    creator: Pubkey,
+    options: OfferOptions,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // CHECK: Settlement signs vault CPIs with the order seeds, and one authority can't be every
+    // rung's order PDA
+    require!(options.vault_authority.is_none(), UniversalOrderError::InvalidVaultAuthority);
+
+    // CHECK: Batch size capped for compute
+    require!(!orders.is_empty() && orders.len() <= MAX_BATCH_ORDERS, UniversalOrderError::InvalidBatchSize);
+    require_remaining_accounts(ctx.remaining_accounts, orders.len() * 3)?;
+
+    let mint_key = ctx.accounts.mint.key();
+    validate_locker_ata(
+        &ctx.accounts.config,
+        ctx.accounts.locker.key(),
+        ctx.accounts.locker_token_account.key(),
+        mint_key,
+        ctx.accounts.token_program.key(),
+    )?;
+    let parties = validate_offer_parties(
+        &ctx.accounts.config,
+        &ctx.accounts.locker.to_account_info(),
+        ctx.accounts.creator_signer.as_ref().map(|s| s.key()),
+        is_sell_order,
+        creator,
+        options.fiat_guy,
+    )?;
+    validate_delegates(options.crypto_guy_signer, options.fiat_guy_signer, &parties)?;
+
+    // CHECK: Locker can fund the whole ladder, prepaid fees included
+    let mut total: u64 = 0;
+    let mut locked: Vec<(u64, u64)> = Vec::with_capacity(orders.len());
+    let mut seen: Vec<u64> = Vec::with_capacity(orders.len());
+    for entry in orders.iter() {
+        // CHECK: Each order id once (its PDAs would collide)
+        require!(!seen.contains(&entry.order_id), UniversalOrderError::DuplicateOrder);
+        seen.push(entry.order_id);
+        // CHECK: Advisory notional floor, per rung
+        require!(entry.fiat_amount >= ctx.accounts.config.min_fiat_amount, UniversalOrderError::NotionalTooSmall);
+        let (prepaid_fee, locked_amount) = offer_locked_amount(
+            &ctx.accounts.config,
+            &mint_key,
+            entry.crypto_amount,
+            options.fee_on_top,
+        )?;
+        locked.push((prepaid_fee, locked_amount));
+        total = total.checked_add(locked_amount).ok_or(UniversalOrderError::InvalidAmount)?;
+    }
+    require!(ctx.accounts.locker_token_account.amount >= total, UniversalOrderError::InsufficientBalance);
+
+    let vault_space = token_account_space(&ctx.accounts.mint.to_account_info())?;
+    let payer = ctx.accounts.fee_payer.to_account_info();
+    let system_program = ctx.accounts.system_program.to_account_info();
+    let token_program = ctx.accounts.token_program.to_account_info();
+
+    for ((entry, accounts), (prepaid_fee, locked_amount)) in orders.iter()
+        .zip(ctx.remaining_accounts.chunks(3))
+        .zip(locked)
+    {
+        let (order_info, vault_info, ticket_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        let order_id_le = entry.order_id.to_le_bytes();
+
+        // CHECK: Each account sits at its canonical PDA
+        let (order_key, order_bump) = Pubkey::find_program_address(
+            &[ORDER_SEED, creator.as_ref(), mint_key.as_ref(), order_id_le.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(*order_info.key, order_key, ErrorCode::ConstraintSeeds);
+        let (vault_key, vault_bump) = Pubkey::find_program_address(&[VAULT_SEED, order_key.as_ref()], ctx.program_id);
+        require_keys_eq!(*vault_info.key, vault_key, ErrorCode::ConstraintSeeds);
+        let (ticket_key, ticket_bump) = Pubkey::find_program_address(
+            &[TICKET_SEED, order_key.as_ref(), 0u64.to_le_bytes().as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(*ticket_info.key, ticket_key, ErrorCode::ConstraintSeeds);
+
+        // Create order + ticket (program-owned) and the vault token account (authority = order PDA)
+        create_pda_account(
+            &payer, order_info, &system_program, UniversalOrder::SPACE, ctx.program_id,
+            &[ORDER_SEED, creator.as_ref(), mint_key.as_ref(), order_id_le.as_ref(), &[order_bump]],
+        )?;
+        create_pda_account(
+            &payer, ticket_info, &system_program, FillTicket::SPACE, ctx.program_id,
+            &[TICKET_SEED, order_key.as_ref(), 0u64.to_le_bytes().as_ref(), &[ticket_bump]],
+        )?;
+        create_pda_account(
+            &payer, vault_info, &system_program, vault_space, token_program.key,
+            &[VAULT_SEED, order_key.as_ref(), &[vault_bump]],
+        )?;
+        initialize_account3(CpiContext::new(
+            token_program.clone(),
+            InitializeAccount3 {
+                account: vault_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: order_info.clone(),
+            },
+        ))?;
+
+        let pdas = OfferPdas { order: order_key, vault: vault_key, ticket: ticket_key };
+        let mut order: Account<'info, UniversalOrder> = Account::try_from_unchecked(order_info)?;
+        let mut ticket: Account<'info, FillTicket> = Account::try_from_unchecked(ticket_info)?;
+        let args = OfferArgs {
+            order_id: entry.order_id,
+            ticket_id,
+            crypto_amount: entry.crypto_amount,
+            fiat_amount: entry.fiat_amount,
+            is_sell_order,
+            creator,
+            crypto_mint: mint_key,
+            crypto_first: options.crypto_first,
+            vault_authority: order_key,
+            payout_destination: options.payout_destination,
+            reference: options.reference,
+            fee_destination: options.fee_destination,
+            fee_on_top: options.fee_on_top,
+            prepaid_fee,
+            crypto_guy_signer: options.crypto_guy_signer,
+            fiat_guy_signer: options.fiat_guy_signer,
+            allowed_takers: options.allowed_takers.clone(),
+        };
+        let bumps = OfferBumps { order: order_bump, vault: vault_bump, ticket: ticket_bump };
+        init_offer(&mut order, &mut ticket, &pdas, &args, &parties, &bumps, clock.unix_timestamp)?;
+        order.exit(ctx.program_id)?;
+        ticket.exit(ctx.program_id)?;
+
+        // Transfer tokens from CryptoGuy to this order's vault
+        let transfer_ctx = CpiContext::new(
+            token_program.clone(),
+            TransferChecked {
+                from: ctx.accounts.locker_token_account.to_account_info(),
+                to: vault_info.clone(),
+                authority: ctx.accounts.locker.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        );
+        transfer_checked(transfer_ctx, locked_amount, ctx.accounts.mint.decimals)?;
+
+        // CHECK: Vault received the full amount (same invariant as accept_offer_and_lock)
+        let vault_data = vault_info.try_borrow_data()?;
+        let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
+        drop(vault_data);
+        require!(vault_balance == locked_amount, UniversalOrderError::LockAmountMismatch);
+        debug_assert_consistency(&order, ctx.accounts.config.strict_invariants)?;
+
+        let seq = ctx.accounts.config.next_event_seq();
+        emit_offer_accepted(&order, &ticket, &pdas, &parties, seq, clock.unix_timestamp);
+    }
+
+    msg!("Created {} orders, locked {}", orders.len(), total);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateOrdersBatch<'info> {
+    /// Admin pays rent AND transaction fee (first signer = pays transaction fee)
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub fee_payer: Signer<'info>,
+
+    /// CryptoGuy who locks the tokens for every order (second signer)
+    #[account(mut)]
+    pub locker: Signer<'info>,
+
+    /// Order creator co-signature (BUY orders only, required when config.require_creator_sig)
+    pub creator_signer: Option<Signer<'info>>,
+
+    /// Global Config PDA (min_fiat_amount, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Crypto mint (USDC, etc.) - supports both SPL Token and Token-2022
+    #[account(
+        constraint = *mint.to_account_info().owner == token_program.key() @ UniversalOrderError::InvalidTokenProgram
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CryptoGuy's token account (source of all locked tokens and prepaid fees)
+    #[account(
+        mut,
+        constraint = locker_token_account.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount,
+        constraint = locker_token_account.owner == locker.key() @ UniversalOrderError::Unauthorized
+    )]
+    pub locker_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
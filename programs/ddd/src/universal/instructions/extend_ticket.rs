@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::TicketExtended;
+use crate::constants::ADMIN_PUBKEY;
+use crate::universal::utils::validate::ticket_roles;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Give the FiatGuy more time to pay (slow bank transfer) without cancel + re-lock.
+/// FiatGuy or admin only, while the ticket is unsigned by FiatGuy and not yet expired.
+/// All extensions together may push the expiry out by at most config.max_ticket_extension_secs
+/// past the original deadline (created_at + fiat_sign_grace_secs)
+pub(crate) fn extend_ticket(
+    ctx: Context<ExtendTicket>,
+    new_expires_at: i64,
+) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let ticket = &mut ctx.accounts.ticket;
+    let signer = ctx.accounts.signer.key();
+    let config = &ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order.key(), UniversalOrderError::Unauthorized);
+
+    // CHECK: Only the FiatGuy or admin can extend
+    let (_, fiat_guy) = ticket_roles(order.is_sell_order, order.creator, ticket.acceptor);
+    require!(signer == fiat_guy || signer == ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Nothing left to wait for once FiatGuy has signed
+    require!(!ticket.fiat_guy_signed, UniversalOrderError::TicketAlreadySigned);
+
+    // CHECK: Still within the signing window (expired tickets belong to sign_or_timeout)
+    let old_expires_at = ticket.expires_at(config.fiat_sign_grace_secs);
+    require!(clock.unix_timestamp < old_expires_at, UniversalOrderError::TicketExpired);
+
+    // CHECK: Strictly later, and never past creation + grace + max_ticket_extension_secs in total,
+    // so repeated extensions can't keep the crypto locked indefinitely
+    require!(new_expires_at > old_expires_at, UniversalOrderError::ExtensionNotLater);
+    require!(
+        new_expires_at <= ticket.max_expires_at(config.fiat_sign_grace_secs, config.max_ticket_extension_secs),
+        UniversalOrderError::ExtensionTooLong
+    );
+
+    ticket.extended_until = new_expires_at;
+
+    emit!(TicketExtended {
+        order: order.key(),
+        ticket: ticket.key(),
+        old_expires_at,
+        new_expires_at,
+        extended_by: signer,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket {} extended: {} -> {}", ticket.ticket_id, old_expires_at, new_expires_at);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExtendTicket<'info> {
+    /// FiatGuy or admin
+    pub signer: Signer<'info>,
+
+    /// Parent order PDA
+    #[account(
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket PDA to extend
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// Global Config PDA (fiat_sign_grace_secs, max_ticket_extension_secs)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::utils::seeds::{ORDER_SEED, CONFIG_SEED};
+
+/// Settlements an order can still take in its current daily window
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct FillBudget {
+    pub max_fills_per_day: u16,
+    pub fills_today: u16,
+    pub remaining_fills: u16,
+    /// Seconds until the daily window resets (0 = no window open, full budget available)
+    pub window_resets_in: i64,
+}
+
+/// Read-only view of the order's daily settlement budget, returned via return data
+/// Uses the same window math as sign_ticket, so takers don't have to reconstruct it. Informational:
+/// the budget is not enforced, settlement past it still goes through
+pub(crate) fn fill_budget(
+    ctx: Context<FillBudgetView>,
+) -> Result<FillBudget> {
+    let now = Clock::get()?.unix_timestamp;
+    let order = &ctx.accounts.order;
+    let max_fills_per_day = ctx.accounts.config.max_fills_per_day;
+    let fills_today = order.daily_fills(now);
+
+    Ok(FillBudget {
+        max_fills_per_day,
+        fills_today,
+        remaining_fills: order.remaining_fills(max_fills_per_day, now),
+        window_resets_in: order.daily_window_resets_in(now),
+    })
+}
+
+#[derive(Accounts)]
+pub struct FillBudgetView<'info> {
+    /// Order PDA to inspect
+    #[account(
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Global Config PDA (max_fills_per_day)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
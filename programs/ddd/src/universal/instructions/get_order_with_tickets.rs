@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::utils::guard::{require_remaining_accounts, load_order_ticket};
+use crate::universal::utils::seeds::ORDER_SEED;
+
+/// Per-ticket metadata returned by get_order_with_tickets
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct TicketSummary {
+    pub index: u64,
+    pub ticket_id: u64,
+    pub acceptor: Pubkey,
+    pub amount: u64,
+    pub crypto_guy_signed: bool,
+    pub fiat_guy_signed: bool,
+    pub created_at: i64,
+    pub last_sign_ts: i64,
+}
+
+/// Order + tickets snapshot (tickets in the order they were passed)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct OrderWithTickets {
+    pub order: Pubkey,
+    pub crypto_amount: u64,
+    pub filled_amount: u64,
+    pub reserved_amount: u64,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub tickets: Vec<TicketSummary>,
+}
+
+/// Read-only snapshot of an order and the `ticket_count` tickets passed in remaining_accounts, returned via return data
+/// Return data is capped at 1024 bytes, so keep ticket lists short (~14 max)
+pub(crate) fn get_order_with_tickets(
+    ctx: Context<GetOrderWithTickets>,
+    ticket_count: u8,
+) -> Result<OrderWithTickets> {
+    // CHECK: Exactly `ticket_count` tickets passed
+    require_remaining_accounts(ctx.remaining_accounts, ticket_count as usize)?;
+
+    let order = &ctx.accounts.order;
+    let order_key = order.key();
+
+    let mut tickets = Vec::with_capacity(ctx.remaining_accounts.len());
+    for info in ctx.remaining_accounts.iter() {
+        let ticket = load_order_ticket(info, &order_key)?;
+
+        tickets.push(TicketSummary {
+            index: ticket.index,
+            ticket_id: ticket.ticket_id,
+            acceptor: ticket.acceptor,
+            amount: ticket.amount,
+            crypto_guy_signed: ticket.crypto_guy_signed,
+            fiat_guy_signed: ticket.fiat_guy_signed,
+            created_at: ticket.created_at,
+            last_sign_ts: ticket.last_sign_ts,
+        });
+    }
+
+    Ok(OrderWithTickets {
+        order: order_key,
+        crypto_amount: order.crypto_amount,
+        filled_amount: order.filled_amount,
+        reserved_amount: order.reserved_amount,
+        created_at: order.created_at,
+        updated_at: order.updated_at,
+        tickets,
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetOrderWithTickets<'info> {
+    /// Order PDA to summarize (tickets go in remaining_accounts)
+    #[account(
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::utils::fees::FEE_BASIS_POINTS;
+use crate::universal::utils::seeds::CONFIG_SEED;
+
+/// Program/Config liveness snapshot for monitors
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+pub struct HealthStatus {
+    pub admin: Pubkey,
+    pub fee_basis_points: u64,
+    pub version: String,
+}
+
+/// Read-only health check, returned via return data
+/// Succeeds only if the Config PDA exists (i.e. initialize_config has run)
+pub(crate) fn health_check(
+    _ctx: Context<HealthCheck>,
+) -> Result<HealthStatus> {
+    Ok(HealthStatus {
+        admin: crate::constants::ADMIN_PUBKEY,
+        fee_basis_points: FEE_BASIS_POINTS,
+        version: env!("CARGO_PKG_VERSION").to_string(),
+    })
+}
+
+#[derive(Accounts)]
+pub struct HealthCheck<'info> {
+    /// Global Config PDA (must be initialized)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::constants::{FILL_COOLDOWN_SECS, MAX_FILLS_PER_DAY, MAX_MINT_FEE_OVERRIDES, FIAT_SIGN_GRACE_SECS, MAX_TICKET_EXTENSION_SECS, MIN_FILL_DENOMINATOR, MAX_ORDER_ACCEPTORS};
+use crate::universal::utils::seeds::CONFIG_SEED;
+
+/// Create the global Config PDA with defaults matching the compile-time constants
+pub(crate) fn initialize_config(
+    ctx: Context<InitializeConfig>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.fill_cooldown_secs = FILL_COOLDOWN_SECS;
+    config.max_fills_per_day = MAX_FILLS_PER_DAY;
+    config.require_ata = false;
+    config.min_fee = 0;
+    config.mint_fees = [MintFee::default(); MAX_MINT_FEE_OVERRIDES];
+    config.require_creator_sig = false;
+    config.fiat_sign_grace_secs = FIAT_SIGN_GRACE_SECS;
+    config.require_immutable_fee_owner = false;
+    config.rent_receiver = crate::constants::ADMIN_PUBKEY;
+    config.max_ticket_extension_secs = MAX_TICKET_EXTENSION_SECS;
+    config.min_fill_denominator = MIN_FILL_DENOMINATOR;
+    config.max_distinct_acceptors = MAX_ORDER_ACCEPTORS as u8;
+    config.event_seq = 0;
+    config.min_fiat_amount = 0;
+    config.strict_invariants = false;
+    config.bump = ctx.bumps.config;
+
+    msg!("Config initialized: fill_cooldown_secs={}, max_fills_per_day={}", config.fill_cooldown_secs, config.max_fills_per_day);
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    /// Admin pays rent for the Config PDA
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global Config PDA (created here)
+    #[account(
+        init,
+        payer = admin,
+        space = Config::SPACE,
+        seeds = [CONFIG_SEED],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
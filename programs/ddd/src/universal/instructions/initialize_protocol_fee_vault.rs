@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::seeds::{CONFIG_SEED, PROTOCOL_FEE_VAULT_SEED};
+
+/// Create the per-mint protocol fee vault (owned by the Config PDA) that sweep_fees deposits into
+pub(crate) fn initialize_protocol_fee_vault(
+    ctx: Context<InitializeProtocolFeeVault>,
+) -> Result<()> {
+    msg!("Protocol fee vault initialized for mint {}", ctx.accounts.mint.key());
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeProtocolFeeVault<'info> {
+    /// Admin pays rent for the fee vault
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global Config PDA (fee vault authority)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Crypto mint - supports both SPL Token and Token-2022
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Protocol fee vault PDA (created here)
+    #[account(
+        init,
+        payer = admin,
+        seeds = [PROTOCOL_FEE_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = config,
+        token::token_program = token_program
+    )]
+    pub protocol_fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
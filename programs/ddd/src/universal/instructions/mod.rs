@@ -1,9 +1,60 @@
 pub mod accept_offer_and_lock;
+pub mod create_orders_batch;
 pub mod sign_ticket;
+pub mod sign_ticket_with_ed25519;
+pub mod unsign_ticket;
 pub mod cancel_ticket;
+pub mod withdraw_ticket;
+pub mod sign_or_timeout;
+pub mod extend_ticket;
+pub mod set_unwrap_to_sol;
+pub mod cancel_all_unsigned;
 pub mod admin_resolve_ticket;
+pub mod admin_split_resolve_ticket;
+pub mod admin_three_way_resolve;
+pub mod reassign_ticket;
+pub mod split_ticket;
+pub mod reconcile_reserved;
+pub mod admin_force_close_order;
+pub mod reclaim_rent;
+pub mod close_receipt;
+pub mod admin_recover_excess;
+pub mod initialize_config;
+pub mod update_config;
+pub mod initialize_protocol_fee_vault;
+pub mod sweep_fees;
+pub mod preview_settlement;
+pub mod fill_budget;
+pub mod rent_estimate;
+pub mod get_order_with_tickets;
+pub mod health_check;
 
 pub use accept_offer_and_lock::*;
+pub use create_orders_batch::*;
 pub use sign_ticket::*;
+pub use sign_ticket_with_ed25519::*;
+pub use unsign_ticket::*;
 pub use cancel_ticket::*;
-pub use admin_resolve_ticket::*;
\ No newline at end of file
+pub use withdraw_ticket::*;
+pub use sign_or_timeout::*;
+pub use extend_ticket::*;
+pub use set_unwrap_to_sol::*;
+pub use cancel_all_unsigned::*;
+pub use admin_resolve_ticket::*;
+pub use admin_split_resolve_ticket::*;
+pub use reassign_ticket::*;
+pub use split_ticket::*;
+pub use reconcile_reserved::*;
+pub use admin_force_close_order::*;
+pub use reclaim_rent::*;
+pub use close_receipt::*;
+pub use admin_recover_excess::*;
+pub use initialize_config::*;
+pub use update_config::*;
+pub use initialize_protocol_fee_vault::*;
+pub use sweep_fees::*;
+pub use preview_settlement::*;
+pub use fill_budget::*;
+pub use rent_estimate::*;
+pub use get_order_with_tickets::*;
+pub use health_check::*;
\ No newline at end of file
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::utils::fees::calculate_fee;
+use crate::universal::utils::seeds::CONFIG_SEED;
+
+/// Fee split a ticket of `amount` in `mint` would settle with
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct SettlementPreview {
+    pub fee_amount: u64,
+    pub net_amount: u64,
+}
+
+/// Read-only quote of the settlement split, returned via return data
+/// Uses the same calculate_fee as sign_ticket/admin_resolve_ticket so client math stays in lockstep
+pub(crate) fn preview_settlement(
+    ctx: Context<PreviewSettlement>,
+    amount: u64,
+    mint: Pubkey,
+) -> Result<SettlementPreview> {
+    let config = &ctx.accounts.config;
+    let (fee_amount, net_amount) = calculate_fee(amount, config.fee_bps_for(&mint), config.min_fee_for(&mint))?;
+    Ok(SettlementPreview { fee_amount, net_amount })
+}
+
+#[derive(Accounts)]
+pub struct PreviewSettlement<'info> {
+    /// Global Config PDA (fee floor)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::TicketReassigned;
+use crate::constants::ADMIN_PUBKEY;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Admin reassign: hand an unsigned ticket to a new counterparty without cancel + re-lock.
+/// SELL orders only - on BUY orders the acceptor is the CryptoGuy who funded the vault,
+/// so swapping them would redirect the refund.
+pub fn reassign_ticket(
+    ctx: Context<ReassignTicket>,
+    new_acceptor: Pubkey,
+) -> Result<()> {
+    let order = &mut ctx.accounts.order;
+    let ticket = &mut ctx.accounts.ticket;
+    let clock = Clock::get()?;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order.key(), UniversalOrderError::Unauthorized);
+
+    // CHECK: Only the FiatGuy side (SELL acceptor) can be swapped
+    require!(order.is_sell_order, UniversalOrderError::InvalidOrderType);
+
+    // CHECK: Nobody has signed yet
+    require!(
+        !ticket.fiat_guy_signed && !ticket.crypto_guy_signed,
+        UniversalOrderError::TicketAlreadySigned
+    );
+
+    // CHECK: New acceptor must be a real counterparty
+    require_keys_neq!(new_acceptor, order.creator, UniversalOrderError::Unauthorized);
+    require_keys_neq!(new_acceptor, ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Private orders only move to a whitelisted taker
+    order.check_taker(&new_acceptor)?;
+
+    // CHECK: Bounded dispute surface - a new counterparty only while under config.max_distinct_acceptors
+    order.track_acceptor(new_acceptor, ctx.accounts.config.max_distinct_acceptors)?;
+
+    let old_acceptor = ticket.acceptor;
+    ticket.acceptor = new_acceptor;
+    // The old FiatGuy's payout wallet, signing key and preferences don't carry over
+    ticket.payout_destination = None;
+    ticket.fiat_guy_signer = None;
+    ticket.unwrap_to_sol = false;
+
+    emit!(TicketReassigned {
+        order: order.key(),
+        ticket: ticket.key(),
+        old_acceptor,
+        new_acceptor,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket {} reassigned: {} -> {}", ticket.ticket_id, old_acceptor, new_acceptor);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReassignTicket<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Parent order PDA (records the new acceptor)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket PDA to reassign
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// Global Config PDA (max_distinct_acceptors)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::auto_close::close_order_and_vault;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, CONFIG_SEED};
+
+/// Close a leaked order: fully filled, nothing reserved and an empty vault, but never closed
+/// because no terminal instruction ran (e.g. its last settlement left dust that was recovered later)
+/// Permissionless - rent only ever goes to config.rent_receiver. Vaults holding dust go through sweep_fees
+pub(crate) fn reclaim_rent(
+    ctx: Context<ReclaimRent>,
+) -> Result<()> {
+    let order = &ctx.accounts.order;
+
+    // CHECK: Order is done - nothing reserved, nothing left to fill
+    require!(order.reserved_amount == 0, UniversalOrderError::OrderHasActiveTickets);
+    require!(order.remaining_amount() == 0, UniversalOrderError::OrderNotComplete);
+
+    // CHECK: Fully empty vault - never closes over tokens
+    require!(ctx.accounts.vault.amount == 0, UniversalOrderError::VaultNotEmpty);
+
+    close_order_and_vault(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        0,
+    )?;
+    msg!("Leaked order closed by {}", ctx.accounts.caller.key());
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReclaimRent<'info> {
+    /// Anyone (pays only the transaction fee)
+    pub caller: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (rent_receiver)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Leaked order PDA (will be closed)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Order vault PDA (must be empty, will be closed)
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
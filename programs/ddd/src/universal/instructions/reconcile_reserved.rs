@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::ReservedReconciled;
+use crate::universal::utils::guard::{require_remaining_accounts, load_order_ticket};
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED};
+
+/// Admin repair: reset order.reserved_amount (and reserved_fees) to the sum of the open tickets passed in remaining_accounts
+/// The admin must pass ALL open tickets - the program can't enumerate them on-chain
+pub(crate) fn reconcile_reserved(
+    ctx: Context<ReconcileReserved>,
+    ticket_count: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // CHECK: Exactly `ticket_count` tickets passed
+    require_remaining_accounts(ctx.remaining_accounts, ticket_count as usize)?;
+
+    let order_key = ctx.accounts.order.key();
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut new_reserved: u64 = 0;
+    let mut new_reserved_fees: u64 = 0;
+    for info in ctx.remaining_accounts.iter() {
+        // CHECK: Each ticket counted once
+        require!(!seen.contains(info.key), UniversalOrderError::DuplicateTicket);
+        seen.push(*info.key);
+
+        let ticket = load_order_ticket(info, &order_key)?;
+        new_reserved = new_reserved
+            .checked_add(ticket.amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        new_reserved_fees = new_reserved_fees
+            .checked_add(ticket.prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+    }
+
+    // CHECK: Tickets can't reserve more than the vault holds
+    let new_locked = new_reserved.checked_add(new_reserved_fees).ok_or(UniversalOrderError::InvalidAmount)?;
+    require!(new_locked <= ctx.accounts.vault.amount, UniversalOrderError::InsufficientBalance);
+
+    let order = &mut ctx.accounts.order;
+    let old_reserved = order.reserved_amount;
+    order.reserved_amount = new_reserved;
+    order.reserved_fees = new_reserved_fees;
+    order.updated_at = clock.unix_timestamp;
+
+    emit!(ReservedReconciled {
+        order: order_key,
+        old_reserved,
+        new_reserved,
+        ticket_count,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Reserved reconciled: {} -> {} ({} tickets)", old_reserved, new_reserved, ticket_count);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReconcileReserved<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Order PDA to repair (open tickets go in remaining_accounts)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Order vault PDA (upper bound for reserved)
+    #[account(
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
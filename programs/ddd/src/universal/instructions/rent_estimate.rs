@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::universal::state::*;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED};
+
+/// Lamports returned to config.rent_receiver when the order closes out
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct RentEstimate {
+    pub order_rent: u64,
+    /// Sized from the actual vault (Token-2022 extensions included)
+    pub vault_rent: u64,
+    /// Per ticket; every ticket returns this when it settles or is cancelled
+    pub ticket_rent: u64,
+    /// order + vault + one ticket: the refund of the final close-out
+    pub total: u64,
+}
+
+/// Read-only view of the close-out rent refund, returned via return data
+/// Rent-exempt minimums from Rent::get() for UniversalOrder::SPACE, the vault and FillTicket::SPACE
+pub(crate) fn rent_estimate(
+    ctx: Context<RentEstimateView>,
+) -> Result<RentEstimate> {
+    let rent = Rent::get()?;
+    let order_rent = rent.minimum_balance(UniversalOrder::SPACE);
+    let vault_rent = rent.minimum_balance(ctx.accounts.vault.to_account_info().data_len());
+    let ticket_rent = rent.minimum_balance(FillTicket::SPACE);
+
+    Ok(RentEstimate {
+        order_rent,
+        vault_rent,
+        ticket_rent,
+        total: order_rent + vault_rent + ticket_rent,
+    })
+}
+
+#[derive(Accounts)]
+pub struct RentEstimateView<'info> {
+    /// Order PDA to inspect
+    #[account(
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Order vault PDA (its size depends on the mint's token program and extensions)
+    #[account(
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+}
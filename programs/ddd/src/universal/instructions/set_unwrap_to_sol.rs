@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::validate::ticket_roles;
+use crate::universal::utils::token_checks::is_native_mint;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED};
+
+/// FiatGuy opts in (or out) of receiving the payout as native SOL instead of WSOL.
+/// WSOL orders only; can be changed until the ticket settles
+pub(crate) fn set_unwrap_to_sol(
+    ctx: Context<SetUnwrapToSol>,
+    unwrap_to_sol: bool,
+) -> Result<()> {
+    let order = &ctx.accounts.order;
+    let ticket = &mut ctx.accounts.ticket;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order.key(), UniversalOrderError::Unauthorized);
+
+    // CHECK: Only the FiatGuy (the payout side) chooses the payout form
+    let (_, fiat_guy) = ticket_roles(order.is_sell_order, order.creator, ticket.acceptor);
+    require_keys_eq!(ctx.accounts.fiat_guy.key(), fiat_guy, UniversalOrderError::Unauthorized);
+
+    // CHECK: Only wrapped SOL can be unwrapped
+    if unwrap_to_sol {
+        require!(is_native_mint(&order.crypto_mint), UniversalOrderError::InvalidMint);
+    }
+
+    ticket.unwrap_to_sol = unwrap_to_sol;
+
+    msg!("Ticket {} unwrap_to_sol = {}", ticket.ticket_id, unwrap_to_sol);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetUnwrapToSol<'info> {
+    /// FiatGuy of the ticket
+    pub fiat_guy: Signer<'info>,
+
+    /// Parent order PDA
+    #[account(
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket PDA to update
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+}
@@ -1,13 +1,29 @@
 use anchor_lang::prelude::*;
-use anchor_lang::prelude::AccountsClose; // for conditional account close
-use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked, close_account, CloseAccount};
+use anchor_spl::token_interface::{
+    TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked,
+    initialize_account3, InitializeAccount3, close_account, CloseAccount,
+};
 use crate::universal::state::*;
 use crate::universal::errors::UniversalOrderError;
-use crate::universal::utils::fees::calculate_fee;
+use crate::universal::utils::fees::settlement_split;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock, debug_assert_consistency};
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::token_checks::{is_native_mint, token_account_space};
+use crate::universal::utils::fee_transfer::pay_fee;
+use crate::universal::utils::init_pda::create_pda_account;
+use crate::universal::utils::validate::{validate_signer, validate_ata};
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED, UNWRAP_SEED, RECEIPT_SEED};
 
 /// Sign a specific ticket; on both signatures, settle that ticket amount
+/// Delegated signers (ticket.crypto_guy_signer / fiat_guy_signer) sign instead of the fund wallets;
+/// the payout still goes to the FiatGuy (or payout_destination)
+/// `client_nonce` makes retries idempotent: replaying the nonce of the signer's recorded signature
+/// fails with DuplicateRequest (already done) instead of RaceCondition (a second, different attempt)
+/// `create_receipt` on the settling signature writes a SettlementReceipt PDA (rent paid by the admin)
 pub fn sign_ticket(
     ctx: Context<SignTicket>,
+    client_nonce: Option<u64>,
+    create_receipt: bool,
 ) -> Result<()> {
     let ticket = &mut ctx.accounts.ticket;
     let signer = &ctx.accounts.signer;
@@ -19,26 +35,64 @@ pub fn sign_ticket(
     let order_id_le = ctx.accounts.order.order_id.to_le_bytes();
     let order_bump = ctx.accounts.order.bump;
     let is_sell = ctx.accounts.order.is_sell_order;
+    let crypto_first = ctx.accounts.order.crypto_first;
 
     // CHECK: Ticket must belong to order
     require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
 
-    // Identify roles
-    let crypto_guy = if is_sell { order_creator } else { ticket.acceptor };
-    let fiat_guy   = if is_sell { ticket.acceptor } else { order_creator };
+    // CHECK: A zeroed ticket has nothing to settle (and must never produce zero-value CPIs)
+    require!(ticket.amount > 0, UniversalOrderError::InvalidAmount);
+
+    // CHECK: token_program must own the mint (SPL Token vs Token-2022), otherwise the CPI fails cryptically
+    require!(
+        ctx.accounts.mint.to_account_info().owner == &ctx.accounts.token_program.key(),
+        UniversalOrderError::InvalidTokenProgram
+    );
+
+    // CHECK: Admin only pays fees/rent and is never a trading party, so it can't sign as one
+    require_keys_neq!(signer.key(), crate::constants::ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+
+    // CHECK: Completed orders accept no further signatures
+    require!(!ctx.accounts.order.is_complete(), UniversalOrderError::OrderCompleted);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
+    // Identify roles (CHECK: signer must be one of the two parties, or its delegated signer)
+    let (crypto_guy, fiat_guy, is_crypto_guy) = validate_signer(
+        is_sell, order_creator, ticket.acceptor, ticket.crypto_guy_signer, ticket.fiat_guy_signer, signer.key(),
+    )?;
+
+    // CHECK: Idempotent retry - this signer's signature is already recorded under the same nonce
+    let already_signed = if is_crypto_guy { ticket.crypto_guy_signed } else { ticket.fiat_guy_signed };
+    if already_signed && client_nonce.is_some() && ticket.client_nonce == client_nonce {
+        return err!(UniversalOrderError::DuplicateRequest);
+    }
+
+    // CHECK: Cooldown between signature state changes on this ticket
+    require!(
+        clock.unix_timestamp.saturating_sub(ticket.last_sign_ts) >= ctx.accounts.config.fill_cooldown_secs,
+        UniversalOrderError::ActionTooFrequent
+    );
 
     // Mark signature
-    if signer.key() == crypto_guy {
-        // Business rule: FiatGuy must sign first. If crypto tries to sign before fiat, error.
-        require!(ticket.fiat_guy_signed, UniversalOrderError::SignatureRequired);
+    if is_crypto_guy {
+        // Business rule: FiatGuy must sign first (unless order is crypto_first). If crypto tries to sign before fiat, error.
+        if !crypto_first {
+            require!(ticket.fiat_guy_signed, UniversalOrderError::SignatureRequired);
+        }
         require!(!ticket.crypto_guy_signed, UniversalOrderError::RaceCondition);
         ticket.crypto_guy_signed = true;
-    } else if signer.key() == fiat_guy {
+    } else {
+        // crypto_first orders: CryptoGuy must sign before FiatGuy
+        if crypto_first {
+            require!(ticket.crypto_guy_signed, UniversalOrderError::SignatureRequired);
+        }
         require!(!ticket.fiat_guy_signed, UniversalOrderError::RaceCondition);
         ticket.fiat_guy_signed = true;
-    } else {
-        return Err(UniversalOrderError::Unauthorized.into());
     }
+    ticket.client_nonce = client_nonce;
+    ticket.last_sign_ts = clock.unix_timestamp;
 
     // We'll update order.updated_at and counters after potential CPI using a mutable borrow
 
@@ -47,37 +101,47 @@ pub fn sign_ticket(
     order: order_key,
         ticket: ticket.key(),
         signer: signer.key(),
-        is_crypto_guy: signer.key() == crypto_guy,
-        is_fiat_guy: signer.key() == fiat_guy,
+        is_crypto_guy,
+        is_fiat_guy: !is_crypto_guy,
         both_signed: ticket.crypto_guy_signed && ticket.fiat_guy_signed,
+        filled_amount: ctx.accounts.order.filled_amount,
+        reserved_amount: ctx.accounts.order.reserved_amount,
         timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
     });
 
     // If both signed -> settle this ticket
     if ticket.crypto_guy_signed && ticket.fiat_guy_signed {
         let amount = ticket.amount;
 
-        // CHECK: FiatGuy ATA provided
-        let fiat_guy_token_account = ctx.accounts.fiat_guy_token_account.as_ref()
-            .ok_or(UniversalOrderError::TokenAccountRequired)?;
-        require!(fiat_guy_token_account.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-        require!(fiat_guy_token_account.owner == fiat_guy, UniversalOrderError::Unauthorized);
+        // CHECK: Never settle more than the order holds, even if counters drifted
+        ctx.accounts.order.check_ticket_amount(amount)?;
 
-        // CHECK: Admin fee account provided
-        let admin_fee_account = ctx.accounts.admin_fee_account.as_ref()
-            .ok_or(UniversalOrderError::TokenAccountRequired)?;
-        require!(admin_fee_account.mint == order_mint, UniversalOrderError::InvalidTokenAccount);
-        require!(admin_fee_account.owner == crate::constants::ADMIN_PUBKEY, UniversalOrderError::Unauthorized);
+        // CHECK: FiatGuy ATA provided (not needed when the payout is unwrapped to native SOL)
+        let payout_destination = ticket.payout_owner(fiat_guy);
+        let unwrap = ticket.unwrap_to_sol && is_native_mint(&order_mint);
+        if !unwrap {
+            let fiat_guy_token_account = ctx.accounts.fiat_guy_token_account.as_ref()
+                .ok_or(UniversalOrderError::TokenAccountRequired)?;
+            validate_ata(fiat_guy_token_account, &order_mint, &payout_destination)?;
+        }
+        let fee_destination = ctx.accounts.order.fee_owner();
 
-        // Calculate 0.25% fee
-        let (fee_amount, net_amount) = calculate_fee(amount)?;
+        // Calculate fee (per-mint bps, floored at the mint's min_fee; fee-on-top orders use the prepaid fee)
+        let (fee_amount, net_amount) = settlement_split(
+            amount,
+            ticket.prepaid_fee,
+            ctx.accounts.order.fee_on_top,
+            ctx.accounts.config.fee_bps_for(&order_mint),
+            ctx.accounts.config.min_fee_for(&order_mint),
+        )?;
         
         // Get mint decimals for transfer_checked
         let decimals = ctx.accounts.mint.decimals;
 
         // Prepare PDA signer: the vault's owner is the order PDA
         let order_signer_seeds = &[
-            b"universal_order",
+            ORDER_SEED,
             order_creator.as_ref(),
             order_mint.as_ref(),
             order_id_le.as_ref(),
@@ -85,38 +149,113 @@ pub fn sign_ticket(
         ];
         let order_signer = &[&order_signer_seeds[..]];
 
-        // Transfer 1: 99.75% to FiatGuy
-        let transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                to: fiat_guy_token_account.to_account_info(),
-                authority: ctx.accounts.order.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-            },
-            order_signer,
-        );
-        transfer_checked(transfer_ctx, net_amount, decimals)?;
-
-        // Transfer 2: 0.25% to Admin (fee)
-        let fee_transfer_ctx = CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            TransferChecked {
-                from: ctx.accounts.vault.to_account_info(),
-                to: admin_fee_account.to_account_info(),
-                authority: ctx.accounts.order.to_account_info(),
-                mint: ctx.accounts.mint.to_account_info(),
-            },
-            order_signer,
-        );
-        transfer_checked(fee_transfer_ctx, fee_amount, decimals)?;
+        // Transfer 1: 99.75% to FiatGuy - skipped when min_fee consumes the whole amount
+        if net_amount > 0 && unwrap {
+            // WSOL → native SOL: move net_amount into a temporary WSOL account owned by the order,
+            // close it into the order, then hand the lamports out (net to the payout wallet,
+            // the temporary account's rent back to the admin who paid it)
+            let payout_wallet = ctx.accounts.payout_wallet.as_ref()
+                .ok_or(UniversalOrderError::UnwrapAccountsRequired)?;
+            let unwrap_account = ctx.accounts.unwrap_account.as_ref()
+                .ok_or(UniversalOrderError::UnwrapAccountsRequired)?;
+            let system_program = ctx.accounts.system_program.as_ref()
+                .ok_or(UniversalOrderError::UnwrapAccountsRequired)?;
+            require_keys_eq!(payout_wallet.key(), payout_destination, UniversalOrderError::Unauthorized);
+
+            let ticket_key = ticket.key();
+            let (expected_unwrap, unwrap_bump) = Pubkey::find_program_address(&[UNWRAP_SEED, ticket_key.as_ref()], ctx.program_id);
+            require_keys_eq!(unwrap_account.key(), expected_unwrap, ErrorCode::ConstraintSeeds);
+
+            let unwrap_info = unwrap_account.to_account_info();
+            let order_info = ctx.accounts.order.to_account_info();
+            let fee_payer_info = ctx.accounts.fee_payer.to_account_info();
+            let space = token_account_space(&ctx.accounts.mint.to_account_info())?;
+            create_pda_account(
+                &fee_payer_info, &unwrap_info, &system_program.to_account_info(), space,
+                &ctx.accounts.token_program.key(),
+                &[UNWRAP_SEED, ticket_key.as_ref(), &[unwrap_bump]],
+            )?;
+            let temp_rent = unwrap_info.lamports();
+            initialize_account3(CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                InitializeAccount3 {
+                    account: unwrap_info.clone(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    authority: order_info.clone(),
+                },
+            ))?;
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: unwrap_info.clone(),
+                    authority: order_info.clone(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                order_signer,
+            );
+            transfer_checked(transfer_ctx, net_amount, decimals)?;
+            close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: unwrap_info,
+                    destination: order_info.clone(),
+                    authority: order_info.clone(),
+                },
+                order_signer,
+            ))?;
+            order_info.sub_lamports(net_amount.checked_add(temp_rent).ok_or(UniversalOrderError::InvalidAmount)?)?;
+            payout_wallet.add_lamports(net_amount)?;
+            fee_payer_info.add_lamports(temp_rent)?;
+        } else if net_amount > 0 {
+            let fiat_guy_token_account = ctx.accounts.fiat_guy_token_account.as_ref()
+                .ok_or(UniversalOrderError::TokenAccountRequired)?;
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: fiat_guy_token_account.to_account_info(),
+                    authority: ctx.accounts.order.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                },
+                order_signer,
+            );
+            transfer_checked(transfer_ctx, net_amount, decimals)?;
+        }
+
+        // Transfer 2: 0.25% to Admin, or the order's fee_destination - fee account only required when there is a fee
+        pay_fee(
+            &ctx.accounts.order,
+            &ctx.accounts.config,
+            ctx.accounts.admin_fee_account.as_ref(),
+            &ctx.accounts.vault,
+            &ctx.accounts.mint,
+            &ctx.accounts.token_program.to_account_info(),
+            fee_amount,
+        )?;
 
         // Update order counters (now take a mutable borrow)
         {
             let order = &mut ctx.accounts.order;
             order.filled_amount = order.filled_amount.saturating_add(amount);
-            order.reserved_amount = order.reserved_amount.saturating_sub(amount);
+            order.reserved_amount = order.reserved_amount.checked_sub(amount)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.reserved_fees = order.reserved_fees.checked_sub(ticket.prepaid_fee)
+                .ok_or(UniversalOrderError::InvalidAmount)?;
+            order.total_fees_collected = order.total_fees_collected.saturating_add(fee_amount);
+            order.settle_count = order.settle_count.saturating_add(1);
+            if let Some(previous_count) = order.record_fill(clock.unix_timestamp) {
+                emit!(crate::universal::events::RateWindowReset {
+                    order: order_key,
+                    previous_count,
+                    timestamp: clock.unix_timestamp,
+                });
+            }
+            // Refresh even if the order stays open (partial settlement) for off-chain staleness checks
+            order.updated_at = clock.unix_timestamp;
+            release_order_lock(order);
         }
+        debug_assert_consistency(&ctx.accounts.order, ctx.accounts.config.strict_invariants)?;
 
         // Emit settlement event
         emit!(crate::universal::events::TicketSettled {
@@ -126,78 +265,64 @@ pub fn sign_ticket(
             fee_amount,
             net_amount,
             fiat_guy,
+            payout_destination,
+            fee_destination,
+            unwrapped: unwrap,
             crypto_guy,
             total_filled: ctx.accounts.order.filled_amount,
+            reference: ticket.reference,
+            version: crate::constants::PROGRAM_VERSION,
             timestamp: clock.unix_timestamp,
+            seq: ctx.accounts.config.next_event_seq(),
         });
 
-        // Read vault balance directly from account data (after transfers completed)
-        let vault_account = ctx.accounts.vault.to_account_info();
-        let vault_data = vault_account.try_borrow_data()?;
-        let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
-        drop(vault_data); // Release borrow
-        msg!("Vault balance after transfers: {}", vault_balance);
-
-        // AUTO-CLOSE order if fully completed (pass vault balance directly)
-        if vault_balance == 0 {
-            let order = &ctx.accounts.order;
-            let remaining = order.remaining_amount();
-            let should_close = remaining == 0 && order.reserved_amount == 0;
-            
-            if should_close {
-                msg!("Auto-closing vault and order, returning rent to admin.");
-                
-                let order_creator = order.creator;
-                let order_mint = order.crypto_mint;
-                let order_id_le = order.order_id.to_le_bytes();
-                let order_bump = order.bump;
-
-                let seeds = &[
-                    b"universal_order".as_ref(),
-                    order_creator.as_ref(),
-                    order_mint.as_ref(),
-                    order_id_le.as_ref(),
-                    &[order_bump],
-                ];
-                let signer = &[&seeds[..]];
-
-                let close_vault_accounts = CloseAccount {
-                    account: ctx.accounts.vault.to_account_info(),
-                    destination: ctx.accounts.admin_rent_receiver.to_account_info(),
-                    authority: ctx.accounts.order.to_account_info(),
-                };
-
-                let cpi_ctx = CpiContext::new_with_signer(
-                    ctx.accounts.token_program.to_account_info(),
-                    close_vault_accounts,
-                    signer,
-                );
-
-                close_account(cpi_ctx)?;
-                msg!("Vault closed, rent returned to admin");
-
-                // Close order account and return rent to admin
-                ctx.accounts.order.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Order closed, rent returned to admin");
-
-                // Close the ticket account returning rent to admin (LAST!)
-                ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-                msg!("Ticket closed, rent returned to admin");
-                
-                return Ok(());
-            }
+        // Durable receipt: written before the ticket closes, keyed by the ticket address
+        if create_receipt {
+            let receipt = ctx.accounts.receipt.as_ref()
+                .ok_or(UniversalOrderError::ReceiptAccountRequired)?;
+            let system_program = ctx.accounts.system_program.as_ref()
+                .ok_or(UniversalOrderError::ReceiptAccountRequired)?;
+            let ticket_key = ticket.key();
+            let (expected_receipt, receipt_bump) = Pubkey::find_program_address(&[RECEIPT_SEED, ticket_key.as_ref()], ctx.program_id);
+            require_keys_eq!(receipt.key(), expected_receipt, ErrorCode::ConstraintSeeds);
+
+            let receipt_info = receipt.to_account_info();
+            create_pda_account(
+                &ctx.accounts.fee_payer.to_account_info(), &receipt_info, &system_program.to_account_info(),
+                SettlementReceipt::SPACE, ctx.program_id,
+                &[RECEIPT_SEED, ticket_key.as_ref(), &[receipt_bump]],
+            )?;
+            let data = SettlementReceipt {
+                ticket: ticket_key,
+                order: order_key,
+                amount,
+                fee_amount,
+                fiat_guy,
+                crypto_guy,
+                timestamp: clock.unix_timestamp,
+                bump: receipt_bump,
+            };
+            data.try_serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
         }
 
-        // If vault not empty or order not completed, just close ticket
-        ticket.close(ctx.accounts.admin_rent_receiver.to_account_info())?;
-        
-        // If not closed, continue to update timestamp
+        // AUTO-CLOSE: ticket always; vault + order once fully completed
+        close_ticket_and_maybe_order(
+            &mut ctx.accounts.order,
+            &ctx.accounts.vault,
+            ticket,
+            &ctx.accounts.admin_rent_receiver.to_account_info(),
+            &ctx.accounts.token_program.to_account_info(),
+            false,
+        )?;
+
         return Ok(());
     }
 
     // If NOT both signed yet, update order timestamp
     let order = &mut ctx.accounts.order;
     order.updated_at = clock.unix_timestamp;
+    release_order_lock(order);
+    debug_assert_consistency(order, ctx.accounts.config.strict_invariants)?;
 
     Ok(())
 }
@@ -211,34 +336,46 @@ pub struct SignTicket<'info> {
     )]
     pub fee_payer: Signer<'info>,
 
-    /// User who signs the ticket (second signer)
+    /// User who signs the ticket (second signer, must not be the admin)
     #[account(mut)]
     pub signer: Signer<'info>,
 
-    /// CHECK: Admin wallet receives rent back (hardcoded address)
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
     #[account(
         mut,
-        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
     )]
     pub admin_rent_receiver: UncheckedAccount<'info>,
 
+    /// Global Config PDA (rate limiting parameters, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
     /// CHECK: Parent order
     #[account(
         mut,
-        seeds = [b"universal_order", order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
         bump = order.bump
     )]
     pub order: Account<'info, UniversalOrder>,
     
-    /// Mint account - needed for transfer_checked
+    /// Mint account - needed for transfer_checked (must be the order's mint, checked before the vault)
+    #[account(
+        constraint = mint.key() == order.crypto_mint @ UniversalOrderError::InvalidMint
+    )]
     pub mint: InterfaceAccount<'info, Mint>,
 
     /// CHECK: Vault PDA - supports both SPL Token and Token-2022
     #[account(
         mut,
-        seeds = [b"vault", order.key().as_ref()],
-        bump,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
         constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority,
         constraint = vault.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
@@ -246,18 +383,33 @@ pub struct SignTicket<'info> {
     /// CHECK: Ticket PDA
     #[account(
         mut,
-        seeds = [b"ticket", order.key().as_ref(), ticket.ticket_id.to_le_bytes().as_ref()],
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
         bump = ticket.bump
     )]
     pub ticket: Account<'info, FillTicket>,
 
-    // FiatGuy's token account (where crypto will be sent)
+    // FiatGuy's token account (where crypto will be sent; omitted when unwrapping to SOL)
     #[account(mut)]
     pub fiat_guy_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    // Admin's token account (for 0.25% fee)
+    /// CHECK: Wallet receiving native SOL when ticket.unwrap_to_sol (must be the payout owner)
+    #[account(mut)]
+    pub payout_wallet: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Temporary WSOL account [UNWRAP_SEED, ticket], created and closed during settlement
+    #[account(mut)]
+    pub unwrap_account: Option<UncheckedAccount<'info>>,
+
+    // Fee token account owned by Admin or order.fee_destination (may be omitted when the fee rounds to 0)
     #[account(mut)]
     pub admin_fee_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
+    /// CHECK: SettlementReceipt PDA [RECEIPT_SEED, ticket], created on settlement when create_receipt
+    #[account(mut)]
+    pub receipt: Option<UncheckedAccount<'info>>,
+
     pub token_program: Interface<'info, TokenInterface>,
+
+    /// Needed only to create the temporary WSOL account when unwrapping, or the receipt
+    pub system_program: Option<Program<'info, System>>,
 }
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::ed25519::parse_single_signature;
+use crate::universal::utils::validate::ticket_roles;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Record FiatGuy's (first) signature from an off-chain ed25519 authorization submitted by the admin
+/// The instruction right before this one must be an Ed25519 program verify of FiatGuy's signature
+/// over `ticket pubkey || ticket.created_at (i64 LE) || ticket.last_sign_ts (i64 LE)` - created_at
+/// keeps a signature from being replayed onto a later ticket that reuses the same PDA address, and
+/// last_sign_ts (moved by every sign/unsign) from being replayed after FiatGuy unsigned
+pub(crate) fn sign_ticket_with_ed25519(
+    ctx: Context<SignTicketWithEd25519>,
+) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let order = &mut ctx.accounts.order;
+    let clock = Clock::get()?;
+
+    // CHECK: Ticket must belong to order
+    require!(ticket.order == order.key(), UniversalOrderError::Unauthorized);
+
+    // CHECK: Completed orders accept no further signatures
+    require!(!order.is_complete(), UniversalOrderError::OrderCompleted);
+
+    // CHECK: Only a first FiatGuy signature - crypto_first orders need CryptoGuy first,
+    // and a settling signature needs the token accounts of sign_ticket
+    require!(!order.crypto_first, UniversalOrderError::SignatureRequired);
+    require!(!ticket.fiat_guy_signed, UniversalOrderError::RaceCondition);
+
+    // CHECK: Cooldown between signature state changes on this ticket
+    require!(
+        clock.unix_timestamp.saturating_sub(ticket.last_sign_ts) >= ctx.accounts.config.fill_cooldown_secs,
+        UniversalOrderError::ActionTooFrequent
+    );
+
+    let (_, fiat_guy) = ticket_roles(order.is_sell_order, order.creator, ticket.acceptor);
+    // FiatGuy's delegated signer (if any) authorizes instead of the wallet
+    let fiat_key = ticket.fiat_guy_signer.unwrap_or(fiat_guy);
+
+    // CHECK: Previous instruction is an Ed25519 verify by FiatGuy over this ticket
+    let ix_sysvar = ctx.accounts.instructions.to_account_info();
+    let current = load_current_index_checked(&ix_sysvar)?;
+    require!(current > 0, UniversalOrderError::InvalidEd25519Instruction);
+    let verify_ix = load_instruction_at_checked(current as usize - 1, &ix_sysvar)?;
+    require_keys_eq!(verify_ix.program_id, solana_sdk_ids::ed25519_program::ID, UniversalOrderError::InvalidEd25519Instruction);
+
+    let (signed_by, message) = parse_single_signature(&verify_ix.data)
+        .ok_or(UniversalOrderError::InvalidEd25519Instruction)?;
+    require_keys_eq!(signed_by, fiat_key, UniversalOrderError::Unauthorized);
+
+    let mut expected = ticket.key().to_bytes().to_vec();
+    expected.extend_from_slice(&ticket.created_at.to_le_bytes());
+    expected.extend_from_slice(&ticket.last_sign_ts.to_le_bytes());
+    require!(message == expected.as_slice(), UniversalOrderError::InvalidEd25519Instruction);
+
+    ticket.fiat_guy_signed = true;
+    ticket.last_sign_ts = clock.unix_timestamp;
+    order.updated_at = clock.unix_timestamp;
+
+    emit!(crate::universal::events::TicketSigned {
+        order: order.key(),
+        ticket: ticket.key(),
+        signer: fiat_key,
+        is_crypto_guy: false,
+        is_fiat_guy: true,
+        both_signed: false,
+        filled_amount: order.filled_amount,
+        reserved_amount: order.reserved_amount,
+        timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SignTicketWithEd25519<'info> {
+    /// Admin submits on FiatGuy's behalf and pays the transaction fee
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub fee_payer: Signer<'info>,
+
+    /// Global Config PDA (rate limiting parameters, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Parent order
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket FiatGuy authorized
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// CHECK: Instructions sysvar (address checked) - used to read the Ed25519 verify instruction
+    #[account(
+        address = solana_sdk_ids::sysvar::instructions::ID
+    )]
+    pub instructions: UncheckedAccount<'info>,
+}
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::TicketSplit;
+use crate::universal::utils::fees::prorate_fee;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Split an unsigned ticket: keep `keep_amount` on it and move the remainder to a new
+/// ticket with the same acceptor. Internal to the order, so reserved_amount is unchanged.
+/// A prepaid fee (fee-on-top orders) is split pro rata, the change ticket's share rounded down
+/// The change ticket takes the next index (order.ticket_index); `new_ticket_id` is its client reference
+pub fn split_ticket(
+    ctx: Context<SplitTicket>,
+    keep_amount: u64,
+    new_ticket_id: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let order_key = ctx.accounts.order.key();
+    let ticket = &mut ctx.accounts.ticket;
+    let new_ticket = &mut ctx.accounts.new_ticket;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
+
+    // CHECK: Nobody has signed yet
+    require!(
+        !ticket.fiat_guy_signed && !ticket.crypto_guy_signed,
+        UniversalOrderError::TicketAlreadySigned
+    );
+
+    // CHECK: Both halves non-empty
+    require!(keep_amount > 0 && keep_amount < ticket.amount, UniversalOrderError::InvalidAmount);
+    require!(new_ticket_id > 0, UniversalOrderError::InvalidAmount);
+
+    let change_amount = ticket.amount - keep_amount;
+
+    // CHECK: Neither half is dust relative to the order
+    let min_fill = ctx.accounts.config.min_fill_amount(ctx.accounts.order.crypto_amount);
+    require!(
+        keep_amount >= min_fill && change_amount >= min_fill,
+        UniversalOrderError::FillTooSmall
+    );
+    let change_fee = prorate_fee(ticket.prepaid_fee, change_amount, ticket.amount)?;
+    ticket.amount = keep_amount;
+    ticket.prepaid_fee -= change_fee;
+
+    // Initialize change ticket
+    new_ticket.order = order_key;
+    new_ticket.acceptor = ticket.acceptor;
+    new_ticket.amount = change_amount;
+    new_ticket.crypto_guy_signed = false;
+    new_ticket.fiat_guy_signed = false;
+    new_ticket.ticket_id = new_ticket_id;
+    new_ticket.created_at = clock.unix_timestamp;
+    new_ticket.last_sign_ts = 0;
+    new_ticket.bump = ctx.bumps.new_ticket;
+    new_ticket.payout_destination = ticket.payout_destination;
+    new_ticket.reference = ticket.reference;
+    new_ticket.index = ctx.accounts.order.ticket_index;
+    new_ticket.extended_until = ticket.extended_until;
+    new_ticket.unwrap_to_sol = ticket.unwrap_to_sol;
+    new_ticket.prepaid_fee = change_fee;
+    new_ticket.crypto_guy_signer = ticket.crypto_guy_signer;
+    new_ticket.fiat_guy_signer = ticket.fiat_guy_signer;
+    new_ticket.client_nonce = None;
+
+    let order = &mut ctx.accounts.order;
+    order.ticket_index = order.ticket_index.checked_add(1).ok_or(UniversalOrderError::InvalidAmount)?;
+    order.updated_at = clock.unix_timestamp;
+
+    emit!(TicketSplit {
+        order: order_key,
+        ticket: ticket.key(),
+        new_ticket: new_ticket.key(),
+        keep_amount,
+        change_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Ticket {} split: kept {}, ticket {} gets {}", ticket.ticket_id, keep_amount, new_ticket_id, change_amount);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(keep_amount: u64, new_ticket_id: u64)]
+pub struct SplitTicket<'info> {
+    /// Admin pays rent for the change ticket
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Parent order PDA
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket PDA to split
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// Change ticket PDA (created here at the order's next index)
+    #[account(
+        init,
+        payer = admin,
+        space = FillTicket::SPACE,
+        seeds = [TICKET_SEED, order.key().as_ref(), order.ticket_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub new_ticket: Account<'info, FillTicket>,
+
+    /// Global Config PDA (min_fill_denominator)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
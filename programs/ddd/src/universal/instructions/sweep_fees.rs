@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::FeesSwept;
+use crate::universal::utils::auto_close::close_order_and_vault;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, CONFIG_SEED, PROTOCOL_FEE_VAULT_SEED};
+
+/// Sweep residual dust from an order's vault into the protocol fee vault, then close vault + order
+/// Only for orders with no active tickets whose vault didn't empty (so auto-close skipped them)
+pub(crate) fn sweep_fees(
+    ctx: Context<SweepFees>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let order = &ctx.accounts.order;
+
+    // CHECK: No active tickets - everything left in the vault is residual
+    require!(order.is_cancellable(), UniversalOrderError::OrderHasActiveTickets);
+
+    let order_key = order.key();
+    let order_creator = order.creator;
+    let order_mint = order.crypto_mint;
+    let order_id_le = order.order_id.to_le_bytes();
+    let order_bump = order.bump;
+
+    let residual = ctx.accounts.vault.amount;
+    require!(residual > 0, UniversalOrderError::InvalidAmount);
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    // Move residual to protocol fee vault
+    let cpi = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.protocol_fee_vault.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        },
+        signer,
+    );
+    transfer_checked(cpi, residual, ctx.accounts.mint.decimals)?;
+
+    emit!(FeesSwept {
+        order: order_key,
+        mint: order_mint,
+        amount: residual,
+        protocol_fee_vault: ctx.accounts.protocol_fee_vault.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    // Vault is now empty - close vault + order, rent to the rent receiver
+    close_order_and_vault(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        residual,
+    )?;
+    msg!("Order closed after sweeping {} dust", residual);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SweepFees<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (fee vault authority)
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Order PDA to sweep and close
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Mint account - needed for transfer_checked
+    #[account(
+        constraint = mint.key() == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Order vault PDA holding the residual
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Protocol fee vault PDA for this mint
+    #[account(
+        mut,
+        seeds = [PROTOCOL_FEE_VAULT_SEED, mint.key().as_ref()],
+        bump,
+        token::mint = mint,
+        token::authority = config
+    )]
+    pub protocol_fee_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
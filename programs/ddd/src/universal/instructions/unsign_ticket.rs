@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::events::TicketUnsigned;
+use crate::universal::utils::validate::validate_signer;
+use crate::universal::utils::seeds::{ORDER_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Withdraw a premature signature: the party whose flag is set clears it again,
+/// allowed only while the other party hasn't signed (after that the ticket settles
+/// or needs admin resolution). Same cooldown as sign_ticket
+pub(crate) fn unsign_ticket(
+    ctx: Context<UnsignTicket>,
+) -> Result<()> {
+    let order = &mut ctx.accounts.order;
+    let ticket = &mut ctx.accounts.ticket;
+    let signer = ctx.accounts.signer.key();
+    let clock = Clock::get()?;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == order.key(), UniversalOrderError::Unauthorized);
+
+    // Identify roles (CHECK: signer must be one of the two parties, or its delegated signer)
+    let (_, _, is_crypto_guy) = validate_signer(
+        order.is_sell_order, order.creator, ticket.acceptor, ticket.crypto_guy_signer, ticket.fiat_guy_signer, signer,
+    )?;
+
+    // CHECK: Cooldown between signature state changes on this ticket
+    require!(
+        clock.unix_timestamp.saturating_sub(ticket.last_sign_ts) >= ctx.accounts.config.fill_cooldown_secs,
+        UniversalOrderError::ActionTooFrequent
+    );
+
+    let (own_signed, other_signed) = if is_crypto_guy {
+        (ticket.crypto_guy_signed, ticket.fiat_guy_signed)
+    } else {
+        (ticket.fiat_guy_signed, ticket.crypto_guy_signed)
+    };
+    // CHECK: Signer has something to withdraw
+    require!(own_signed, UniversalOrderError::NotSigned);
+    // CHECK: Once the other party has signed, the signature is binding
+    require!(!other_signed, UniversalOrderError::TicketAlreadySigned);
+
+    if is_crypto_guy {
+        ticket.crypto_guy_signed = false;
+    } else {
+        ticket.fiat_guy_signed = false;
+    }
+    ticket.client_nonce = None;
+    ticket.last_sign_ts = clock.unix_timestamp;
+    order.updated_at = clock.unix_timestamp;
+
+    emit!(TicketUnsigned {
+        order: order.key(),
+        ticket: ticket.key(),
+        signer,
+        is_crypto_guy,
+        timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
+    });
+
+    msg!("Ticket {} unsigned by {}", ticket.ticket_id, signer);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnsignTicket<'info> {
+    /// Party withdrawing their signature (CryptoGuy or FiatGuy, or its delegated signer)
+    pub signer: Signer<'info>,
+
+    /// Parent order PDA
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Ticket PDA to unsign
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// Global Config PDA (fill_cooldown_secs, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::constants::MAX_ORDER_ACCEPTORS;
+use crate::universal::utils::seeds::CONFIG_SEED;
+use crate::universal::utils::fees::{max_min_fee, MAX_FEE_BASIS_POINTS, MAX_MIN_FEE};
+
+/// Admin setter: minimum interval between ticket signatures
+pub(crate) fn set_fill_cooldown(
+    ctx: Context<UpdateConfig>,
+    fill_cooldown_secs: i64,
+) -> Result<()> {
+    require!(fill_cooldown_secs >= 0, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.fill_cooldown_secs = fill_cooldown_secs;
+    msg!("Config updated: fill_cooldown_secs={}", fill_cooldown_secs);
+    Ok(())
+}
+
+/// Admin setter: max fills per order per day window (at least 1 - 0 would leave every order without budget)
+pub(crate) fn set_max_fills_per_day(
+    ctx: Context<UpdateConfig>,
+    max_fills_per_day: u16,
+) -> Result<()> {
+    require!(max_fills_per_day >= 1, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.max_fills_per_day = max_fills_per_day;
+    msg!("Config updated: max_fills_per_day={}", max_fills_per_day);
+    Ok(())
+}
+
+/// Admin setter: require locker token accounts to be the canonical ATA
+pub(crate) fn set_require_ata(
+    ctx: Context<UpdateConfig>,
+    require_ata: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.require_ata = require_ata;
+    msg!("Config updated: require_ata={}", require_ata);
+    Ok(())
+}
+
+/// Admin setter: require the creator to co-sign BUY-order creation
+pub(crate) fn set_require_creator_sig(
+    ctx: Context<UpdateConfig>,
+    require_creator_sig: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.require_creator_sig = require_creator_sig;
+    msg!("Config updated: require_creator_sig={}", require_creator_sig);
+    Ok(())
+}
+
+/// Admin setter: wallet that receives rent from closed tickets/vaults/orders
+pub(crate) fn set_rent_receiver(
+    ctx: Context<UpdateConfig>,
+    rent_receiver: Pubkey,
+) -> Result<()> {
+    require!(rent_receiver != Pubkey::default(), UniversalOrderError::InvalidArgument);
+
+    let config = &mut ctx.accounts.config;
+    config.rent_receiver = rent_receiver;
+    msg!("Config updated: rent_receiver={}", rent_receiver);
+    Ok(())
+}
+
+/// Admin setter: require Token-2022 fee accounts to have an immutable owner
+pub(crate) fn set_require_immutable_fee_owner(
+    ctx: Context<UpdateConfig>,
+    require_immutable_fee_owner: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.require_immutable_fee_owner = require_immutable_fee_owner;
+    msg!("Config updated: require_immutable_fee_owner={}", require_immutable_fee_owner);
+    Ok(())
+}
+
+/// Admin setter: grace period before CryptoGuy may time out a ticket FiatGuy hasn't signed
+pub(crate) fn set_fiat_sign_grace(
+    ctx: Context<UpdateConfig>,
+    fiat_sign_grace_secs: i64,
+) -> Result<()> {
+    require!(fiat_sign_grace_secs >= 0, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.fiat_sign_grace_secs = fiat_sign_grace_secs;
+    msg!("Config updated: fiat_sign_grace_secs={}", fiat_sign_grace_secs);
+    Ok(())
+}
+
+/// Admin setter: cap on how far one extend_ticket call may push a ticket's expiry
+pub(crate) fn set_max_ticket_extension(
+    ctx: Context<UpdateConfig>,
+    max_ticket_extension_secs: i64,
+) -> Result<()> {
+    require!(max_ticket_extension_secs >= 0, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.max_ticket_extension_secs = max_ticket_extension_secs;
+    msg!("Config updated: max_ticket_extension_secs={}", max_ticket_extension_secs);
+    Ok(())
+}
+
+/// Admin setter: minimum ticket size as a fraction of its order (0 = no minimum)
+pub(crate) fn set_min_fill_denominator(
+    ctx: Context<UpdateConfig>,
+    min_fill_denominator: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.min_fill_denominator = min_fill_denominator;
+    msg!("Config updated: min_fill_denominator={}", min_fill_denominator);
+    Ok(())
+}
+
+/// Admin setter: max distinct acceptors per order (1..=MAX_ORDER_ACCEPTORS, the per-order capacity)
+pub(crate) fn set_max_distinct_acceptors(
+    ctx: Context<UpdateConfig>,
+    max_distinct_acceptors: u8,
+) -> Result<()> {
+    require!(
+        max_distinct_acceptors >= 1 && max_distinct_acceptors as usize <= MAX_ORDER_ACCEPTORS,
+        UniversalOrderError::InvalidAmount
+    );
+    let config = &mut ctx.accounts.config;
+    config.max_distinct_acceptors = max_distinct_acceptors;
+    msg!("Config updated: max_distinct_acceptors={}", max_distinct_acceptors);
+    Ok(())
+}
+
+/// Admin setter: minimum fiat_amount per offer (0 = no floor)
+pub(crate) fn set_min_fiat_amount(
+    ctx: Context<UpdateConfig>,
+    min_fiat_amount: u64,
+) -> Result<()> {
+    require!(min_fiat_amount <= crate::constants::MAX_FIAT_AMOUNT, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.min_fiat_amount = min_fiat_amount;
+    msg!("Config updated: min_fiat_amount={}", min_fiat_amount);
+    Ok(())
+}
+
+/// Admin setter: turn order consistency warnings into hard errors
+pub(crate) fn set_strict_invariants(
+    ctx: Context<UpdateConfig>,
+    strict_invariants: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.strict_invariants = strict_invariants;
+    msg!("Config updated: strict_invariants={}", strict_invariants);
+    Ok(())
+}
+
+/// Admin setter: default fee floor in raw base units for mints without an override
+/// (0 = plain bps fee, at most MAX_MIN_FEE - sized for 6-decimal mints)
+pub(crate) fn set_min_fee(
+    ctx: Context<UpdateConfig>,
+    min_fee: u64,
+) -> Result<()> {
+    require!(min_fee <= MAX_MIN_FEE, UniversalOrderError::InvalidAmount);
+
+    let config = &mut ctx.accounts.config;
+    config.min_fee = min_fee;
+    msg!("Config updated: min_fee={}", min_fee);
+    Ok(())
+}
+
+/// Admin setter: per-mint fee bps (at most MAX_FEE_BASIS_POINTS) and fee floor in the mint's base
+/// units (at most 1 whole token, see max_min_fee). Updates the existing entry or takes a free slot
+pub(crate) fn set_mint_fee(
+    ctx: Context<UpdateMintFee>,
+    fee_bps: u16,
+    min_fee: u64,
+) -> Result<()> {
+    require!(fee_bps <= MAX_FEE_BASIS_POINTS, UniversalOrderError::InvalidAmount);
+    require!(min_fee <= max_min_fee(ctx.accounts.mint.decimals), UniversalOrderError::InvalidAmount);
+
+    let mint = ctx.accounts.mint.key();
+    let config = &mut ctx.accounts.config;
+    let slot = match config.mint_fees.iter().position(|f| f.mint == mint) {
+        Some(i) => i,
+        None => config.mint_fees.iter().position(|f| f.mint == Pubkey::default())
+            .ok_or(UniversalOrderError::MintFeeTableFull)?,
+    };
+    config.mint_fees[slot] = MintFee { mint, fee_bps, min_fee };
+    msg!("Config updated: mint {} fee_bps={} min_fee={}", mint, fee_bps, min_fee);
+    Ok(())
+}
+
+/// Admin setter: drop a per-mint fee override (mint falls back to the default bps and floor)
+pub(crate) fn remove_mint_fee(
+    ctx: Context<UpdateConfig>,
+    mint: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    if let Some(i) = config.mint_fees.iter().position(|f| f.mint == mint) {
+        config.mint_fees[i] = MintFee::default();
+        msg!("Config updated: mint {} fee override removed", mint);
+    }
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global Config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMintFee<'info> {
+    /// Admin signer must match ADMIN_PUBKEY
+    #[account(
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global Config PDA
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Mint the override applies to (its decimals bound the fee floor)
+    pub mint: InterfaceAccount<'info, Mint>,
+}
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface, Mint, transfer_checked, TransferChecked};
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::auto_close::close_ticket_and_maybe_order;
+use crate::universal::utils::guard::{acquire_order_lock, release_order_lock};
+use crate::universal::utils::validate::validate_ata;
+use crate::universal::utils::seeds::{ORDER_SEED, VAULT_SEED, TICKET_SEED, CONFIG_SEED};
+
+/// Withdraw a BUY-order ticket - ONLY the CryptoGuy who funded it, ONLY before FiatGuy signs
+/// Refunds the locked tokens to CryptoGuy and auto-closes order + vault when empty
+pub fn withdraw_ticket(
+    ctx: Context<WithdrawTicket>,
+) -> Result<()> {
+    let ticket = &mut ctx.accounts.ticket;
+    let withdrawer = &ctx.accounts.withdrawer;
+    let clock = Clock::get()?;
+
+    // Snapshot order fields
+    let order_key = ctx.accounts.order.key();
+    let order_creator = ctx.accounts.order.creator;
+    let order_mint = ctx.accounts.order.crypto_mint;
+    let order_id_le = ctx.accounts.order.order_id.to_le_bytes();
+    let order_bump = ctx.accounts.order.bump;
+    let is_sell = ctx.accounts.order.is_sell_order;
+
+    require!(ticket.order == order_key, UniversalOrderError::Unauthorized);
+
+    // CHECK: Only BUY orders - there the acceptor (CryptoGuy) funded the ticket
+    require!(!is_sell, UniversalOrderError::InvalidOrderType);
+
+    // CHECK: Reentrancy guard
+    acquire_order_lock(&mut ctx.accounts.order)?;
+
+    // CHECK: Only the CryptoGuy (ticket acceptor) can withdraw
+    let crypto_guy = ticket.acceptor;
+    require!(withdrawer.key() == crypto_guy, UniversalOrderError::Unauthorized);
+
+    // CHECK: Can only withdraw before FiatGuy signs
+    require!(!ticket.fiat_guy_signed, UniversalOrderError::CannotCancel);
+
+    // Get CryptoGuy's token account for refund
+    let crypto_guy_ata = ctx.accounts.crypto_guy_token_account.as_ref()
+        .ok_or(UniversalOrderError::TokenAccountRequired)?;
+    validate_ata(crypto_guy_ata, &order_mint, &crypto_guy)?;
+
+    // Get mint decimals
+    let decimals = ctx.accounts.mint.decimals;
+
+    // Prepare PDA signer
+    let signer_seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&signer_seeds[..]];
+
+    // Refund tokens (and any prepaid fee) from vault to CryptoGuy
+    let amount = ticket.amount;
+    let locked_amount = ticket.locked_amount()?;
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.vault.to_account_info(),
+            to: crypto_guy_ata.to_account_info(),
+            authority: ctx.accounts.order.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+        },
+        signer,
+    );
+    transfer_checked(transfer_ctx, locked_amount, decimals)?;
+    {
+        let order = &mut ctx.accounts.order;
+        order.reserved_amount = order.reserved_amount.checked_sub(amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.reserved_fees = order.reserved_fees.checked_sub(ticket.prepaid_fee)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.refund_count = order.refund_count.saturating_add(1);
+        // Refund leaves the order - shrink target so remaining tickets can still complete it
+        order.crypto_amount = order.crypto_amount.checked_sub(amount)
+            .ok_or(UniversalOrderError::InvalidAmount)?;
+        order.updated_at = clock.unix_timestamp;
+        release_order_lock(order);
+    }
+    ticket.amount = 0;
+    ticket.prepaid_fee = 0;
+
+    // Emit cancellation event
+    emit!(crate::universal::events::TicketCancelled {
+        order: order_key,
+        ticket: ticket.key(),
+        canceller: withdrawer.key(),
+        amount,
+        refunded: true,
+        reference: ticket.reference,
+        timestamp: clock.unix_timestamp,
+        seq: ctx.accounts.config.next_event_seq(),
+    });
+
+    // AUTO-CLOSE: ticket always; vault + order if empty and no reservations left
+    close_ticket_and_maybe_order(
+        &mut ctx.accounts.order,
+        &ctx.accounts.vault,
+        ticket,
+        &ctx.accounts.admin_rent_receiver.to_account_info(),
+        &ctx.accounts.token_program.to_account_info(),
+        true,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTicket<'info> {
+    /// Admin pays transaction fee (first signer = pays transaction fee)
+    #[account(
+        mut,
+        address = crate::constants::ADMIN_PUBKEY @ UniversalOrderError::Unauthorized
+    )]
+    pub fee_payer: Signer<'info>,
+
+    /// CryptoGuy who funded the ticket (second signer)
+    #[account(mut)]
+    pub withdrawer: Signer<'info>,
+
+    /// CHECK: Rent receiver (validated against config.rent_receiver)
+    #[account(
+        mut,
+        address = config.rent_receiver @ UniversalOrderError::Unauthorized
+    )]
+    pub admin_rent_receiver: UncheckedAccount<'info>,
+
+    /// Global Config PDA (rent_receiver, event_seq)
+    #[account(
+        mut,
+        seeds = [CONFIG_SEED],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+
+    /// Parent order (closed if nothing remains reserved)
+    #[account(
+        mut,
+        seeds = [ORDER_SEED, order.creator.as_ref(), order.crypto_mint.as_ref(), order.order_id.to_le_bytes().as_ref()],
+        bump = order.bump
+    )]
+    pub order: Account<'info, UniversalOrder>,
+
+    /// Mint account - needed for transfer_checked
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Vault (closed once empty) - supports both SPL Token and Token-2022
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, order.key().as_ref()],
+        bump = order.vault_bump,
+        constraint = vault.mint == order.crypto_mint @ UniversalOrderError::InvalidTokenAccount,
+        constraint = vault.owner == order.vault_authority @ UniversalOrderError::InvalidVaultAuthority,
+        constraint = vault.mint == mint.key() @ UniversalOrderError::InvalidTokenAccount
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// Ticket to withdraw (will be closed)
+    #[account(
+        mut,
+        seeds = [TICKET_SEED, order.key().as_ref(), ticket.index.to_le_bytes().as_ref()],
+        bump = ticket.bump
+    )]
+    pub ticket: Account<'info, FillTicket>,
+
+    /// CryptoGuy's token account (receives refund)
+    #[account(mut)]
+    pub crypto_guy_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
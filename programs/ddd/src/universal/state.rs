@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use crate::constants::{ADMIN_PUBKEY, MAX_ALLOWED_TAKERS, MAX_MINT_FEE_OVERRIDES, MAX_ORDER_ACCEPTORS, SECONDS_PER_DAY};
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::fees::FEE_BASIS_POINTS;
 
 /// Universal Order State
 /// Works for both Sell Orders (CryptoGuy creates) and Buy Orders (FiatGuy creates)
@@ -8,7 +11,8 @@ pub struct UniversalOrder {
     /// Order creator (can be CryptoGuy or FiatGuy)
     pub creator: Pubkey,
     
-    /// The other party who accepts/fills the order
+    /// Legacy single-acceptor slot, kept for layout and never written (always None):
+    /// each ticket carries its own acceptor, and `acceptors` records them per order
     pub acceptor: Option<Pubkey>,
     
     /// Mint of the cryptocurrency being traded
@@ -50,6 +54,52 @@ pub struct UniversalOrder {
     
     /// Bump for PDA derivation
     pub bump: u8,
+
+    /// Processing flag: set while an instruction is mid-CPI (reentrancy guard)
+    pub locked: bool,
+
+    /// Signing order: false = FiatGuy signs first (default), true = CryptoGuy signs first
+    pub crypto_first: bool,
+
+    /// Cumulative fees paid to admin from this order's settlements
+    pub total_fees_collected: u64,
+
+    /// Dispute analytics: tickets settled to FiatGuy / refunded to CryptoGuy
+    pub settle_count: u16,
+    pub refund_count: u16,
+
+    /// Authority of the vault token account (currently always the order PDA;
+    /// groundwork for external custody overlays)
+    pub vault_authority: Pubkey,
+
+    /// Bump for the vault PDA (stored so instructions don't re-derive it)
+    pub vault_bump: u8,
+
+    /// Number of tickets ever created on this order; the next ticket's PDA index.
+    /// Tickets live at [TICKET_SEED, order, 0..ticket_index] (closed ones are simply missing)
+    pub ticket_index: u64,
+
+    /// Negotiated fee recipient (e.g. a rebate pool) that owns the fee token account on
+    /// settlement; None = the admin treasury
+    pub fee_destination: Option<Pubkey>,
+
+    /// Distinct acceptors ever assigned to this order's tickets (first `acceptor_count` slots used),
+    /// bounded by config.max_distinct_acceptors
+    pub acceptors: [Pubkey; MAX_ORDER_ACCEPTORS],
+    pub acceptor_count: u8,
+
+    /// Fee mode: false = fee comes out of the locked amount (FiatGuy gets amount - fee),
+    /// true = the locker prepays the fee on top (FiatGuy gets the full amount)
+    pub fee_on_top: bool,
+
+    /// Sum of the prepaid fees held in the vault for active tickets (fee-on-top orders),
+    /// on top of reserved_amount
+    pub reserved_fees: u64,
+
+    /// Private order: only these takers may become a ticket's acceptor (when takers_whitelisted);
+    /// unused slots are Pubkey::default(). Set at creation
+    pub allowed_takers: [Pubkey; MAX_ALLOWED_TAKERS],
+    pub takers_whitelisted: bool,
 }
 
 impl UniversalOrder {
@@ -70,7 +120,22 @@ impl UniversalOrder {
         8 + // created_at
         8 + // updated_at
         32 + // vault
-        1; // bump
+        1 + // bump
+        1 + // locked
+        1 + // crypto_first
+        8 + // total_fees_collected
+        2 + // settle_count
+        2 + // refund_count
+        32 + // vault_authority
+        1 + // vault_bump
+        8 + // ticket_index
+        (1 + 32) + // fee_destination (Option<Pubkey>)
+        32 * MAX_ORDER_ACCEPTORS + // acceptors
+        1 + // acceptor_count
+        1 + // fee_on_top
+        8 + // reserved_fees
+        32 * MAX_ALLOWED_TAKERS + // allowed_takers
+        1; // takers_whitelisted
 
 
     
@@ -83,6 +148,114 @@ impl UniversalOrder {
     pub fn available_amount(&self) -> u64 {
         self.remaining_amount().saturating_sub(self.reserved_amount)
     }
+
+    /// Order fully settled: nothing left to fill and no active reservations
+    pub fn is_complete(&self) -> bool {
+        self.remaining_amount() == 0 && self.reserved_amount == 0
+    }
+
+    /// No active tickets hold reservations, so the order can be cancelled/closed
+    pub fn is_cancellable(&self) -> bool {
+        self.reserved_amount == 0
+    }
+
+    /// Warning text when the counters contradict each other (reservations beyond what is left
+    /// to fill), None when consistent. Logged by `debug_assert_consistency`
+    pub fn consistency_warning(&self) -> Option<String> {
+        (self.reserved_amount > self.remaining_amount()).then(|| format!(
+            "order {} inconsistent: reserved_amount {} > remaining_amount {}",
+            self.order_id, self.reserved_amount, self.remaining_amount()
+        ))
+    }
+
+    /// A single ticket can never be worth more than the whole order (TicketExceedsOrder).
+    /// Defensive: holds by construction unless the counters drifted
+    pub fn check_ticket_amount(&self, amount: u64) -> Result<()> {
+        require!(amount <= self.crypto_amount, UniversalOrderError::TicketExceedsOrder);
+        Ok(())
+    }
+
+    /// Settlements counted in the current daily window (0 once a day has passed since daily_reset_ts)
+    pub fn daily_fills(&self, now: i64) -> u16 {
+        if now.saturating_sub(self.daily_reset_ts) >= SECONDS_PER_DAY {
+            0
+        } else {
+            self.daily_fill_count
+        }
+    }
+
+    /// Settlements left in the current daily window under `max_fills_per_day`; a lapsed window
+    /// counts as empty, so the full budget is back a day after the window opened
+    pub fn remaining_fills(&self, max_fills_per_day: u16, now: i64) -> u16 {
+        max_fills_per_day.saturating_sub(self.daily_fills(now))
+    }
+
+    /// Seconds until the current daily window resets (0 when no window is open)
+    pub fn daily_window_resets_in(&self, now: i64) -> i64 {
+        self.daily_reset_ts.saturating_add(SECONDS_PER_DAY).saturating_sub(now).max(0)
+    }
+
+    /// `acceptor` already holds (or held) a ticket on this order
+    pub fn has_acceptor(&self, acceptor: &Pubkey) -> bool {
+        self.acceptors[..self.acceptor_count as usize].contains(acceptor)
+    }
+
+    /// Record `acceptor`; known acceptors always pass, a new one fails once `cap` distinct
+    /// acceptors are recorded (TooManyAcceptors)
+    pub fn track_acceptor(&mut self, acceptor: Pubkey, cap: u8) -> Result<()> {
+        if self.has_acceptor(&acceptor) {
+            return Ok(());
+        }
+        let count = self.acceptor_count as usize;
+        require!(count < (cap as usize).min(MAX_ORDER_ACCEPTORS), UniversalOrderError::TooManyAcceptors);
+        self.acceptors[count] = acceptor;
+        self.acceptor_count += 1;
+        Ok(())
+    }
+
+    /// Store the taker whitelist; an empty list leaves the order open to anyone.
+    /// At most MAX_ALLOWED_TAKERS entries (TooManyAllowedTakers)
+    pub fn set_allowed_takers(&mut self, takers: &[Pubkey]) -> Result<()> {
+        require!(takers.len() <= MAX_ALLOWED_TAKERS, UniversalOrderError::TooManyAllowedTakers);
+        self.allowed_takers = [Pubkey::default(); MAX_ALLOWED_TAKERS];
+        self.allowed_takers[..takers.len()].copy_from_slice(takers);
+        self.takers_whitelisted = !takers.is_empty();
+        Ok(())
+    }
+
+    /// `taker` may become a ticket's acceptor: always on open orders, only if whitelisted on
+    /// private ones (Unauthorized)
+    pub fn check_taker(&self, taker: &Pubkey) -> Result<()> {
+        if self.takers_whitelisted {
+            // Unused slots are Pubkey::default(), never a match
+            require!(
+                *taker != Pubkey::default() && self.allowed_takers.contains(taker),
+                UniversalOrderError::Unauthorized
+            );
+        }
+        Ok(())
+    }
+
+    /// Owner the fee token account must have: fee_destination if set, else ADMIN_PUBKEY
+    pub fn fee_owner(&self) -> Pubkey {
+        self.fee_destination.unwrap_or(ADMIN_PUBKEY)
+    }
+
+    /// Count one settlement, opening a new daily window first if the current one has lapsed.
+    /// Returns the lapsed window's count when an earlier window was reset (for RateWindowReset)
+    pub fn record_fill(&mut self, now: i64) -> Option<u16> {
+        let mut reset = None;
+        if now.saturating_sub(self.daily_reset_ts) >= SECONDS_PER_DAY {
+            if self.daily_reset_ts != 0 {
+                reset = Some(self.daily_fill_count);
+            }
+            self.daily_reset_ts = now;
+            self.daily_fill_count = 0;
+        }
+        self.daily_fill_count = self.daily_fill_count.saturating_add(1);
+        self.last_action_ts = now;
+        reset
+    }
 }
 
 /// FillTicket - individual parallel partial fill intent
@@ -97,12 +270,32 @@ pub struct FillTicket {
     /// Role-based signatures per ticket
     pub crypto_guy_signed: bool,
     pub fiat_guy_signed: bool,
-    /// Optional client-side identifier to avoid collisions
+    /// Client-side reference (not part of the PDA seeds)
     pub ticket_id: u64,
     /// Creation timestamp
     pub created_at: i64,
+    /// Rate limiting: last signature state change (for cooldown)
+    pub last_sign_ts: i64,
     /// Bump for PDA
     pub bump: u8,
+    /// Wallet that must own the payout token account (None = FiatGuy)
+    pub payout_destination: Option<Pubkey>,
+    /// Off-chain payment reference (e.g. processor id hash); zeroes = none
+    pub reference: [u8; 32],
+    /// Sequential per-order index used in the PDA seeds (see UniversalOrder::ticket_index)
+    pub index: u64,
+    /// Expiry pushed out by extend_ticket (0 = not extended, expiry = created_at + grace)
+    pub extended_until: i64,
+    /// Deliver the payout as native SOL (WSOL orders only, set by the FiatGuy via set_unwrap_to_sol)
+    pub unwrap_to_sol: bool,
+    /// Fee locked on top of `amount` (fee-on-top orders; 0 otherwise)
+    pub prepaid_fee: u64,
+    /// Key that signs for the CryptoGuy (None = the CryptoGuy wallet itself)
+    pub crypto_guy_signer: Option<Pubkey>,
+    /// Key that signs for the FiatGuy (None = the FiatGuy wallet itself)
+    pub fiat_guy_signer: Option<Pubkey>,
+    /// Client nonce of the signature recorded by sign_universal_ticket (None = none given / no signature)
+    pub client_nonce: Option<u64>,
 }
 
 impl FillTicket {
@@ -114,5 +307,477 @@ impl FillTicket {
         1 +  // fiat_guy_signed
         8 +  // ticket_id
         8 +  // created_at
+        8 +  // last_sign_ts
+        1 +  // bump
+        (1 + 32) + // payout_destination (Option<Pubkey>)
+        32 + // reference
+        8 + // index
+        8 + // extended_until
+        1 + // unwrap_to_sol
+        8 + // prepaid_fee
+        (1 + 32) + // crypto_guy_signer (Option<Pubkey>)
+        (1 + 32) + // fiat_guy_signer (Option<Pubkey>)
+        (1 + 8); // client_nonce (Option<u64>)
+
+    /// Deadline for the FiatGuy signature: created_at + grace, or the extension if later
+    pub fn expires_at(&self, grace_secs: i64) -> i64 {
+        self.created_at.saturating_add(grace_secs).max(self.extended_until)
+    }
+
+    /// Latest expiry extensions can reach: creation + grace + one `max_extension_secs`, however
+    /// many extensions it takes to get there
+    pub fn max_expires_at(&self, grace_secs: i64, max_extension_secs: i64) -> i64 {
+        self.created_at.saturating_add(grace_secs).saturating_add(max_extension_secs)
+    }
+
+    /// FiatGuy has had `grace_secs` since ticket creation (plus any extension) to sign
+    pub fn fiat_sign_grace_elapsed(&self, now: i64, grace_secs: i64) -> bool {
+        now >= self.expires_at(grace_secs)
+    }
+
+    /// Owner the payout token account must have: payout_destination if set, else `fiat_guy`
+    pub fn payout_owner(&self, fiat_guy: Pubkey) -> Pubkey {
+        self.payout_destination.unwrap_or(fiat_guy)
+    }
+
+    /// Tokens this ticket holds in the vault (amount + prepaid fee), all refunded on cancel
+    pub fn locked_amount(&self) -> Result<u64> {
+        Ok(self.amount.checked_add(self.prepaid_fee).ok_or(UniversalOrderError::InvalidAmount)?)
+    }
+}
+
+/// Durable proof of a settlement, written by sign_universal_ticket when asked (create_receipt)
+/// PDA: [RECEIPT_SEED, ticket] - stays after the ticket and order close
+#[account]
+pub struct SettlementReceipt {
+    /// Settled ticket (closed by now)
+    pub ticket: Pubkey,
+    /// Parent order
+    pub order: Pubkey,
+    /// Ticket amount settled (100%)
+    pub amount: u64,
+    /// Fee taken at settlement
+    pub fee_amount: u64,
+    pub fiat_guy: Pubkey,
+    pub crypto_guy: Pubkey,
+    /// Settlement time
+    pub timestamp: i64,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+impl SettlementReceipt {
+    pub const SPACE: usize = 8 + // discriminator
+        32 + // ticket
+        32 + // order
+        8 +  // amount
+        8 +  // fee_amount
+        32 + // fiat_guy
+        32 + // crypto_guy
+        8 +  // timestamp
+        1;   // bump
+}
+
+/// Global program configuration (admin-managed runtime parameters)
+/// Single PDA: [b"config"]
+#[account]
+pub struct Config {
+    /// Rate limiting: minimum interval between ticket signatures (seconds)
+    pub fill_cooldown_secs: i64,
+    /// Rate limiting: max fills per order per day window
+    pub max_fills_per_day: u16,
+    /// Require locker token accounts to be the canonical ATA
+    pub require_ata: bool,
+    /// Default fee floor in raw base units for mints without an override (fee = max(bps fee, floor),
+    /// capped at total). Meant for 6-decimal stablecoins; other decimals classes need a MintFee entry
+    pub min_fee: u64,
+    /// Per-mint fee bps and floor overrides (unused slots have mint = Pubkey::default())
+    pub mint_fees: [MintFee; MAX_MINT_FEE_OVERRIDES],
+    /// Require the creator's signature on BUY-order creation (SELL creators already sign as locker)
+    pub require_creator_sig: bool,
+    /// Seconds after ticket creation before CryptoGuy may time out a ticket FiatGuy hasn't signed
+    pub fiat_sign_grace_secs: i64,
+    /// Require Token-2022 fee accounts to carry the ImmutableOwner extension
+    pub require_immutable_fee_owner: bool,
+    /// Wallet that receives rent when tickets/vaults/orders close (defaults to the admin)
+    pub rent_receiver: Pubkey,
+    /// Max seconds a single extend_ticket call may push a ticket's expiry out
+    pub max_ticket_extension_secs: i64,
+    /// Minimum ticket size as a fraction of the order: amount >= crypto_amount / denominator (0 = off)
+    pub min_fill_denominator: u16,
+    /// Max distinct acceptors across an order's tickets (1..=MAX_ORDER_ACCEPTORS)
+    pub max_distinct_acceptors: u8,
+    /// Last global event sequence number handed out (see `next_event_seq`)
+    pub event_seq: u64,
+    /// Advisory notional floor: offers need fiat_amount >= this (fiat minor units, 0 = off)
+    pub min_fiat_amount: u64,
+    /// Fail (instead of only logging) when an order's counters are found inconsistent
+    pub strict_invariants: bool,
+    /// Bump for PDA
+    pub bump: u8,
+}
+
+/// Per-mint fee override entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MintFee {
+    pub mint: Pubkey,
+    pub fee_bps: u16,
+    /// Fee floor in this mint's base units (replaces config.min_fee for the mint)
+    pub min_fee: u64,
+}
+
+impl MintFee {
+    pub const SPACE: usize = 32 + 2 + 8;
+}
+
+/// Standard reasons for admin ticket resolutions (passed as `reason_code: u8`, echoed in UniversalAdminResolved)
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u8)]
+pub enum ResolutionReason {
+    FraudRefund = 0,
+    FiatConfirmed = 1,
+    Timeout = 2,
+    Other = 3,
+}
+
+impl ResolutionReason {
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(Self::FraudRefund),
+            1 => Some(Self::FiatConfirmed),
+            2 => Some(Self::Timeout),
+            3 => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+impl Config {
+    pub const SPACE: usize = 8 + // discriminator
+        8 +  // fill_cooldown_secs
+        2 +  // max_fills_per_day
+        1 +  // require_ata
+        8 +  // min_fee
+        MintFee::SPACE * MAX_MINT_FEE_OVERRIDES + // mint_fees
+        1 +  // require_creator_sig
+        8 +  // fiat_sign_grace_secs
+        1 +  // require_immutable_fee_owner
+        32 + // rent_receiver
+        8 +  // max_ticket_extension_secs
+        2 +  // min_fill_denominator
+        1 +  // max_distinct_acceptors
+        8 +  // event_seq
+        8 +  // min_fiat_amount
+        1 +  // strict_invariants
         1;   // bump
-}
\ No newline at end of file
+
+    /// Smallest ticket allowed on an order of `crypto_amount` (0 when the check is off).
+    /// A fraction of the order, so it scales with the mint's decimals on its own
+    pub fn min_fill_amount(&self, crypto_amount: u64) -> u64 {
+        if self.min_fill_denominator == 0 {
+            0
+        } else {
+            crypto_amount / self.min_fill_denominator as u64
+        }
+    }
+
+    /// Fee bps for `mint`: its override if present, otherwise the default FEE_BASIS_POINTS
+    pub fn fee_bps_for(&self, mint: &Pubkey) -> u64 {
+        self.mint_fees
+            .iter()
+            .find(|f| f.mint == *mint && *mint != Pubkey::default())
+            .map_or(FEE_BASIS_POINTS, |f| f.fee_bps as u64)
+    }
+
+    /// Fee floor for `mint`: its override's floor if present, otherwise the default config.min_fee
+    pub fn min_fee_for(&self, mint: &Pubkey) -> u64 {
+        self.mint_fees
+            .iter()
+            .find(|f| f.mint == *mint && *mint != Pubkey::default())
+            .map_or(self.min_fee, |f| f.min_fee)
+    }
+
+    /// Next program-wide event sequence number (1, 2, ...). Stamped into the lifecycle events as
+    /// `seq` so indexers get a total order across orders, beyond slot and transaction index
+    pub fn next_event_seq(&mut self) -> u64 {
+        self.event_seq = self.event_seq.saturating_add(1);
+        self.event_seq
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(crypto_amount: u64, filled_amount: u64, reserved_amount: u64) -> UniversalOrder {
+        UniversalOrder {
+            creator: Pubkey::default(),
+            acceptor: None,
+            crypto_mint: Pubkey::default(),
+            is_sell_order: true,
+            crypto_amount,
+            fiat_amount: 0,
+            order_id: 1,
+            filled_amount,
+            reserved_amount,
+            last_action_ts: 0,
+            daily_fill_count: 0,
+            daily_reset_ts: 0,
+            created_at: 0,
+            updated_at: 0,
+            vault: Pubkey::default(),
+            bump: 0,
+            locked: false,
+            crypto_first: false,
+            total_fees_collected: 0,
+            settle_count: 0,
+            refund_count: 0,
+            vault_authority: Pubkey::default(),
+            vault_bump: 0,
+            ticket_index: 0,
+            fee_destination: None,
+            acceptors: [Pubkey::default(); MAX_ORDER_ACCEPTORS],
+            acceptor_count: 0,
+            fee_on_top: false,
+            reserved_fees: 0,
+            allowed_takers: [Pubkey::default(); MAX_ALLOWED_TAKERS],
+            takers_whitelisted: false,
+        }
+    }
+
+    #[test]
+    fn is_complete_requires_full_fill_and_no_reservations() {
+        assert!(order(100, 100, 0).is_complete());
+        assert!(!order(100, 60, 0).is_complete());
+        assert!(!order(100, 60, 40).is_complete());
+        assert!(!order(100, 100, 10).is_complete());
+        assert!(order(0, 0, 0).is_complete());
+    }
+
+    #[test]
+    fn is_cancellable_requires_no_reservations() {
+        assert!(order(100, 0, 0).is_cancellable());
+        assert!(order(100, 60, 0).is_cancellable());
+        assert!(!order(100, 0, 100).is_cancellable());
+        assert!(!order(100, 60, 40).is_cancellable());
+    }
+
+    #[test]
+    fn consistency_warning_flags_over_reservation() {
+        assert_eq!(order(100, 60, 40).consistency_warning(), None);
+        assert_eq!(order(100, 0, 0).consistency_warning(), None);
+        // Deliberately corrupted: 50 reserved, only 40 left to fill
+        assert_eq!(
+            order(100, 60, 50).consistency_warning().as_deref(),
+            Some("order 1 inconsistent: reserved_amount 50 > remaining_amount 40")
+        );
+    }
+
+    #[test]
+    fn ticket_amount_capped_at_order_total() {
+        let o = order(100, 0, 100);
+        assert!(o.check_ticket_amount(99).is_ok());
+        assert!(o.check_ticket_amount(100).is_ok());
+        assert_eq!(o.check_ticket_amount(101).unwrap_err(), UniversalOrderError::TicketExceedsOrder.into());
+    }
+
+    #[test]
+    fn taker_whitelist_limits_acceptors() {
+        let mut o = order(100, 0, 0);
+        let maker_pick = Pubkey::new_unique();
+        let stranger = Pubkey::new_unique();
+        // Open order: anyone
+        o.set_allowed_takers(&[]).unwrap();
+        assert!(o.check_taker(&stranger).is_ok());
+        // Private order: listed takers only
+        o.set_allowed_takers(&[maker_pick]).unwrap();
+        assert!(o.check_taker(&maker_pick).is_ok());
+        assert_eq!(o.check_taker(&stranger).unwrap_err(), UniversalOrderError::Unauthorized.into());
+        // Unused slots are not wildcards
+        assert!(o.check_taker(&Pubkey::default()).is_err());
+        let five = [Pubkey::new_unique(); MAX_ALLOWED_TAKERS + 1];
+        assert_eq!(o.set_allowed_takers(&five).unwrap_err(), UniversalOrderError::TooManyAllowedTakers.into());
+    }
+
+    #[test]
+    fn fee_owner_defaults_to_admin() {
+        let mut o = order(100, 0, 0);
+        assert_eq!(o.fee_owner(), ADMIN_PUBKEY);
+        let pool = Pubkey::new_unique();
+        o.fee_destination = Some(pool);
+        assert_eq!(o.fee_owner(), pool);
+    }
+
+    #[test]
+    fn track_acceptor_caps_new_acceptors_only() {
+        let mut o = order(100, 0, 0);
+        let (a, b, c) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(o.track_acceptor(a, 2).is_ok());
+        assert!(o.track_acceptor(b, 2).is_ok());
+        assert_eq!(o.acceptor_count, 2);
+        // Cap reached: a third acceptor is rejected, known ones still pass
+        assert_eq!(o.track_acceptor(c, 2).unwrap_err(), UniversalOrderError::TooManyAcceptors.into());
+        assert!(o.track_acceptor(a, 2).is_ok());
+        assert_eq!(o.acceptor_count, 2);
+        // Capacity bounds the cap
+        assert!(o.track_acceptor(c, u8::MAX).is_ok());
+        o.track_acceptor(Pubkey::new_unique(), u8::MAX).unwrap();
+        assert!(o.track_acceptor(Pubkey::new_unique(), u8::MAX).is_err());
+    }
+
+    #[test]
+    fn daily_fill_window_counts_and_resets() {
+        let mut o = order(100, 0, 0);
+        let t0 = 10 * SECONDS_PER_DAY;
+        assert_eq!(o.daily_fills(t0), 0);
+        assert_eq!(o.daily_window_resets_in(t0), 0);
+
+        // Opening the first window is not a reset
+        assert_eq!(o.record_fill(t0), None);
+        assert_eq!(o.record_fill(t0 + 60), None);
+        assert_eq!(o.daily_fills(t0 + 60), 2);
+        assert_eq!(o.daily_window_resets_in(t0 + 60), SECONDS_PER_DAY - 60);
+
+        // Window lapses a day after its first fill
+        assert_eq!(o.daily_fills(t0 + SECONDS_PER_DAY - 1), 2);
+        assert_eq!(o.daily_fills(t0 + SECONDS_PER_DAY), 0);
+        // The lapsed window's count is reported once, by the fill that resets it
+        assert_eq!(o.record_fill(t0 + SECONDS_PER_DAY), Some(2));
+        assert_eq!(o.record_fill(t0 + SECONDS_PER_DAY + 1), None);
+        assert_eq!(o.daily_fills(t0 + SECONDS_PER_DAY), 2);
+        assert_eq!(o.daily_reset_ts, t0 + SECONDS_PER_DAY);
+    }
+
+    #[test]
+    fn remaining_fills_resets_after_a_day() {
+        let mut o = order(100, 0, 0);
+        let t0 = 10 * SECONDS_PER_DAY;
+        assert_eq!(o.remaining_fills(2, t0), 2);
+        o.record_fill(t0);
+        assert_eq!(o.remaining_fills(2, t0), 1);
+        o.record_fill(t0 + 60);
+        assert_eq!(o.remaining_fills(2, t0 + 60), 0);
+        // Past the budget settlement still records, the view never goes negative
+        o.record_fill(t0 + 120);
+        assert_eq!(o.remaining_fills(2, t0 + 120), 0);
+        // Full budget back once the window lapses, before any new fill
+        assert_eq!(o.remaining_fills(2, t0 + SECONDS_PER_DAY - 1), 0);
+        assert_eq!(o.remaining_fills(2, t0 + SECONDS_PER_DAY), 2);
+        assert_eq!(o.daily_window_resets_in(t0 + SECONDS_PER_DAY), 0);
+    }
+
+    #[test]
+    fn fiat_sign_grace_elapsed_counts_from_ticket_creation() {
+        let ticket = FillTicket {
+            order: Pubkey::default(),
+            acceptor: Pubkey::default(),
+            amount: 0,
+            crypto_guy_signed: false,
+            fiat_guy_signed: false,
+            ticket_id: 1,
+            created_at: 1_000,
+            last_sign_ts: 0,
+            bump: 0,
+            payout_destination: None,
+            reference: [0u8; 32],
+            index: 0,
+            extended_until: 0,
+            unwrap_to_sol: false,
+            prepaid_fee: 0,
+            crypto_guy_signer: None,
+            fiat_guy_signer: None,
+            client_nonce: None,
+        };
+        assert!(!ticket.fiat_sign_grace_elapsed(1_059, 60));
+        assert!(ticket.fiat_sign_grace_elapsed(1_060, 60));
+        assert!(ticket.fiat_sign_grace_elapsed(1_000, 0));
+
+        let extended = FillTicket { extended_until: 1_500, ..ticket };
+        assert_eq!(extended.expires_at(60), 1_500);
+        assert!(!extended.fiat_sign_grace_elapsed(1_499, 60));
+        assert!(extended.fiat_sign_grace_elapsed(1_500, 60));
+        // A longer config grace still wins over a shorter extension
+        assert_eq!(extended.expires_at(1_000), 2_000);
+
+        // Extensions are capped in absolute terms, not per call
+        assert_eq!(ticket.max_expires_at(60, 500), 1_560);
+        assert_eq!(extended.max_expires_at(60, 500), 1_560);
+    }
+
+    #[test]
+    fn resolution_reason_round_trips_known_codes() {
+        for reason in [
+            ResolutionReason::FraudRefund,
+            ResolutionReason::FiatConfirmed,
+            ResolutionReason::Timeout,
+            ResolutionReason::Other,
+        ] {
+            assert_eq!(ResolutionReason::from_code(reason as u8), Some(reason));
+        }
+        assert_eq!(ResolutionReason::from_code(4), None);
+    }
+
+    fn config() -> Config {
+        Config {
+            fill_cooldown_secs: 0,
+            max_fills_per_day: 0,
+            require_ata: false,
+            min_fee: 0,
+            mint_fees: [MintFee::default(); MAX_MINT_FEE_OVERRIDES],
+            require_creator_sig: false,
+            fiat_sign_grace_secs: 0,
+            require_immutable_fee_owner: false,
+            rent_receiver: Pubkey::default(),
+            max_ticket_extension_secs: 0,
+            min_fill_denominator: 0,
+            max_distinct_acceptors: 0,
+            event_seq: 0,
+            min_fiat_amount: 0,
+            strict_invariants: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn fee_bps_for_uses_override_or_default() {
+        let usdc = Pubkey::new_unique();
+        let usdt = Pubkey::new_unique();
+        let mut config = config();
+        config.mint_fees[0] = MintFee { mint: usdt, fee_bps: 50, min_fee: 0 };
+        assert_eq!(config.fee_bps_for(&usdt), 50);
+        assert_eq!(config.fee_bps_for(&usdc), FEE_BASIS_POINTS);
+        assert_eq!(config.fee_bps_for(&Pubkey::default()), FEE_BASIS_POINTS);
+    }
+
+    #[test]
+    fn min_fee_for_uses_override_or_default() {
+        let usdc = Pubkey::new_unique();
+        let wsol = Pubkey::new_unique();
+        let mut config = config();
+        config.min_fee = 10_000;
+        // 9-decimal mint gets its own floor; an override with no floor turns the default off
+        config.mint_fees[0] = MintFee { mint: wsol, fee_bps: 20, min_fee: 1_000_000 };
+        assert_eq!(config.min_fee_for(&wsol), 1_000_000);
+        assert_eq!(config.min_fee_for(&usdc), 10_000);
+        config.mint_fees[0].min_fee = 0;
+        assert_eq!(config.min_fee_for(&wsol), 0);
+        assert_eq!(config.min_fee_for(&Pubkey::default()), 10_000);
+    }
+
+    #[test]
+    fn next_event_seq_starts_at_one_and_increments() {
+        let mut config = config();
+        assert_eq!(config.next_event_seq(), 1);
+        assert_eq!(config.next_event_seq(), 2);
+        assert_eq!(config.event_seq, 2);
+    }
+
+    #[test]
+    fn min_fill_amount_is_a_fraction_of_the_order() {
+        // Denominator 0 disables the minimum
+        assert_eq!(config().min_fill_amount(1_000_000), 0);
+        let config = Config { min_fill_denominator: 100, ..config() };
+        assert_eq!(config.min_fill_amount(1_000_000), 10_000);
+        assert_eq!(config.min_fill_amount(99), 0);
+    }
+}
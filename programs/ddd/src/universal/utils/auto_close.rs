@@ -1,77 +1,113 @@
 use anchor_lang::prelude::*;
 use anchor_lang::prelude::AccountsClose;
-use anchor_spl::token::{TokenAccount, CloseAccount, close_account};
+use anchor_spl::token_interface;
 use crate::universal::state::*;
+use crate::universal::utils::seeds::ORDER_SEED;
 
-/// Auto-close vault and order if conditions are met
-/// Returns rent to admin_rent_receiver
-/// 
-/// For payout: closes only if order is fully completed (remaining=0, reserved=0)
-/// For refund: closes always (order is cancelled)
-pub fn auto_close_if_needed<'info>(
+/// Close the resolved ticket and, if the vault is empty and the order is done, the vault and order too
+/// Returns rent to rent_receiver; the full close-out goes through `close_all`
+///
+/// For payout: order closes only if fully completed (remaining=0, reserved=0)
+/// For refund: order closes once no active reservations remain
+/// Returns true if the order was closed
+pub fn close_ticket_and_maybe_order<'info>(
     order: &mut Account<'info, UniversalOrder>,
-    vault: &Account<'info, TokenAccount>,
-    admin_rent_receiver: &AccountInfo<'info>,
+    vault: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    ticket: &mut Account<'info, FillTicket>,
+    rent_receiver: &AccountInfo<'info>,
     token_program: &AccountInfo<'info>,
-    is_refund: bool, // true = always close, false = close only if completed
-) -> Result<()> {
-    let should_close = if is_refund {
-        // Refund = order cancelled, always close
-        msg!("Auto-close check: is_refund=true, will close");
-        true
-    } else {
-        // Payout = check if order fully completed
-        let remaining = order.remaining_amount();
-        let will_close = remaining == 0 && order.reserved_amount == 0;
-        msg!("Auto-close check: remaining={}, reserved={}, will_close={}", remaining, order.reserved_amount, will_close);
-        will_close
-    };
+    is_refund: bool,
+) -> Result<bool> {
+    // Read vault balance directly from account data (cached amount is stale after CPI)
+    let vault_account = vault.to_account_info();
+    let vault_data = vault_account.try_borrow_data()?;
+    let vault_balance = u64::from_le_bytes(vault_data[64..72].try_into().unwrap());
+    drop(vault_data);
+
+    let order_done = if is_refund { order.is_cancellable() } else { order.is_complete() };
+    msg!("Auto-close check: vault_balance={}, is_refund={}, order_done={}", vault_balance, is_refund, order_done);
 
-    if !should_close {
-        msg!("Auto-close skipped: conditions not met");
-        return Ok(());
+    if vault_balance != 0 || !order_done {
+        // Order stays open, just close ticket
+        ticket.close(rent_receiver.clone())?;
+        msg!("Ticket closed");
+        return Ok(false);
     }
 
-    msg!("Auto-closing vault and order, returning rent to admin.");
+    close_all(order, vault, ticket, rent_receiver, token_program)?;
 
-    // Close vault if empty
-    let vault_balance = vault.amount;
-    if vault_balance == 0 {
-        let order_creator = order.creator;
-        let order_mint = order.crypto_mint;
-        let order_id_le = order.order_id.to_le_bytes();
-        let order_bump = order.bump;
+    Ok(true)
+}
 
-        let seeds = &[
-            b"universal_order".as_ref(),
-            order_creator.as_ref(),
-            order_mint.as_ref(),
-            order_id_le.as_ref(),
-            &[order_bump],
-        ];
-        let signer = &[&seeds[..]];
+/// Close-out of an order with its last ticket, all rent to `rent_receiver`: vault and order via
+/// `close_order_and_vault`, then the ticket (LAST, since callers still hold it mutably borrowed
+/// out of their accounts). The vault must already be empty (close_account fails otherwise)
+pub fn close_all<'info>(
+    order: &mut Account<'info, UniversalOrder>,
+    vault: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    ticket: &mut Account<'info, FillTicket>,
+    rent_receiver: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+) -> Result<()> {
+    close_order_and_vault(order, vault, rent_receiver, token_program, 0)?;
+
+    ticket.close(rent_receiver.clone())?;
+    msg!("Ticket closed");
 
-        let close_vault_accounts = CloseAccount {
+    Ok(())
+}
+
+/// The one place an order is closed together with its vault (admin_force_close_order only handles
+/// orders whose vault is already gone): vault → OrderClosed → order, all rent to `rent_receiver`.
+/// The vault CPI is signed with the order seeds, so the order must still be open; OrderClosed is
+/// emitted from its final counters before it closes. `dust_amount` is what the caller moved out of
+/// the vault just before (sweep_fees), 0 otherwise. The vault must already be empty
+pub fn close_order_and_vault<'info>(
+    order: &mut Account<'info, UniversalOrder>,
+    vault: &InterfaceAccount<'info, token_interface::TokenAccount>,
+    rent_receiver: &AccountInfo<'info>,
+    token_program: &AccountInfo<'info>,
+    dust_amount: u64,
+) -> Result<()> {
+    let order_creator = order.creator;
+    let order_mint = order.crypto_mint;
+    let order_id_le = order.order_id.to_le_bytes();
+    let order_bump = order.bump;
+
+    let seeds = &[
+        ORDER_SEED,
+        order_creator.as_ref(),
+        order_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order_bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.clone(),
+        token_interface::CloseAccount {
             account: vault.to_account_info(),
-            destination: admin_rent_receiver.clone(),
+            destination: rent_receiver.clone(),
             authority: order.to_account_info(),
-        };
+        },
+        signer,
+    );
+    token_interface::close_account(cpi_ctx)?;
+    msg!("Vault closed");
 
-        let cpi_ctx = CpiContext::new_with_signer(
-            token_program.clone(),
-            close_vault_accounts,
-            signer,
-        );
+    emit!(crate::universal::events::OrderClosed {
+        order: order.key(),
+        creator: order_creator,
+        dust_amount,
+        total_fees_collected: order.total_fees_collected,
+        settle_count: order.settle_count,
+        refund_count: order.refund_count,
+        rent_returned_to: rent_receiver.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
 
-        close_account(cpi_ctx)?;
-        msg!("Vault closed, rent returned to admin");
-
-        // Close order account and return rent to admin (only after vault is closed)
-        order.close(admin_rent_receiver.clone())?;
-        msg!("Order closed, rent returned to admin");
-    } else {
-        msg!("Warning: Vault still has {} tokens, cannot close yet", vault_balance);
-    }
+    order.close(rent_receiver.clone())?;
+    msg!("Order closed");
 
     Ok(())
 }
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+// Ed25519 native program instruction layout (single signature, all data inline):
+// [num_signatures: u8, padding: u8, offsets: 7 x u16 LE, ...pubkey / signature / message]
+const OFFSETS_START: usize = 2;
+const OFFSETS_LEN: usize = 14;
+const CURRENT_IX: u16 = u16::MAX;
+
+/// Parse an Ed25519 verify instruction carrying exactly one signature whose pubkey,
+/// signature and message all live in the instruction itself
+/// Returns (signer pubkey, signed message) or None if the layout is anything else
+pub fn parse_single_signature(data: &[u8]) -> Option<(Pubkey, &[u8])> {
+    if data.len() < OFFSETS_START + OFFSETS_LEN || data[0] != 1 {
+        return None;
+    }
+    let field = |i: usize| {
+        let at = OFFSETS_START + i * 2;
+        u16::from_le_bytes([data[at], data[at + 1]])
+    };
+    let (sig_ix, pubkey_offset, pubkey_ix) = (field(1), field(2) as usize, field(3));
+    let (msg_offset, msg_size, msg_ix) = (field(4) as usize, field(5) as usize, field(6));
+
+    // Data referenced from other instructions could be swapped under the signature check
+    if sig_ix != CURRENT_IX || pubkey_ix != CURRENT_IX || msg_ix != CURRENT_IX {
+        return None;
+    }
+    let pubkey = data.get(pubkey_offset..pubkey_offset.checked_add(32)?)?;
+    let message = data.get(msg_offset..msg_offset.checked_add(msg_size)?)?;
+    Some((Pubkey::try_from(pubkey).ok()?, message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Layout produced by web3.js Ed25519Program.createInstructionWithPublicKey
+    fn ed25519_ix(pubkey: &Pubkey, message: &[u8], ix_index: u16) -> Vec<u8> {
+        let pubkey_offset: u16 = 16;
+        let sig_offset: u16 = pubkey_offset + 32;
+        let msg_offset: u16 = sig_offset + 64;
+        let mut data = vec![1u8, 0];
+        for v in [sig_offset, ix_index, pubkey_offset, ix_index, msg_offset, message.len() as u16, ix_index] {
+            data.extend_from_slice(&v.to_le_bytes());
+        }
+        data.extend_from_slice(pubkey.as_ref());
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(message);
+        data
+    }
+
+    #[test]
+    fn parses_inline_single_signature() {
+        let signer = Pubkey::new_unique();
+        let data = ed25519_ix(&signer, b"ticket-message", CURRENT_IX);
+        let (pubkey, message) = parse_single_signature(&data).unwrap();
+        assert_eq!(pubkey, signer);
+        assert_eq!(message, b"ticket-message");
+    }
+
+    #[test]
+    fn rejects_cross_instruction_references_and_bad_layouts() {
+        let signer = Pubkey::new_unique();
+        assert!(parse_single_signature(&ed25519_ix(&signer, b"m", 0)).is_none());
+
+        let mut two_sigs = ed25519_ix(&signer, b"m", CURRENT_IX);
+        two_sigs[0] = 2;
+        assert!(parse_single_signature(&two_sigs).is_none());
+
+        let truncated = ed25519_ix(&signer, b"message", CURRENT_IX);
+        assert!(parse_single_signature(&truncated[..truncated.len() - 1]).is_none());
+        assert!(parse_single_signature(&[]).is_none());
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, Mint, transfer_checked, TransferChecked};
+use crate::universal::state::{Config, UniversalOrder};
+use crate::universal::errors::UniversalOrderError;
+use crate::universal::utils::token_checks::has_immutable_owner;
+use crate::universal::utils::validate::validate_ata;
+use crate::universal::utils::seeds::ORDER_SEED;
+
+/// Pay `fee_amount` from the vault to the order's fee owner (admin, or `order.fee_destination`)
+/// Shared by every path that takes a fee so they validate the same account: owned by
+/// `order.fee_owner()` and, when `config.require_immutable_fee_owner` is set, a Token-2022 account
+/// must carry ImmutableOwner. The fee account is only required when `fee_amount > 0`.
+/// The transfer is signed with the order seeds, so the vault authority must be the order PDA
+pub fn pay_fee<'info>(
+    order: &Account<'info, UniversalOrder>,
+    config: &Config,
+    fee_account: Option<&InterfaceAccount<'info, TokenAccount>>,
+    vault: &InterfaceAccount<'info, TokenAccount>,
+    mint: &InterfaceAccount<'info, Mint>,
+    token_program: &AccountInfo<'info>,
+    fee_amount: u64,
+) -> Result<()> {
+    if fee_amount == 0 {
+        return Ok(());
+    }
+    let fee_account = fee_account.ok_or(UniversalOrderError::TokenAccountRequired)?;
+    validate_ata(fee_account, &order.crypto_mint, &order.fee_owner())?;
+    // CHECK: Token-2022 treasury accounts must not allow owner reassignment (when enabled)
+    let fee_account_info = fee_account.to_account_info();
+    if config.require_immutable_fee_owner && fee_account_info.owner == &anchor_spl::token_2022::ID {
+        require!(has_immutable_owner(&fee_account_info)?, UniversalOrderError::FeeAccountOwnerMutable);
+    }
+
+    let order_id_le = order.order_id.to_le_bytes();
+    let seeds = &[
+        ORDER_SEED,
+        order.creator.as_ref(),
+        order.crypto_mint.as_ref(),
+        order_id_le.as_ref(),
+        &[order.bump],
+    ];
+    let signer = &[&seeds[..]];
+
+    let cpi = CpiContext::new_with_signer(
+        token_program.clone(),
+        TransferChecked {
+            from: vault.to_account_info(),
+            to: fee_account_info,
+            authority: order.to_account_info(),
+            mint: mint.to_account_info(),
+        },
+        signer,
+    );
+    transfer_checked(cpi, fee_amount, mint.decimals)
+}
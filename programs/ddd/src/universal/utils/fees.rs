@@ -1,17 +1,173 @@
 use anchor_lang::prelude::*;
+use crate::universal::errors::UniversalOrderError;
 
 pub const FEE_BASIS_POINTS: u64 = 20;
+/// Protocol ceiling for per-mint fee overrides (5%)
+pub const MAX_FEE_BASIS_POINTS: u16 = 500;
+/// Protocol ceiling for the default config.min_fee, in raw base units (1 token at 6 decimals -
+/// the decimals class the default floor is meant for; see max_min_fee for per-mint floors)
+pub const MAX_MIN_FEE: u64 = 1_000_000;
 
-pub fn calculate_fee(total: u64) -> Result<(u64, u64)> {
-    let fee = total
-        .checked_mul(FEE_BASIS_POINTS)
+/// Protocol ceiling for a per-mint fee floor: 1 whole token of a mint with `decimals`
+pub fn max_min_fee(decimals: u8) -> u64 {
+    10u64.checked_pow(decimals as u32).unwrap_or(u64::MAX)
+}
+
+/// Fee = max(total * fee_bps / 10_000, min_fee), capped at `total` so net never underflows
+/// `fee_bps` comes from Config::fee_bps_for (per-mint override or FEE_BASIS_POINTS)
+pub fn calculate_fee(total: u64, fee_bps: u64, min_fee: u64) -> Result<(u64, u64)> {
+    let bps_fee = total
+        .checked_mul(fee_bps)
         .ok_or(ProgramError::ArithmeticOverflow)?
         .checked_div(10_000)
         .ok_or(ProgramError::ArithmeticOverflow)?;
+    let fee = bps_fee.max(min_fee).min(total);
     
     let net = total
         .checked_sub(fee)
         .ok_or(ProgramError::ArithmeticOverflow)?;
     
     Ok((fee, net))
-}
\ No newline at end of file
+}
+
+/// (fee, net) for settling a ticket of `amount`: fee-inclusive orders take calculate_fee out of
+/// `amount`; fee-on-top orders deliver all of `amount` and charge the fee prepaid at lock time
+pub fn settlement_split(
+    amount: u64,
+    prepaid_fee: u64,
+    fee_on_top: bool,
+    fee_bps: u64,
+    min_fee: u64,
+) -> Result<(u64, u64)> {
+    if fee_on_top {
+        Ok((prepaid_fee, amount))
+    } else {
+        calculate_fee(amount, fee_bps, min_fee)
+    }
+}
+
+/// (fee, net, refund) for an admin split of a ticket of `amount`: `settle_amount` settles as in
+/// settlement_split (fee-on-top orders charge the prorated share of `prepaid_fee`), the rest is
+/// refunded together with the unused share of the prepaid fee. Fee-inclusive orders prepay nothing
+pub fn split_resolve_amounts(
+    amount: u64,
+    settle_amount: u64,
+    prepaid_fee: u64,
+    fee_on_top: bool,
+    fee_bps: u64,
+    min_fee: u64,
+) -> Result<(u64, u64, u64)> {
+    require!(settle_amount > 0 && settle_amount < amount, UniversalOrderError::InvalidAmount);
+    let refund_amount = amount - settle_amount;
+    if fee_on_top {
+        let fee = prorate_fee(prepaid_fee, settle_amount, amount)?;
+        Ok((fee, settle_amount, refund_amount + (prepaid_fee - fee)))
+    } else {
+        let (fee, net) = calculate_fee(settle_amount, fee_bps, min_fee)?;
+        Ok((fee, net, refund_amount))
+    }
+}
+
+/// Share of `prepaid_fee` that belongs to `part` of a ticket of `whole` (rounded down, so the
+/// ticket keeping the rest of the fee never comes up short)
+pub fn prorate_fee(prepaid_fee: u64, part: u64, whole: u64) -> Result<u64> {
+    require!(whole > 0 && part <= whole, UniversalOrderError::InvalidAmount);
+    let share = (prepaid_fee as u128) * (part as u128) / (whole as u128);
+    Ok(share as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bps_fee_below_floor_uses_min_fee() {
+        // 20 bps of 1_000 = 2 < 100
+        assert_eq!(calculate_fee(1_000, FEE_BASIS_POINTS, 100).unwrap(), (100, 900));
+    }
+
+    #[test]
+    fn bps_fee_above_floor_uses_bps() {
+        // 20 bps of 10_000_000 = 20_000 > 100
+        assert_eq!(calculate_fee(10_000_000, FEE_BASIS_POINTS, 100).unwrap(), (20_000, 9_980_000));
+    }
+
+    #[test]
+    fn zero_floor_keeps_plain_bps() {
+        assert_eq!(calculate_fee(100, FEE_BASIS_POINTS, 0).unwrap(), (0, 100));
+    }
+
+    #[test]
+    fn default_bps_within_protocol_ceiling() {
+        assert!(FEE_BASIS_POINTS <= MAX_FEE_BASIS_POINTS as u64);
+        // Highest allowed override and floor still leave a net on a 10 USDC settlement
+        assert_eq!(calculate_fee(10_000_000, MAX_FEE_BASIS_POINTS as u64, MAX_MIN_FEE).unwrap(), (1_000_000, 9_000_000));
+    }
+
+    #[test]
+    fn per_mint_floor_ceiling_scales_with_decimals() {
+        assert_eq!(max_min_fee(6), MAX_MIN_FEE);
+        assert_eq!(max_min_fee(9), 1_000_000_000);
+        assert_eq!(max_min_fee(0), 1);
+        assert_eq!(max_min_fee(20), u64::MAX);
+    }
+
+    #[test]
+    fn per_mint_bps_overrides_default() {
+        // 100 bps of 1_000_000 = 10_000
+        assert_eq!(calculate_fee(1_000_000, 100, 0).unwrap(), (10_000, 990_000));
+    }
+
+    #[test]
+    fn floor_at_or_above_total_is_capped() {
+        assert_eq!(calculate_fee(50, FEE_BASIS_POINTS, 50).unwrap(), (50, 0));
+        assert_eq!(calculate_fee(50, FEE_BASIS_POINTS, 1_000).unwrap(), (50, 0));
+    }
+
+    #[test]
+    fn fee_on_top_delivers_the_full_amount() {
+        // Fee-inclusive: FiatGuy gets amount - fee
+        assert_eq!(settlement_split(10_000_000, 0, false, FEE_BASIS_POINTS, 0).unwrap(), (20_000, 9_980_000));
+        // Fee-on-top: the prepaid fee goes to the treasury, FiatGuy gets the whole amount
+        assert_eq!(settlement_split(10_000_000, 20_000, true, FEE_BASIS_POINTS, 0).unwrap(), (20_000, 10_000_000));
+        // ...even if the config changed since the lock
+        assert_eq!(settlement_split(10_000_000, 20_000, true, 100, 500_000).unwrap(), (20_000, 10_000_000));
+    }
+
+    #[test]
+    fn prorated_fee_shares_add_up() {
+        assert_eq!(prorate_fee(20_000, 6_000_000, 10_000_000).unwrap(), 12_000);
+        // Rounded down; the other share takes the remainder
+        let share = prorate_fee(7, 1, 3).unwrap();
+        assert_eq!(share, 2);
+        assert_eq!(7 - share, 5);
+        assert_eq!(prorate_fee(u64::MAX, u64::MAX, u64::MAX).unwrap(), u64::MAX);
+        assert!(prorate_fee(1, 2, 1).is_err());
+        assert!(prorate_fee(1, 0, 0).is_err());
+    }
+
+    #[test]
+    fn fee_inclusive_split_refunds_only_the_unsettled_part() {
+        // 60/40 of 10 USDC, nothing prepaid: fee comes out of the settled 6
+        assert_eq!(
+            split_resolve_amounts(10_000_000, 6_000_000, 0, false, FEE_BASIS_POINTS, 0).unwrap(),
+            (12_000, 5_988_000, 4_000_000)
+        );
+        // A min_fee above the prorated bps still can't underflow the refund
+        assert_eq!(
+            split_resolve_amounts(10_000_000, 6_000_000, 0, false, FEE_BASIS_POINTS, 50_000).unwrap(),
+            (50_000, 5_950_000, 4_000_000)
+        );
+    }
+
+    #[test]
+    fn fee_on_top_split_returns_the_unused_prepaid_fee() {
+        // 60/40 of 10 USDC with 20_000 prepaid: 12_000 charged, 8_000 refunded with the 4
+        assert_eq!(
+            split_resolve_amounts(10_000_000, 6_000_000, 20_000, true, FEE_BASIS_POINTS, 0).unwrap(),
+            (12_000, 6_000_000, 4_008_000)
+        );
+        assert!(split_resolve_amounts(10_000_000, 10_000_000, 20_000, true, FEE_BASIS_POINTS, 0).is_err());
+        assert!(split_resolve_amounts(10_000_000, 0, 0, false, FEE_BASIS_POINTS, 0).is_err());
+    }
+}
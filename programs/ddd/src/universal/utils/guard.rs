@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::universal::state::*;
+use crate::universal::errors::UniversalOrderError;
+
+/// Set the order processing flag and persist it immediately,
+/// so a re-entrant instruction reading the account mid-CPI sees it locked
+pub fn acquire_order_lock(order: &mut Account<'_, UniversalOrder>) -> Result<()> {
+    require!(!order.locked, UniversalOrderError::Reentrancy);
+    order.locked = true;
+    order.exit(&crate::ID)?;
+    Ok(())
+}
+
+/// Clear the order processing flag (persisted by Anchor on instruction exit)
+pub fn release_order_lock(order: &mut Account<'_, UniversalOrder>) {
+    order.locked = false;
+}
+
+/// Observability check run at the end of state-mutating instructions: logs a warning when the
+/// order's counters are inconsistent (never expected), and fails with config.strict_invariants
+pub fn debug_assert_consistency(order: &UniversalOrder, strict: bool) -> Result<()> {
+    if let Some(warning) = order.consistency_warning() {
+        msg!("WARNING: {}", warning);
+        require!(!strict, UniversalOrderError::InvariantViolation);
+    }
+    Ok(())
+}
+
+/// Strict mode for batch instructions: remaining_accounts must match the declared count exactly,
+/// so stray accounts from a buggy client are rejected instead of silently ignored
+pub fn require_remaining_accounts(remaining: &[AccountInfo], expected: usize) -> Result<()> {
+    require!(remaining.len() == expected, UniversalOrderError::UnexpectedAccounts);
+    Ok(())
+}
+
+/// Deserialize a FillTicket passed in remaining_accounts and check it belongs to `order_key`
+pub fn load_order_ticket(info: &AccountInfo, order_key: &Pubkey) -> Result<FillTicket> {
+    // CHECK: Program-owned FillTicket (discriminator checked on deserialize)
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let data = info.try_borrow_data()?;
+    let ticket = FillTicket::try_deserialize(&mut &data[..])?;
+
+    // CHECK: Ticket belongs to order
+    require!(ticket.order == *order_key, UniversalOrderError::Unauthorized);
+    Ok(ticket)
+}
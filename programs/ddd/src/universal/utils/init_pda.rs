@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{create_account, transfer, allocate, assign, CreateAccount, Transfer, Allocate, Assign};
+
+/// Create a PDA owned by `owner` with `space` bytes, rent paid by `payer`
+/// Mirrors Anchor's `init`: a pre-funded address (someone sent lamports to it) is topped up,
+/// allocated and assigned instead of failing create_account
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    target: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent = Rent::get()?;
+    let current_lamports = target.lamports();
+    if current_lamports == 0 {
+        let cpi = CpiContext::new(
+            system_program.clone(),
+            CreateAccount { from: payer.clone(), to: target.clone() },
+        );
+        return create_account(cpi.with_signer(&[signer_seeds]), rent.minimum_balance(space), space as u64, owner);
+    }
+
+    let required_lamports = rent.minimum_balance(space).max(1).saturating_sub(current_lamports);
+    if required_lamports > 0 {
+        let cpi = CpiContext::new(
+            system_program.clone(),
+            Transfer { from: payer.clone(), to: target.clone() },
+        );
+        transfer(cpi, required_lamports)?;
+    }
+    let cpi = CpiContext::new(system_program.clone(), Allocate { account_to_allocate: target.clone() });
+    allocate(cpi.with_signer(&[signer_seeds]), space as u64)?;
+    let cpi = CpiContext::new(system_program.clone(), Assign { account_to_assign: target.clone() });
+    assign(cpi.with_signer(&[signer_seeds]), owner)
+}
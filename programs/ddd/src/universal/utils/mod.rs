@@ -1,2 +1,9 @@
 pub mod fees;
+pub mod fee_transfer;
 pub mod auto_close;
+pub mod guard;
+pub mod token_checks;
+pub mod ed25519;
+pub mod validate;
+pub mod init_pda;
+pub mod seeds;
@@ -0,0 +1,31 @@
+//! Canonical PDA seed prefixes - use these instead of inline byte literals
+
+use anchor_lang::prelude::*;
+
+/// Order PDA: [ORDER_SEED, creator, mint, order_id_le]
+#[constant]
+pub const ORDER_SEED: &[u8] = b"universal_order";
+
+/// Vault PDA: [VAULT_SEED, order]
+#[constant]
+pub const VAULT_SEED: &[u8] = b"vault";
+
+/// Ticket PDA: [TICKET_SEED, order, index_le] (index = order.ticket_index at creation)
+#[constant]
+pub const TICKET_SEED: &[u8] = b"ticket";
+
+/// Temporary WSOL account used to unwrap a settlement: [UNWRAP_SEED, ticket] (closed in the same instruction)
+#[constant]
+pub const UNWRAP_SEED: &[u8] = b"unwrap";
+
+/// Settlement receipt PDA: [RECEIPT_SEED, ticket] (outlives the ticket, closed by close_receipt)
+#[constant]
+pub const RECEIPT_SEED: &[u8] = b"receipt";
+
+/// Global Config PDA: [CONFIG_SEED]
+#[constant]
+pub const CONFIG_SEED: &[u8] = b"config";
+
+/// Per-mint protocol fee vault PDA: [PROTOCOL_FEE_VAULT_SEED, mint]
+#[constant]
+pub const PROTOCOL_FEE_VAULT_SEED: &[u8] = b"protocol_fee_vault";
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+};
+use anchor_spl::token::spl_token;
+
+/// True if a Token-2022 token account carries the ImmutableOwner extension
+/// (its owner can't be reassigned via SetAuthority)
+pub fn has_immutable_owner(token_account: &AccountInfo) -> Result<bool> {
+    let data = token_account.try_borrow_data()?;
+    let state = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+    Ok(state.get_extension_types()?.contains(&ExtensionType::ImmutableOwner))
+}
+
+/// Space for a new token account of `mint`: Token-2022 mints may require account extensions
+/// (same rule as Anchor's `init`)
+pub fn token_account_space(mint_info: &AccountInfo) -> Result<usize> {
+    if *mint_info.owner == spl_token_2022::ID {
+        let mint_data = mint_info.try_borrow_data()?;
+        let mint_state = StateWithExtensions::<spl_token_2022::state::Mint>::unpack(&mint_data)?;
+        let required = ExtensionType::get_required_init_account_extensions(&mint_state.get_extension_types()?);
+        Ok(ExtensionType::try_calculate_account_len::<spl_token_2022::state::Account>(&required)?)
+    } else {
+        Ok(anchor_spl::token::TokenAccount::LEN)
+    }
+}
+
+/// Wrapped SOL mint of either token program
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == spl_token::native_mint::ID || *mint == spl_token_2022::native_mint::ID
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::universal::errors::UniversalOrderError;
+
+/// (crypto_guy, fiat_guy) of a ticket: on SELL orders the creator locks crypto and the
+/// acceptor pays fiat, on BUY orders it's the other way around
+pub fn ticket_roles(is_sell_order: bool, creator: Pubkey, acceptor: Pubkey) -> (Pubkey, Pubkey) {
+    if is_sell_order {
+        (creator, acceptor)
+    } else {
+        (acceptor, creator)
+    }
+}
+
+/// Acceptor stored on a new offer's ticket: SELL = the FiatGuy (buyer accepts the seller's offer),
+/// BUY = the locker (seller accepts the buyer's offer). Chosen so that `ticket_roles` later
+/// yields the locker as CryptoGuy
+pub fn offer_acceptor(is_sell_order: bool, fiat_guy: Pubkey, locker: Pubkey) -> Pubkey {
+    if is_sell_order {
+        fiat_guy
+    } else {
+        locker
+    }
+}
+
+/// Same as `ticket_roles`, but `signer` must be one of the two parties, and the two parties must
+/// differ (one wallet holding both roles could settle a ticket alone)
+pub fn validate_roles(
+    is_sell_order: bool,
+    creator: Pubkey,
+    acceptor: Pubkey,
+    signer: Pubkey,
+) -> Result<(Pubkey, Pubkey)> {
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell_order, creator, acceptor);
+    require_keys_neq!(crypto_guy, fiat_guy, UniversalOrderError::Unauthorized);
+    require!(signer == crypto_guy || signer == fiat_guy, UniversalOrderError::Unauthorized);
+    Ok((crypto_guy, fiat_guy))
+}
+
+/// Signature check with delegated signers: `crypto_guy_signer` / `fiat_guy_signer` (when set) sign
+/// instead of the fund wallets. Returns (crypto_guy, fiat_guy, signer acts for the CryptoGuy).
+/// The two parties and their two signing keys must each differ
+pub fn validate_signer(
+    is_sell_order: bool,
+    creator: Pubkey,
+    acceptor: Pubkey,
+    crypto_guy_signer: Option<Pubkey>,
+    fiat_guy_signer: Option<Pubkey>,
+    signer: Pubkey,
+) -> Result<(Pubkey, Pubkey, bool)> {
+    let (crypto_guy, fiat_guy) = ticket_roles(is_sell_order, creator, acceptor);
+    require_keys_neq!(crypto_guy, fiat_guy, UniversalOrderError::Unauthorized);
+    let crypto_key = crypto_guy_signer.unwrap_or(crypto_guy);
+    let fiat_key = fiat_guy_signer.unwrap_or(fiat_guy);
+    require_keys_neq!(crypto_key, fiat_key, UniversalOrderError::Unauthorized);
+    require!(signer == crypto_key || signer == fiat_key, UniversalOrderError::Unauthorized);
+    Ok((crypto_guy, fiat_guy, signer == crypto_key))
+}
+
+/// Token account must hold `mint` (InvalidTokenAccount) and belong to `owner` (Unauthorized)
+pub fn validate_ata(ata: &TokenAccount, mint: &Pubkey, owner: &Pubkey) -> Result<()> {
+    validate_token_fields(&ata.mint, &ata.owner, mint, owner)
+}
+
+fn validate_token_fields(
+    account_mint: &Pubkey,
+    account_owner: &Pubkey,
+    mint: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    require!(account_mint == mint, UniversalOrderError::InvalidTokenAccount);
+    require!(account_owner == owner, UniversalOrderError::Unauthorized);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roles_follow_order_direction() {
+        let creator = Pubkey::new_unique();
+        let acceptor = Pubkey::new_unique();
+        // SELL: creator is the CryptoGuy
+        assert_eq!(ticket_roles(true, creator, acceptor), (creator, acceptor));
+        // BUY: creator is the FiatGuy
+        assert_eq!(ticket_roles(false, creator, acceptor), (acceptor, creator));
+    }
+
+    #[test]
+    fn offer_acceptor_round_trips_to_the_locker() {
+        let creator = Pubkey::new_unique();
+        let fiat_guy = Pubkey::new_unique();
+        let locker = Pubkey::new_unique();
+        // SELL: creator locks, the `fiat_guy` argument pays
+        let acceptor = offer_acceptor(true, fiat_guy, creator);
+        assert_eq!(ticket_roles(true, creator, acceptor), (creator, fiat_guy));
+        // BUY: a third party locks, the creator pays
+        let acceptor = offer_acceptor(false, fiat_guy, locker);
+        assert_eq!(ticket_roles(false, creator, acceptor), (locker, creator));
+    }
+
+    #[test]
+    fn validate_roles_requires_a_party() {
+        let creator = Pubkey::new_unique();
+        let acceptor = Pubkey::new_unique();
+        for is_sell in [true, false] {
+            assert!(validate_roles(is_sell, creator, acceptor, creator).is_ok());
+            assert!(validate_roles(is_sell, creator, acceptor, acceptor).is_ok());
+            assert_eq!(
+                validate_roles(is_sell, creator, acceptor, Pubkey::new_unique()).unwrap_err(),
+                UniversalOrderError::Unauthorized.into()
+            );
+            // Creator can't also be the acceptor
+            assert_eq!(
+                validate_roles(is_sell, creator, creator, creator).unwrap_err(),
+                UniversalOrderError::Unauthorized.into()
+            );
+        }
+    }
+
+    #[test]
+    fn delegated_signer_replaces_the_fund_wallet() {
+        let creator = Pubkey::new_unique();
+        let acceptor = Pubkey::new_unique();
+        let ops = Pubkey::new_unique();
+        // No delegates: same as validate_roles
+        assert_eq!(validate_signer(true, creator, acceptor, None, None, acceptor).unwrap(), (creator, acceptor, false));
+        // SELL FiatGuy (acceptor) delegates to `ops`: ops signs, the wallet itself no longer can
+        assert_eq!(validate_signer(true, creator, acceptor, None, Some(ops), ops).unwrap(), (creator, acceptor, false));
+        assert_eq!(
+            validate_signer(true, creator, acceptor, None, Some(ops), acceptor).unwrap_err(),
+            UniversalOrderError::Unauthorized.into()
+        );
+        // BUY CryptoGuy (acceptor) delegates
+        assert_eq!(validate_signer(false, creator, acceptor, Some(ops), None, ops).unwrap(), (acceptor, creator, true));
+        // One key can't sign for both sides
+        assert_eq!(
+            validate_signer(true, creator, acceptor, Some(ops), Some(ops), ops).unwrap_err(),
+            UniversalOrderError::Unauthorized.into()
+        );
+        assert_eq!(
+            validate_signer(true, creator, acceptor, None, Some(creator), creator).unwrap_err(),
+            UniversalOrderError::Unauthorized.into()
+        );
+    }
+
+    #[test]
+    fn token_fields_check_mint_then_owner() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        assert!(validate_token_fields(&mint, &owner, &mint, &owner).is_ok());
+        assert_eq!(
+            validate_token_fields(&other, &owner, &mint, &owner).unwrap_err(),
+            UniversalOrderError::InvalidTokenAccount.into()
+        );
+        assert_eq!(
+            validate_token_fields(&mint, &other, &mint, &owner).unwrap_err(),
+            UniversalOrderError::Unauthorized.into()
+        );
+    }
+}
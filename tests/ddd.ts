@@ -2,10 +2,16 @@ import * as anchor from "@coral-xyz/anchor";
 import { PublicKey, Keypair, SystemProgram, Transaction } from "@solana/web3.js";
 import {
     TOKEN_PROGRAM_ID,
+    TOKEN_2022_PROGRAM_ID,
     getOrCreateAssociatedTokenAccount,
+    getAssociatedTokenAddressSync,
+    createAccount,
     transfer,
+    createWrappedNativeAccount,
+    NATIVE_MINT,
 } from "@solana/spl-token";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import { Ddd } from "../target/types/ddd";
 import { 
     setupTestEnvironment, 
@@ -14,16 +20,65 @@ import {
     getTokenBalance,
     mintMoreTokens,
     setupUniversalTestToken,
-    TestToken
+    TestToken,
+    createTestToken,
+    setupUserWithTokens,
+    createToken2022WithTransferFee,
+    createUniversalTokenAccount,
+    mintUniversalTokens
 } from "../utils/testTokens";
 import { checkDonorBalance } from "../utils/solFunder";
+import { parseEvents } from "../utils/eventHelpers";
 import { setupAnchorEnvironment, waitForCooldown, TEST_WALLETS } from "../utils/testConfig";
 import {
     acceptOfferAndLock,
+    acceptOfferAndSign,
+    createOrdersBatch,
     signTicket,
     cancelTicket,
+    withdrawTicket,
+    cancelAllUnsigned,
+    adminForceCloseOrder,
+    reassignTicket,
+    splitTicket,
     deriveOrderPdas,
-    deriveTicketPda
+    deriveTicketPda,
+    deriveOrderTicketPdas,
+    deriveConfigPda,
+    initializeConfig,
+    setFillCooldown,
+    setMaxFillsPerDay,
+    setRequireAta,
+    setRequireCreatorSig,
+    setMinFee,
+    setMinFiatAmount,
+    setStrictInvariants,
+    setMintFee,
+    removeMintFee,
+    initializeProtocolFeeVault,
+    sweepFees,
+    adminRecoverExcess,
+    reclaimRent,
+    closeReceipt,
+    deriveReceiptPda,
+    ResolutionReason,
+    setFiatSignGrace,
+    signOrTimeout,
+    extendTicket,
+    setUnwrapToSol,
+    deriveUnwrapPda,
+    setMaxTicketExtension,
+    setMinFillDenominator,
+    setMaxDistinctAcceptors,
+    getFillBudget,
+    getRentEstimate,
+    isOrderNotFoundError,
+    setRequireImmutableFeeOwner,
+    setRentReceiver,
+    getRentReceiver,
+    signTicketWithEd25519,
+    ticketAuthorizationMessage,
+    unsignTicket
 } from "../utils/orderHelpers";
 
 describe.skip("🧪 Universal Orders: New Flow Tests", () => {
@@ -85,6 +140,8 @@ describe.skip("🧪 Universal Orders: New Flow Tests", () => {
             await provider.sendAndConfirm(tx, [provider.wallet.payer as Keypair]);
         }
         console.log("👑 Admin funded:", (await connection.getBalance(adminSigner.publicKey)) / 1_000_000_000, "SOL");
+
+        await initializeConfig(program, adminSigner);
     });
 
     after("cleanup", async function () {
@@ -457,10 +514,11 @@ describe.skip("🧪 Universal Orders: New Flow Tests", () => {
         const ticketData = await program.account.fillTicket.fetch(ticketPda);
 
         await (program.methods as any)
-            .adminResolveUniversalTicket(true)
+            .adminResolveUniversalTicket(true, ResolutionReason.FiatConfirmed)
             .accounts({
                 admin: adminSigner.publicKey,
                 adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
                 order: orderPda,
                 mint: tokenSetup.mint,
                 vault: vaultPda,
@@ -519,10 +577,11 @@ describe.skip("🧪 Universal Orders: New Flow Tests", () => {
         const ticketData = await program.account.fillTicket.fetch(ticketPda);
 
         await (program.methods as any)
-            .adminResolveUniversalTicket(false)
+            .adminResolveUniversalTicket(false, ResolutionReason.Other)
             .accounts({
                 admin: adminSigner.publicKey,
                 adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
                 order: orderPda,
                 mint: tokenSetup.mint,
                 vault: vaultPda,
@@ -558,148 +617,4321 @@ describe.skip("🧪 Universal Orders: New Flow Tests", () => {
     });
 });
 
+describe.only("🧪 Universal Orders: Extended Flow Tests", () => {
+    const { connection, provider, program } = setupAnchorEnvironment();
 
-describe.only("Universal Orders - Token Support", () => {
-    const provider = anchor.AnchorProvider.env();
-    anchor.setProvider(provider);
-    const program = anchor.workspace.Ddd as anchor.Program<Ddd>;
-    
-    // Test users
-    let adminSigner: Keypair;
+    let tokenSetup: TestTokenSetup;
     let cryptoGuy: Keypair;
     let fiatGuy: Keypair;
-    
-    // Will be set up for each test
-    let token: TestToken;
-    let accounts: Map<string, PublicKey>;
-    
-    before(async () => {
-        // Use configured admin wallet that matches on-chain constant
-        // This ensures address checks against ADMIN_PUBKEY pass.
-        const { TEST_WALLETS } = await import("../utils/testConfig");
-        adminSigner = TEST_WALLETS.buyer; // must match constants::ADMIN_PUBKEY
+    let cryptoGuyTokenAccount: PublicKey;
+    let fiatGuyTokenAccount: PublicKey;
+    let adminTokenAccount: PublicKey; 
+    const adminSigner = TEST_WALLETS.buyer; 
+
+    const DECIMALS = 6;
+    const usdc = (n: number) => new anchor.BN(Math.round(n * 1_000_000));
+
+    // Helper to log admin SOL balance
+    const logAdminBalance = async (label: string) => {
+        const balance = await connection.getBalance(adminSigner.publicKey);
+        console.log(`💰 ${label}: ${(balance / 1_000_000_000).toFixed(5)} SOL`);
+        return balance;
+    };
+
+    before("setup token mint and users", async () => {
+        await checkDonorBalance(connection);
 
-        // Fresh test users for each run
         cryptoGuy = Keypair.generate();
-        fiatGuy = Keypair.generate();
+        fiatGuy   = Keypair.generate();
 
-        // Ensure both the tx fee payer (provider wallet) and the admin payer have SOL
-        const targets = [provider.wallet.publicKey, adminSigner.publicKey];
-        for (const pk of targets) {
-            try {
-                const sig = await provider.connection.requestAirdrop(
-                    pk,
-                    10 * anchor.web3.LAMPORTS_PER_SOL
-                );
-                await provider.connection.confirmTransaction(sig);
-            } catch (e) {
-                // Ignore if on a cluster without airdrop; tests may still pass if wallets are funded
+        const env = await setupTestEnvironment(
+            connection,
+            provider.wallet.payer as Keypair,
+            [cryptoGuy, fiatGuy],
+            TEST_TOKEN_AMOUNT_100,
+            DECIMALS,
+        );
+        tokenSetup = env.tokenSetup;
+        cryptoGuyTokenAccount = env.userAccounts[0].tokenAccount;
+        fiatGuyTokenAccount   = env.userAccounts[1].tokenAccount;
+
+        const adminAtaInfo = await getOrCreateAssociatedTokenAccount(
+            connection,
+            provider.wallet.payer as Keypair,
+            tokenSetup.mint,
+            adminSigner.publicKey
+        );
+        adminTokenAccount = adminAtaInfo.address;
+
+        const minLamports = 200_000_000;
+        const current = await connection.getBalance(adminSigner.publicKey);
+        if (current < minLamports) {
+            const tx = new Transaction().add(
+                SystemProgram.transfer({
+                    fromPubkey: provider.wallet.publicKey,
+                    toPubkey: adminSigner.publicKey,
+                    lamports: minLamports - current + 50_000_000,
+                })
+            );
+            await provider.sendAndConfirm(tx, [provider.wallet.payer as Keypair]);
+        }
+        console.log("👑 Admin funded:", (await connection.getBalance(adminSigner.publicKey)) / 1_000_000_000, "SOL");
+
+        await initializeConfig(program, adminSigner);
+    });
+
+    after("cleanup", async function () {
+        this.timeout(30000);
+        try {
+            if (!tokenSetup) return;
+            const payer = provider.wallet.payer as Keypair;
+            const mainAtaInfo = await getOrCreateAssociatedTokenAccount(
+                connection, payer, tokenSetup.mint, payer.publicKey
+            );
+            const mainAta = mainAtaInfo.address;
+
+            for (const user of [
+                { owner: cryptoGuy, ata: cryptoGuyTokenAccount },
+                { owner: fiatGuy, ata: fiatGuyTokenAccount },
+            ]) {
+                try {
+                    const bal = await getTokenBalance(connection, user.ata);
+                    if (bal > 0) {
+                        await transfer(connection, payer, user.ata, mainAta, user.owner, bal);
+                    }
+                } catch (e) {}
             }
+        } catch (e) {}
+    });
+
+    it("❌ SELL: admin wallet as FiatGuy is rejected at creation", async () => {
+        const orderId = new anchor.BN(Date.now() + 83);
+        try {
+            await acceptOfferAndLock(
+                program, orderId, new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+                cryptoGuy.publicKey, adminSigner.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
         }
+        const { orderPda } = deriveOrderPdas(program.programId, cryptoGuy.publicKey, tokenSetup.mint, orderId);
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Admin rejected as SELL FiatGuy, no order created");
+    });
 
-        console.log("✅ Test users initialized");
-        console.log("   Admin:", adminSigner.publicKey.toBase58());
-        console.log("   CryptoGuy:", cryptoGuy.publicKey.toBase58());
-        console.log("   FiatGuy:", fiatGuy.publicKey.toBase58());
+    it("🧽 Settlement and refund close vault, order and ticket", async () => {
+        // Settlement: both signatures → sign_ticket closes all three
+        const settled = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 60), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await waitForCooldown();
+        for (const signer of [fiatGuy, cryptoGuy]) {
+            await signTicket(
+                program, signer, settled.orderPda, tokenSetup.mint, settled.vaultPda, settled.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+        }
+        for (const pda of [settled.vaultPda, settled.orderPda, settled.ticketPda]) {
+            expect(await connection.getAccountInfo(pda)).to.be.null;
+        }
+        console.log("✓ Settlement closed vault + order + ticket");
+
+        // Refund: FiatGuy cancels → cancel_ticket closes all three
+        const refunded = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 61), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await cancelTicket(
+            program, fiatGuy, refunded.orderPda, tokenSetup.mint, refunded.vaultPda, refunded.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        for (const pda of [refunded.vaultPda, refunded.orderPda, refunded.ticketPda]) {
+            expect(await connection.getAccountInfo(pda)).to.be.null;
+        }
+        console.log("✓ Refund closed vault + order + ticket");
     });
-    
-    /**
-     * Helper function to run the full order flow
-     */
-    async function testFullOrderFlow(tokenType: "SPL" | "Token-2022") {
-        const isToken2022 = tokenType === "Token-2022";
-        
-        console.log(`\n🧪 Testing with ${tokenType}...`);
-        
-        // Setup token and accounts
-        const setup = await setupUniversalTestToken(
-            provider.connection,
-            adminSigner,
-            [cryptoGuy, fiatGuy, adminSigner], // Create accounts for all parties
-            isToken2022,
-            6,
-            1_000_000_000 // 1000 tokens
+
+    it("🪦 Signing a ticket of an auto-closed order maps to OrderNotFound", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 66), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
         );
-        
-        token = setup.token;
-        accounts = setup.accounts;
-        
-    console.log(`✅ ${tokenType} setup complete`);
-    console.log("   Mint:", token.mint.toBase58());
-    console.log("   Token Program:", token.tokenProgram.toBase58());
-        
-        // Test parameters
-        const orderId = new anchor.BN(Date.now());
-        const ticketId = new anchor.BN(1);
-        const cryptoAmount = new anchor.BN(100_000_000); // 100 tokens
-        const fiatAmount = new anchor.BN(100_00); // $100 (2 decimals)
-        const isSellOrder = true; // CryptoGuy sells, FiatGuy buys
-        const creator = cryptoGuy.publicKey; // CryptoGuy creates SELL order
-        
-        const cryptoGuyAta = accounts.get(cryptoGuy.publicKey.toBase58())!;
-        const fiatGuyAta = accounts.get(fiatGuy.publicKey.toBase58())!;
-        const adminAta = accounts.get(adminSigner.publicKey.toBase58())!;
-        
-    // Capture initial balances (admin may have been pre-minted in setup)
-    const adminInitialBal = await provider.connection.getTokenAccountBalance(adminAta).catch(() => null);
-    const adminInitialUi = adminInitialBal?.value?.uiAmount ?? 0;
-    console.log("   Admin initial fee account:", adminInitialUi, "tokens");
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
 
-    // Step 1: Accept offer and lock
-        console.log("\n📝 Step 1: Accept offer and lock tokens...");
-        let signature: string, orderPda: PublicKey, vaultPda: PublicKey, ticketPda: PublicKey;
         try {
-            ({ signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
-                program,
-                orderId,
-                ticketId,
-                cryptoAmount,
-                fiatAmount,
-                isSellOrder,
-                creator,
-                fiatGuy.publicKey,
-                cryptoGuy,
-                cryptoGuyAta,
-                token.mint,
-                adminSigner,
-                token.tokenProgram // Pass the correct token program
-            ));
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
         } catch (e: any) {
-            if (typeof e?.getLogs === "function") {
-                console.error("acceptOfferAndLock logs:", await e.getLogs());
+            expect(e.message).to.include("AccountNotInitialized");
+            expect(isOrderNotFoundError(e)).to.be.true;
+            console.log("✓ Stale sign on a closed order reported as OrderNotFound");
+        }
+        expect(isOrderNotFoundError(new Error("Error Code: AccountNotInitialized ... caused by account: ticket."))).to.be.false;
+    });
+
+    it("🏦 Config: rent flows to a configured cold wallet on auto-close", async () => {
+        try {
+            await setRentReceiver(program, adminSigner, PublicKey.default);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidArgument");
+        }
+
+        const coldWallet = Keypair.generate();
+        await setRentReceiver(program, adminSigner, coldWallet.publicKey);
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 69), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            let rent = 0;
+            for (const pda of [orderPda, vaultPda, ticketPda]) {
+                rent += (await connection.getAccountInfo(pda))!.lamports;
             }
-            throw e;
+
+            // The admin wallet is no longer an accepted rent receiver
+            try {
+                await (program.methods as any)
+                    .cancelUniversalTicket()
+                    .accounts({
+                        feePayer: adminSigner.publicKey,
+                        canceller: fiatGuy.publicKey,
+                        adminRentReceiver: adminSigner.publicKey,
+                        config: deriveConfigPda(program.programId),
+                        order: orderPda,
+                        mint: tokenSetup.mint,
+                        vault: vaultPda,
+                        ticket: ticketPda,
+                        cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                        tokenProgram: TOKEN_PROGRAM_ID,
+                    })
+                    .signers([adminSigner, fiatGuy])
+                    .rpc();
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("Unauthorized");
+                console.log("✓ Admin rejected as rent receiver");
+            }
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            expect(await connection.getBalance(coldWallet.publicKey)).to.eq(rent);
+            console.log(`✓ Cold wallet received ${rent} lamports of rent`);
+        } finally {
+            await setRentReceiver(program, adminSigner, adminSigner.publicKey);
         }
-        
-        console.log("✅ Tokens locked");
-        console.log("   Signature:", signature);
-        console.log("   Order PDA:", orderPda.toBase58());
-        console.log("   Vault PDA:", vaultPda.toBase58());
-        console.log("   Ticket PDA:", ticketPda.toBase58());
-        
-        // Step 2: FiatGuy signs (first signature)
-        console.log("\n📝 Step 2: FiatGuy signs ticket...");
-        let sig1: string;
+    });
+
+    it("🔚 Settlement close-out closes vault, order and ticket with exact lamports to the receiver", async () => {
+        const coldWallet = Keypair.generate();
+        await setRentReceiver(program, adminSigner, coldWallet.publicKey);
         try {
-            sig1 = await signTicket(
-                program,
-                fiatGuy,
-                orderPda,
-                token.mint,
-                vaultPda,
-                ticketPda,
-                fiatGuyAta,
-                adminAta,
-                adminSigner,
-                token.tokenProgram
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 139), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
             );
-        } catch (e: any) {
-            if (typeof e?.getLogs === "function") {
-                console.error("signTicket (fiat) logs:", await e.getLogs());
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            let lamports = 0;
+            for (const pda of [orderPda, vaultPda, ticketPda]) {
+                lamports += (await connection.getAccountInfo(pda))!.lamports;
             }
-            throw e;
+
+            await waitForCooldown();
+            const sig = await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            for (const pda of [orderPda, vaultPda, ticketPda]) {
+                expect(await connection.getAccountInfo(pda)).to.be.null;
+            }
+            expect(await connection.getBalance(coldWallet.publicKey)).to.eq(lamports);
+            const closed = (await parseEvents(program, connection, sig)).find(e => e.name === "orderClosed")!;
+            expect(closed.data.rentReturnedTo.toBase58()).to.eq(coldWallet.publicKey.toBase58());
+            console.log(`✓ All three accounts closed, ${lamports} lamports returned`);
+        } finally {
+            await setRentReceiver(program, adminSigner, adminSigner.publicKey);
         }
-        console.log("✅ FiatGuy signed:", sig1);
+    });
+
+    it("🧮 rent_estimate matches the rent returned on close-out", async () => {
+        const coldWallet = Keypair.generate();
+        await setRentReceiver(program, adminSigner, coldWallet.publicKey);
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 136), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            const estimate = await getRentEstimate(program, orderPda, vaultPda);
+            expect(estimate.total.toNumber()).to.eq(
+                estimate.orderRent.toNumber() + estimate.vaultRent.toNumber() + estimate.ticketRent.toNumber()
+            );
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            // A fresh receiver's balance is exactly the refund
+            expect(await connection.getBalance(coldWallet.publicKey)).to.eq(estimate.total.toNumber());
+            console.log(`✓ Estimated ${estimate.total.toNumber()} lamports, received the same`);
+        } finally {
+            await setRentReceiver(program, adminSigner, adminSigner.publicKey);
+        }
+    });
+
+    it("🖋️ sign_ticket_with_ed25519 accepts only FiatGuy's signature over the ticket", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 70), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const message = await ticketAuthorizationMessage(program, ticketPda);
+
+        const rejects = async (authorizer: Keypair, msg: Buffer, error: string) => {
+            try {
+                await signTicketWithEd25519(program, authorizer, orderPda, ticketPda, adminSigner, msg);
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include(error);
+            }
+        };
+        // Wrong signer, wrong ticket, missing created_at, missing last_sign_ts
+        await rejects(cryptoGuy, message, "Unauthorized");
+        await rejects(fiatGuy, Buffer.concat([Keypair.generate().publicKey.toBuffer(), message.subarray(32)]), "InvalidEd25519Instruction");
+        await rejects(fiatGuy, message.subarray(0, 32), "InvalidEd25519Instruction");
+        await rejects(fiatGuy, message.subarray(0, 40), "InvalidEd25519Instruction");
+        expect((await program.account.fillTicket.fetch(ticketPda)).fiatGuySigned).to.be.false;
+        console.log("✓ Invalid authorizations leave the ticket unsigned");
+
+        await signTicketWithEd25519(program, fiatGuy, orderPda, ticketPda, adminSigner, message);
+        expect((await program.account.fillTicket.fetch(ticketPda)).fiatGuySigned).to.be.true;
+        console.log("✓ FiatGuy's off-chain signature recorded");
+
+        // After FiatGuy unsigns, the same authorization can't be replayed to re-sign
+        await waitForCooldown();
+        await unsignTicket(program, fiatGuy, orderPda, ticketPda);
+        await waitForCooldown();
+        await rejects(fiatGuy, message, "InvalidEd25519Instruction");
+        expect((await program.account.fillTicket.fetch(ticketPda)).fiatGuySigned).to.be.false;
+        console.log("✓ Authorization replay after unsign rejected");
+
+        // A fresh authorization (over the new last_sign_ts) works
+        await signTicketWithEd25519(program, fiatGuy, orderPda, ticketPda, adminSigner);
+        expect((await program.account.fillTicket.fetch(ticketPda)).fiatGuySigned).to.be.true;
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("❌ SELL: second signature within cooldown is rejected", async () => {
+        const orderId = new anchor.BN(Date.now() + 3);
+        const ticketId = new anchor.BN(1);
+        const cryptoAmount = usdc(2);
+
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, orderId, ticketId, cryptoAmount, new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await waitForCooldown();
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        try {
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("ActionTooFrequent");
+            console.log("✓ Signature inside cooldown blocked");
+        }
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        console.log("✓ Signature after cooldown settled");
+    });
+
+    it("⚙️ Config: zero cooldown allows back-to-back signatures", async () => {
+        const configPda = deriveConfigPda(program.programId);
+        const original = await program.account.config.fetch(configPda);
+
+        await setFillCooldown(program, adminSigner, new anchor.BN(0));
+        await setMaxFillsPerDay(program, adminSigner, original.maxFillsPerDay + 10);
+        const updated = await program.account.config.fetch(configPda);
+        expect(updated.fillCooldownSecs.toNumber()).to.eq(0);
+        expect(updated.maxFillsPerDay).to.eq(original.maxFillsPerDay + 10);
+
+        try {
+            const orderId = new anchor.BN(Date.now() + 4);
+            const ticketId = new anchor.BN(1);
+            const cryptoAmount = usdc(2);
+
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, orderId, ticketId, cryptoAmount, new anchor.BN(200), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            console.log("✓ Back-to-back signatures settled with zero cooldown");
+        } finally {
+            await setFillCooldown(program, adminSigner, original.fillCooldownSecs);
+            await setMaxFillsPerDay(program, adminSigner, original.maxFillsPerDay);
+        }
+    });
+
+    it("❌ Ticket seeded with a different order is rejected", async () => {
+        const orderId = new anchor.BN(Date.now() + 5);
+        const otherOrderId = new anchor.BN(Date.now() + 6);
+        const ticketId = new anchor.BN(1);
+        const cryptoAmount = usdc(1);
+
+        const { orderPda, vaultPda } = deriveOrderPdas(
+            program.programId, cryptoGuy.publicKey, tokenSetup.mint, orderId
+        );
+        const { orderPda: otherOrderPda } = deriveOrderPdas(
+            program.programId, cryptoGuy.publicKey, tokenSetup.mint, otherOrderId
+        );
+        // Right index (0), wrong order: only the order key in the seeds differs. The ticket's
+        // seeds constraint is the guard - no handler-level check could ever see this ticket
+        const wrongTicketPda = deriveTicketPda(program.programId, otherOrderPda, new anchor.BN(0));
+
+        try {
+            await (program.methods as any)
+                .acceptOfferAndLock(
+                    orderId, ticketId, cryptoAmount, new anchor.BN(100), true, cryptoGuy.publicKey,
+                    {
+                        fiatGuy: fiatGuy.publicKey,
+                        cryptoFirst: false,
+                        vaultAuthority: null,
+                        payoutDestination: null,
+                        reference: Array(32).fill(0),
+                        feeDestination: null,
+                        feeOnTop: false,
+                        cryptoGuySigner: null,
+                        fiatGuySigner: null,
+                        allowedTakers: [],
+                    }
+                )
+                .accounts({
+                    feePayer: adminSigner.publicKey,
+                    locker: cryptoGuy.publicKey,
+                    creatorSigner: null,
+                    config: deriveConfigPda(program.programId),
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    ticket: wrongTicketPda,
+                    lockerTokenAccount: cryptoGuyTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([adminSigner, cryptoGuy])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("ConstraintSeeds");
+            expect(e.message).to.include("ticket");
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            console.log("✓ Mismatched ticket PDA rejected");
+        }
+    });
+
+    it("📡 OfferAccepted reports locker and acceptor for SELL and BUY", async () => {
+        const cryptoAmount = usdc(1);
+
+        const sell = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 7), new anchor.BN(1), cryptoAmount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const sellEvent = (await parseEvents(program, connection, sell.signature))
+            .find(e => e.name === "offerAccepted")!;
+        expect(sellEvent.data.locker.toBase58()).to.eq(cryptoGuy.publicKey.toBase58());
+        expect(sellEvent.data.acceptor.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+
+        const buy = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 8), new anchor.BN(1), cryptoAmount, new anchor.BN(100), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const buyEvent = (await parseEvents(program, connection, buy.signature))
+            .find(e => e.name === "offerAccepted")!;
+        expect(buyEvent.data.locker.toBase58()).to.eq(cryptoGuy.publicKey.toBase58());
+        expect(buyEvent.data.acceptor.toBase58()).to.eq(cryptoGuy.publicKey.toBase58());
+        console.log("✓ locker/acceptor reported for both directions");
+
+        await waitForCooldown();
+        await cancelTicket(
+            program, fiatGuy, sell.orderPda, tokenSetup.mint, sell.vaultPda, sell.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        await cancelTicket(
+            program, fiatGuy, buy.orderPda, tokenSetup.mint, buy.vaultPda, buy.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("🔒 Order processing flag is cleared after each instruction", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 9), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        expect((await program.account.universalOrder.fetch(orderPda)).locked).to.eq(false);
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect((await program.account.universalOrder.fetch(orderPda)).locked).to.eq(false);
+        console.log("✓ locked=false after first signature");
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+    });
+
+    it("❌ Admin key cannot sign as a trading party", async () => {
+        // Offers never make the admin a party, so any admin signature is an outsider's
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 10), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        try {
+            await signTicket(
+                program, adminSigner, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                adminTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Admin signer rejected");
+        }
+
+        await (program.methods as any)
+            .adminResolveUniversalTicket(false, ResolutionReason.Other)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: orderPda,
+                mint: tokenSetup.mint,
+                vault: vaultPda,
+                ticket: ticketPda,
+                fiatGuyTokenAccount: fiatGuyTokenAccount,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: adminTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+    });
+
+    it("❌ BUY: admin key cannot be the accepting CryptoGuy", async () => {
+        // BUY acceptor = the locker; the admin would be a trading party on a ticket it can never sign
+        try {
+            await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 129), new anchor.BN(1), new anchor.BN(1), new anchor.BN(100), false,
+                fiatGuy.publicKey, adminSigner.publicKey, adminSigner,
+                adminTokenAccount, tokenSetup.mint, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Admin rejected as BUY acceptor");
+        }
+    });
+
+    it("🧮 preview_settlement returns the on-chain fee split", async () => {
+        const amount = usdc(10);
+        const preview = await (program.methods as any)
+            .previewSettlement(amount, tokenSetup.mint)
+            .accounts({ config: deriveConfigPda(program.programId) })
+            .view();
+
+        const fee = Math.floor(amount.toNumber() * 20 / 10_000);
+        expect(preview.feeAmount.toNumber()).to.eq(fee);
+        expect(preview.netAmount.toNumber()).to.eq(amount.toNumber() - fee);
+        console.log("✓ Preview:", preview.feeAmount.toString(), "/", preview.netAmount.toString());
+    });
+
+
+    it("🩺 health_check reports admin, fee bps and version", async () => {
+        const health = await (program.methods as any)
+            .healthCheck()
+            .accounts({ config: deriveConfigPda(program.programId) })
+            .view();
+
+        expect(health.admin.toBase58()).to.eq(adminSigner.publicKey.toBase58());
+        expect(health.feeBasisPoints.toNumber()).to.eq(20);
+        expect(health.version).to.match(/^\d+\.\d+\.\d+/);
+        console.log("✓ Health:", health.admin.toBase58(), health.feeBasisPoints.toString(), health.version);
+    });
+    it("🧾 get_order_with_tickets returns ticket metadata and rejects foreign tickets", async () => {
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 22), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 23), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, a.orderPda, tokenSetup.mint, a.vaultPda, a.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        const summary = await (program.methods as any)
+            .getOrderWithTickets(1)
+            .accounts({ order: a.orderPda })
+            .remainingAccounts([{ pubkey: a.ticketPda, isSigner: false, isWritable: false }])
+            .view();
+        const ticket = await program.account.fillTicket.fetch(a.ticketPda);
+        expect(summary.order.toBase58()).to.eq(a.orderPda.toBase58());
+        expect(summary.tickets).to.have.length(1);
+        expect(summary.tickets[0].amount.toNumber()).to.eq(usdc(1).toNumber());
+        expect(summary.tickets[0].createdAt.toNumber()).to.eq(ticket.createdAt.toNumber());
+        expect(summary.tickets[0].fiatGuySigned).to.eq(true);
+        expect(summary.tickets[0].cryptoGuySigned).to.eq(false);
+        console.log("✓ Ticket metadata returned");
+
+        try {
+            await (program.methods as any)
+                .getOrderWithTickets(1)
+                .accounts({ order: a.orderPda })
+                .remainingAccounts([{ pubkey: b.ticketPda, isSigner: false, isWritable: false }])
+                .view();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.not.eq("Should fail");
+            console.log("✓ Ticket of another order rejected");
+        }
+
+        // Strict mode: an extra account beyond the declared count is rejected
+        try {
+            await (program.methods as any)
+                .getOrderWithTickets(1)
+                .accounts({ order: a.orderPda })
+                .remainingAccounts([
+                    { pubkey: a.ticketPda, isSigner: false, isWritable: false },
+                    { pubkey: a.vaultPda, isSigner: false, isWritable: false },
+                ])
+                .view();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("UnexpectedAccounts");
+            console.log("✓ Stray remaining account rejected");
+        }
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, a.orderPda, tokenSetup.mint, a.vaultPda, a.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await cancelTicket(
+            program, fiatGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("🌱 IDL seed constants derive the on-chain PDAs", async () => {
+        const seed = (name: string) => {
+            const c = (program.idl as any).constants.find((k: any) => k.name === name);
+            return Buffer.from(JSON.parse(c.value));
+        };
+        const orderId = new anchor.BN(Date.now() + 39);
+        const ticketId = new anchor.BN(1);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, orderId, ticketId, usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        const [derivedOrder] = PublicKey.findProgramAddressSync(
+            [seed("orderSeed"), cryptoGuy.publicKey.toBuffer(), tokenSetup.mint.toBuffer(), orderId.toArrayLike(Buffer, "le", 8)],
+            program.programId
+        );
+        const [derivedVault] = PublicKey.findProgramAddressSync(
+            [seed("vaultSeed"), derivedOrder.toBuffer()], program.programId
+        );
+        const [derivedTicket] = PublicKey.findProgramAddressSync(
+            [seed("ticketSeed"), derivedOrder.toBuffer(), ticketId.toArrayLike(Buffer, "le", 8)], program.programId
+        );
+        expect(derivedOrder.toBase58()).to.eq(orderPda.toBase58());
+        expect(derivedVault.toBase58()).to.eq(vaultPda.toBase58());
+        expect(derivedTicket.toBase58()).to.eq(ticketPda.toBase58());
+        const order = await program.account.universalOrder.fetch(derivedOrder);
+        expect(order.orderId.toString()).to.eq(orderId.toString());
+        console.log("✓ PDAs derived from IDL seed constants match on-chain accounts");
+
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+
+    it("🏷️ OfferAccepted and TicketSettled carry PROGRAM_VERSION", async () => {
+        const idlVersion = Number((program.idl as any).constants.find((k: any) => k.name === "PROGRAM_VERSION").value);
+        expect(idlVersion).to.be.greaterThan(0);
+
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 56), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const accepted = (await parseEvents(program, connection, signature))
+            .find(e => e.name === "offerAccepted")!;
+        expect(accepted.data.version).to.eq(idlVersion);
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        const sig = await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const settled = (await parseEvents(program, connection, sig))
+            .find(e => e.name === "ticketSettled")!;
+        expect(settled.data.version).to.eq(idlVersion);
+        console.log("✓ Events stamped with version", idlVersion);
+    });
+    it("🔢 Lifecycle events carry a program-wide increasing seq", async () => {
+        const configPda = deriveConfigPda(program.programId);
+        const seqBefore = (await program.account.config.fetch(configPda)).eventSeq.toNumber();
+
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 122), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const accepted = (await parseEvents(program, connection, signature)).find(e => e.name === "offerAccepted")!;
+        expect(accepted.data.seq.toNumber()).to.eq(seqBefore + 1);
+
+        const signSig = await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const signed = (await parseEvents(program, connection, signSig)).find(e => e.name === "ticketSigned")!;
+        expect(signed.data.seq.toNumber()).to.eq(seqBefore + 2);
+
+        // Two events in one instruction still get distinct, ordered numbers
+        await waitForCooldown();
+        const settleSig = await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const events = await parseEvents(program, connection, settleSig);
+        const settleSigned = events.find(e => e.name === "ticketSigned")!;
+        const settled = events.find(e => e.name === "ticketSettled")!;
+        expect(settleSigned.data.seq.toNumber()).to.eq(seqBefore + 3);
+        expect(settled.data.seq.toNumber()).to.eq(seqBefore + 4);
+        expect((await program.account.config.fetch(configPda)).eventSeq.toNumber()).to.eq(seqBefore + 4);
+        console.log("✓ seq", seqBefore + 1, "→", seqBefore + 4);
+    });
+
+    it("⚙️ Config: min_fee floors the fee on small settlements", async () => {
+        const minFee = new anchor.BN(1_000);
+        await setMinFee(program, adminSigner, minFee);
+        try {
+            const small = await (program.methods as any)
+                .previewSettlement(new anchor.BN(10_000), tokenSetup.mint)
+                .accounts({ config: deriveConfigPda(program.programId) })
+                .view();
+            expect(small.feeAmount.toNumber()).to.eq(1_000);   // 20 bps = 20 < floor
+            expect(small.netAmount.toNumber()).to.eq(9_000);
+
+            const large = await (program.methods as any)
+                .previewSettlement(usdc(10), tokenSetup.mint)
+                .accounts({ config: deriveConfigPda(program.programId) })
+                .view();
+            expect(large.feeAmount.toNumber()).to.eq(20_000);  // 20 bps > floor
+
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 41), new anchor.BN(1), new anchor.BN(10_000), new anchor.BN(1), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            const beforeAdmin = await getTokenBalance(connection, adminTokenAccount);
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            expect(await getTokenBalance(connection, adminTokenAccount) - beforeAdmin).to.eq(1_000);
+            console.log("✓ Fee floor applied on-chain");
+        } finally {
+            await setMinFee(program, adminSigner, new anchor.BN(0));
+        }
+    });
+
+    it("⚙️ Config: min_fee covering the whole amount skips the zero net transfer", async () => {
+        await setMinFee(program, adminSigner, new anchor.BN(10_000));
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 71), new anchor.BN(1), new anchor.BN(5_000), new anchor.BN(1), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+            const beforeAdmin = await getTokenBalance(connection, adminTokenAccount);
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            expect(await getTokenBalance(connection, fiatGuyTokenAccount) - beforeFiat).to.eq(0);
+            expect(await getTokenBalance(connection, adminTokenAccount) - beforeAdmin).to.eq(5_000);
+            console.log("✓ Zero net amount settled without a transfer to FiatGuy");
+        } finally {
+            await setMinFee(program, adminSigner, new anchor.BN(0));
+        }
+    });
+
+    it("⚙️ Config: admin resolve skips the zero net transfer when min_fee covers the amount", async () => {
+        await setMinFee(program, adminSigner, new anchor.BN(10_000));
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 146), new anchor.BN(1), new anchor.BN(5_000), new anchor.BN(1), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+            const beforeAdmin = await getTokenBalance(connection, adminTokenAccount);
+            await (program.methods as any)
+                .adminResolveUniversalTicket(true, ResolutionReason.FiatConfirmed)
+                .accounts({
+                    admin: adminSigner.publicKey,
+                    adminRentReceiver: await getRentReceiver(program),
+                    config: deriveConfigPda(program.programId),
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    ticket: ticketPda,
+                    fiatGuyTokenAccount: fiatGuyTokenAccount,
+                    cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                    adminFeeAccount: adminTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([adminSigner])
+                .rpc();
+            expect(await getTokenBalance(connection, fiatGuyTokenAccount) - beforeFiat).to.eq(0);
+            expect(await getTokenBalance(connection, adminTokenAccount) - beforeAdmin).to.eq(5_000);
+            console.log("✓ Admin resolve settled a zero net amount without a transfer to FiatGuy");
+        } finally {
+            await setMinFee(program, adminSigner, new anchor.BN(0));
+        }
+    });
+
+    it("⚙️ Config: per-mint fee override applies only to its mint", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        const otherToken = await createTestToken(connection, payer, DECIMALS);
+        const otherCrypto = await setupUserWithTokens(connection, payer, cryptoGuy, otherToken, TEST_TOKEN_AMOUNT_100);
+        const otherFiat = await setupUserWithTokens(connection, payer, fiatGuy, otherToken, 0);
+        const otherAdmin = (await getOrCreateAssociatedTokenAccount(
+            connection, payer, otherToken.mint, adminSigner.publicKey
+        )).address;
+
+        // Protocol ceilings: 5% per-mint bps, 1 whole token of per-mint floor, 1_000_000 base units of default min_fee
+        for (const reject of [
+            () => setMintFee(program, adminSigner, otherToken.mint, 501),
+            () => setMintFee(program, adminSigner, otherToken.mint, 100, new anchor.BN(10 ** DECIMALS + 1)),
+            () => setMinFee(program, adminSigner, new anchor.BN(1_000_001)),
+        ]) {
+            try {
+                await reject();
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("InvalidAmount");
+            }
+        }
+
+        await setMintFee(program, adminSigner, otherToken.mint, 100, new anchor.BN(50_000)); // 1%, floor 0.05
+        try {
+            const settle = async (mint: PublicKey, cryptoAta: PublicKey, fiatAta: PublicKey, adminAta: PublicKey, n: number) => {
+                const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                    program, new anchor.BN(Date.now() + n), new anchor.BN(1), usdc(10), new anchor.BN(100), true,
+                    cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                    cryptoAta, mint, adminSigner
+                );
+                await signTicket(program, fiatGuy, orderPda, mint, vaultPda, ticketPda, fiatAta, adminAta, adminSigner);
+                await waitForCooldown();
+                const before = await getTokenBalance(connection, adminAta);
+                await signTicket(program, cryptoGuy, orderPda, mint, vaultPda, ticketPda, fiatAta, adminAta, adminSigner);
+                return (await getTokenBalance(connection, adminAta)) - before;
+            };
+
+            const defaultFee = await settle(tokenSetup.mint, cryptoGuyTokenAccount, fiatGuyTokenAccount, adminTokenAccount, 42);
+            const overrideFee = await settle(otherToken.mint, otherCrypto.tokenAccount, otherFiat.tokenAccount, otherAdmin, 43);
+
+            expect(defaultFee).to.eq(usdc(10).toNumber() * 20 / 10_000);
+            expect(overrideFee).to.eq(usdc(10).toNumber() * 100 / 10_000);
+
+            const preview = await (program.methods as any)
+                .previewSettlement(usdc(10), otherToken.mint)
+                .accounts({ config: deriveConfigPda(program.programId) })
+                .view();
+            expect(preview.feeAmount.toNumber()).to.eq(overrideFee);
+
+            // The floor is per mint: 1% of 1 token = 10_000 < 50_000 on the override, plain 20 bps elsewhere
+            const smallOverride = await (program.methods as any)
+                .previewSettlement(usdc(1), otherToken.mint)
+                .accounts({ config: deriveConfigPda(program.programId) })
+                .view();
+            expect(smallOverride.feeAmount.toNumber()).to.eq(50_000);
+            const smallDefault = await (program.methods as any)
+                .previewSettlement(usdc(1), tokenSetup.mint)
+                .accounts({ config: deriveConfigPda(program.programId) })
+                .view();
+            expect(smallDefault.feeAmount.toNumber()).to.eq(2_000);
+            console.log("✓ Per-mint fees:", defaultFee, "/", overrideFee);
+        } finally {
+            await removeMintFee(program, adminSigner, otherToken.mint);
+        }
+    });
+
+    it("⚙️ Config: min_fiat_amount rejects offers below the notional floor", async () => {
+        const floor = new anchor.BN(500);
+        await setMinFiatAmount(program, adminSigner, floor);
+        try {
+            const config = await program.account.config.fetch(deriveConfigPda(program.programId));
+            expect(config.minFiatAmount.toNumber()).to.eq(500);
+
+            try {
+                await acceptOfferAndLock(
+                    program, new anchor.BN(Date.now() + 124), new anchor.BN(1), usdc(1), floor.subn(1), true,
+                    cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("NotionalTooSmall");
+                console.log("✓ fiat_amount one below the floor rejected");
+            }
+
+            // Exactly at the floor is accepted
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 125), new anchor.BN(1), usdc(1), floor, true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            expect((await program.account.universalOrder.fetch(orderPda)).fiatAmount.toNumber()).to.eq(500);
+            console.log("✓ fiat_amount at the floor accepted");
+
+            // Batches check every rung
+            try {
+                await createOrdersBatch(
+                    program,
+                    [
+                        { orderId: new anchor.BN(Date.now() + 126), cryptoAmount: usdc(1), fiatAmount: floor },
+                        { orderId: new anchor.BN(Date.now() + 127), cryptoAmount: usdc(1), fiatAmount: floor.subn(1) },
+                    ],
+                    new anchor.BN(1), true, cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("NotionalTooSmall");
+                console.log("✓ Batch with one rung below the floor rejected");
+            }
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        } finally {
+            await setMinFiatAmount(program, adminSigner, new anchor.BN(0));
+        }
+    });
+
+    it("⚙️ Config: strict_invariants leaves consistent orders untouched", async () => {
+        // Corrupted counters can't be produced through instructions; the warning text itself is
+        // covered by the state.rs unit test. Here: no false positives across lock, split, sign, resolve
+        await setStrictInvariants(program, adminSigner, true);
+        try {
+            expect((await program.account.config.fetch(deriveConfigPda(program.programId))).strictInvariants).to.eq(true);
+
+            const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 130), new anchor.BN(1), usdc(4), new anchor.BN(400), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            const lockLogs = (await connection.getTransaction(signature, {
+                commitment: "confirmed", maxSupportedTransactionVersion: 0,
+            }))!.meta!.logMessages!;
+            expect(lockLogs.some(l => l.includes("WARNING"))).to.eq(false);
+
+            const { newTicketPda } = await splitTicket(
+                program, orderPda, ticketPda, usdc(2), new anchor.BN(2), adminSigner
+            );
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await (program.methods as any)
+                .adminResolveUniversalTicket(false, ResolutionReason.Other)
+                .accounts({
+                    admin: adminSigner.publicKey,
+                    adminRentReceiver: adminSigner.publicKey,
+                    config: deriveConfigPda(program.programId),
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    ticket: newTicketPda,
+                    fiatGuyTokenAccount: fiatGuyTokenAccount,
+                    cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                    adminFeeAccount: adminTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([adminSigner])
+                .rpc();
+            console.log("✓ Full lifecycle passes with strict invariants on");
+        } finally {
+            await setStrictInvariants(program, adminSigner, false);
+        }
+    });
+
+    it("🛡️ Order address cannot be squatted on behalf of a BUY creator", async () => {
+        const orderId = new anchor.BN(Date.now() + 44);
+        const griefer = Keypair.generate();
+
+        // Non-admin fee payer can never init an order PDA
+        try {
+            await acceptOfferAndLock(
+                program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, griefer
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Non-admin fee payer rejected");
+        }
+
+        await setRequireCreatorSig(program, adminSigner, true);
+        try {
+            // Locker + admin without the creator cannot claim the creator's order address
+            try {
+                await acceptOfferAndLock(
+                    program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                    fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("CreatorSignatureRequired");
+                console.log("✓ BUY order without creator signature rejected");
+            }
+
+            // Wrong co-signer is rejected too
+            try {
+                await acceptOfferAndLock(
+                    program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                    fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                    TOKEN_PROGRAM_ID, false, griefer
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("CreatorSignatureRequired");
+            }
+
+            // The address is still free for the real creator
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, fiatGuy
+            );
+            expect((await program.account.universalOrder.fetch(orderPda)).creator.toBase58())
+                .to.eq(fiatGuy.publicKey.toBase58());
+            console.log("✓ Creator-signed BUY order created");
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        } finally {
+            await setRequireCreatorSig(program, adminSigner, false);
+        }
+    });
+
+    it("🤝 Default trust model: admin + locker open a BUY order without the creator's signature", async () => {
+        const orderId = new anchor.BN(Date.now() + 135);
+        const griefer = Keypair.generate();
+        expect((await program.account.config.fetch(deriveConfigPda(program.programId))).requireCreatorSig).to.be.false;
+
+        // A co-signer that is passed must still be the creator
+        try {
+            await acceptOfferAndLock(
+                program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, griefer
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("CreatorSignatureRequired");
+        }
+
+        // Admin-mediated: the FiatGuy creator never signs, the order still opens in their name
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        expect((await program.account.universalOrder.fetch(orderPda)).creator.toBase58())
+            .to.eq(fiatGuy.publicKey.toBase58());
+        console.log("✓ BUY order opened on the creator's behalf (require_creator_sig off)");
+
+        // The creator can't be made to pay: nothing settles without their signature, and they can back out
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("❌ creator_signer: missing on an enforced BUY order, spurious on a SELL order", async () => {
+        await setRequireCreatorSig(program, adminSigner, true);
+        try {
+            try {
+                await acceptOfferAndLock(
+                    program, new anchor.BN(Date.now() + 137), new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                    fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("CreatorSignatureRequired");
+                console.log("✓ BUY without the creator's co-signature rejected");
+            }
+        } finally {
+            await setRequireCreatorSig(program, adminSigner, false);
+        }
+
+        // SELL creator is the locker; passing it again as creator_signer is a client mistake
+        try {
+            await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 138), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, cryptoGuy
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("UnexpectedCreatorSigner");
+            console.log("✓ SELL with a spurious creator_signer rejected");
+        }
+    });
+
+    it("🔐 Private order: only whitelisted takers can fill", async () => {
+        const outsider = Keypair.generate();
+        const privateOrder = (orderId: anchor.BN, taker: PublicKey, allowed: PublicKey[]) => acceptOfferAndLock(
+            program, orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, taker, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, null, null, false, null, null, allowed
+        );
+
+        // Not on the list -> no ticket
+        try {
+            await privateOrder(new anchor.BN(Date.now() + 140), outsider.publicKey, [fiatGuy.publicKey]);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Non-whitelisted taker rejected");
+        }
+
+        const { orderPda, vaultPda, ticketPda } = await privateOrder(
+            new anchor.BN(Date.now() + 141), fiatGuy.publicKey, [outsider.publicKey, fiatGuy.publicKey]
+        );
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.takersWhitelisted).to.be.true;
+
+        // Nor can the admin hand the ticket to someone off the list
+        try {
+            await reassignTicket(program, orderPda, ticketPda, Keypair.generate().publicKey, adminSigner);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+
+        // Whitelisted taker fills as usual
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Whitelisted taker filled the private order");
+    });
+
+    it("🏦 vault_authority defaults to the order PDA; custom authorities are rejected", async () => {
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 45), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const orderA = await program.account.universalOrder.fetch(a.orderPda);
+        expect(orderA.vaultAuthority.toBase58()).to.eq(a.orderPda.toBase58());
+
+        // Explicitly passing the order PDA is the same as the default
+        const explicitId = new anchor.BN(Date.now() + 46);
+        const { orderPda: explicitOrder } = deriveOrderPdas(program.programId, cryptoGuy.publicKey, tokenSetup.mint, explicitId);
+        const b = await acceptOfferAndLock(
+            program, explicitId, new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, explicitOrder
+        );
+        expect((await program.account.universalOrder.fetch(b.orderPda)).vaultAuthority.toBase58())
+            .to.eq(explicitOrder.toBase58());
+
+        try {
+            await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 47), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, null, Keypair.generate().publicKey
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidVaultAuthority");
+            console.log("✓ Custom vault authority rejected");
+        }
+
+        // Default-authority orders settle and refund as before
+        await signTicket(
+            program, fiatGuy, a.orderPda, tokenSetup.mint, a.vaultPda, a.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, a.orderPda, tokenSetup.mint, a.vaultPda, a.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await cancelTicket(
+            program, fiatGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(a.orderPda)).to.be.null;
+        expect(await connection.getAccountInfo(b.orderPda)).to.be.null;
+        console.log("✓ Default vault authority settles and refunds");
+    });
+
+    it("🧩 sign_ticket rejects a token program that doesn't own the mint", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 48), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        // SPL Token mint + Token-2022 program
+        try {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidTokenProgram");
+            console.log("✓ Mismatched token program rejected");
+        }
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+
+        // Also rejected on the settling signature
+        try {
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidTokenProgram");
+        }
+
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("🔑 order stores the canonical vault bump", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 51), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const [canonicalVault, canonicalBump] = PublicKey.findProgramAddressSync(
+            [Buffer.from("vault"), orderPda.toBuffer()],
+            program.programId
+        );
+        expect(canonicalVault.toBase58()).to.eq(vaultPda.toBase58());
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.vaultBump).to.eq(canonicalBump);
+        console.log("✓ vault_bump:", order.vaultBump);
+
+        // Stored bump validates the vault on later instructions
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("📬 payout_destination: default FiatGuy and delegated custodial wallet", async () => {
+        const settle = async (orderPda: PublicKey, vaultPda: PublicKey, ticketPda: PublicKey, payoutAta: PublicKey) => {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                payoutAta, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            const sig = await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                payoutAta, adminTokenAccount, adminSigner
+            );
+            return (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+        };
+
+        // Default: payout goes to the FiatGuy
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 53), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const defaultSettled = await settle(a.orderPda, a.vaultPda, a.ticketPda, fiatGuyTokenAccount);
+        expect(defaultSettled.data.payoutDestination.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+
+        // Delegated: payout must go to the custodial wallet's token account
+        const custodian = Keypair.generate();
+        const custodianAta = (await getOrCreateAssociatedTokenAccount(
+            connection, provider.wallet.payer as Keypair, tokenSetup.mint, custodian.publicKey
+        )).address;
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 54), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, custodian.publicKey
+        );
+        await signTicket(
+            program, fiatGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        try {
+            await signTicket(
+                program, cryptoGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ FiatGuy's own account rejected when a destination is set");
+        }
+        const sig = await signTicket(
+            program, cryptoGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            custodianAta, adminTokenAccount, adminSigner
+        );
+        const delegated = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+        expect(delegated.data.payoutDestination.toBase58()).to.eq(custodian.publicKey.toBase58());
+        expect(delegated.data.fiatGuy.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+        expect(await getTokenBalance(connection, custodianAta)).to.eq(delegated.data.netAmount.toNumber());
+        console.log("✓ Net amount delivered to the custodial wallet");
+    });
+
+    it("🪪 Delegated FiatGuy signer signs, settlement still pays the FiatGuy wallet", async () => {
+        const opsKey = Keypair.generate();
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 131), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, null, null, false, null, opsKey.publicKey
+        );
+        const ticket = await program.account.fillTicket.fetch(ticketPda);
+        expect(ticket.fiatGuySigner!.toBase58()).to.eq(opsKey.publicKey.toBase58());
+        expect(ticket.cryptoGuySigner).to.be.null;
+
+        // The fund wallet itself no longer signs for the FiatGuy side
+        try {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ FiatGuy wallet rejected as signer when delegated");
+        }
+
+        await signTicket(
+            program, opsKey, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect((await program.account.fillTicket.fetch(ticketPda)).fiatGuySigned).to.be.true;
+
+        await waitForCooldown();
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const fee = Math.floor(usdc(2).toNumber() * 20 / 10_000);
+        expect(await getTokenBalance(connection, fiatGuyTokenAccount) - beforeFiat).to.eq(usdc(2).toNumber() - fee);
+        console.log("✓ Delegate signed, FiatGuy wallet received the payout");
+    });
+
+    it("🏦 fee_destination: default admin and per-order rebate pool", async () => {
+        const settle = async (orderPda: PublicKey, vaultPda: PublicKey, ticketPda: PublicKey, feeAta: PublicKey) => {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, feeAta, adminSigner
+            );
+            await waitForCooldown();
+            const sig = await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, feeAta, adminSigner
+            );
+            return (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+        };
+
+        // Default: fee goes to the admin treasury
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 84), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        expect((await program.account.universalOrder.fetch(a.orderPda)).feeDestination).to.be.null;
+        const defaultSettled = await settle(a.orderPda, a.vaultPda, a.ticketPda, adminTokenAccount);
+        expect(defaultSettled.data.feeDestination.toBase58()).to.eq(adminSigner.publicKey.toBase58());
+
+        // Per-order: fee must go to the rebate pool's token account
+        const pool = Keypair.generate();
+        const poolAta = (await getOrCreateAssociatedTokenAccount(
+            connection, provider.wallet.payer as Keypair, tokenSetup.mint, pool.publicKey
+        )).address;
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 85), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, null, pool.publicKey
+        );
+        expect((await program.account.universalOrder.fetch(b.orderPda)).feeDestination.toBase58())
+            .to.eq(pool.publicKey.toBase58());
+        await signTicket(
+            program, fiatGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        try {
+            await signTicket(
+                program, cryptoGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Admin fee account rejected when the order has a fee destination");
+        }
+        const sig = await signTicket(
+            program, cryptoGuy, b.orderPda, tokenSetup.mint, b.vaultPda, b.ticketPda,
+            fiatGuyTokenAccount, poolAta, adminSigner
+        );
+        const routed = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+        expect(routed.data.feeDestination.toBase58()).to.eq(pool.publicKey.toBase58());
+        expect(routed.data.feeAmount.toNumber()).to.be.greaterThan(0);
+        expect(await getTokenBalance(connection, poolAta)).to.eq(routed.data.feeAmount.toNumber());
+        console.log("✓ Fee delivered to the rebate pool");
+    });
+
+    it("🏦 fee_destination: admin resolve pays the rebate pool, not the admin", async () => {
+        const pool = Keypair.generate();
+        const poolAta = (await getOrCreateAssociatedTokenAccount(
+            connection, provider.wallet.payer as Keypair, tokenSetup.mint, pool.publicKey
+        )).address;
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 142), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, null, pool.publicKey
+        );
+        const resolve = async (feeAccount: PublicKey) => (program.methods as any)
+            .adminResolveUniversalTicket(true, ResolutionReason.FiatConfirmed)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: await getRentReceiver(program),
+                config: deriveConfigPda(program.programId),
+                order: orderPda,
+                mint: tokenSetup.mint,
+                vault: vaultPda,
+                ticket: ticketPda,
+                fiatGuyTokenAccount: fiatGuyTokenAccount,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: feeAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+
+        try {
+            await resolve(adminTokenAccount);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+            console.log("✓ Admin fee account rejected on admin resolve of a fee_destination order");
+        }
+
+        const poolBefore = await getTokenBalance(connection, poolAta);
+        await resolve(poolAta);
+        const fee = Math.floor(usdc(2).toNumber() * 20 / 10_000);
+        expect(await getTokenBalance(connection, poolAta) - poolBefore).to.eq(fee);
+        console.log("✓ Admin resolve delivered the fee to the rebate pool");
+    });
+
+    it("💵 fee_on_top: locker prepays the fee, FiatGuy receives the full amount", async () => {
+        const amount = usdc(5);
+        const fee = Math.floor(amount.toNumber() * 20 / 10_000);
+        const lockAndSettle = async (orderOffset: number, feeOnTop: boolean) => {
+            const lockerBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + orderOffset), new anchor.BN(1), amount, new anchor.BN(500), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, null, null, null, null, null, feeOnTop
+            );
+            const locked = lockerBefore - await getTokenBalance(connection, cryptoGuyTokenAccount);
+            expect(await getTokenBalance(connection, vaultPda)).to.eq(locked);
+
+            const fiatBefore = await getTokenBalance(connection, fiatGuyTokenAccount);
+            const feeBefore = await getTokenBalance(connection, adminTokenAccount);
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            return {
+                locked,
+                received: await getTokenBalance(connection, fiatGuyTokenAccount) - fiatBefore,
+                feePaid: await getTokenBalance(connection, adminTokenAccount) - feeBefore,
+            };
+        };
+
+        // Fee-inclusive (default): lock amount, FiatGuy gets amount - fee
+        const inclusive = await lockAndSettle(118, false);
+        expect(inclusive.locked).to.eq(amount.toNumber());
+        expect(inclusive.received).to.eq(amount.toNumber() - fee);
+        expect(inclusive.feePaid).to.eq(fee);
+        console.log("✓ Fee-inclusive: FiatGuy received", inclusive.received);
+
+        // Fee-on-top: lock amount + fee, FiatGuy gets the whole amount
+        const onTop = await lockAndSettle(119, true);
+        expect(onTop.locked).to.eq(amount.toNumber() + fee);
+        expect(onTop.received).to.eq(amount.toNumber());
+        expect(onTop.feePaid).to.eq(fee);
+        console.log("✓ Fee-on-top: FiatGuy received", onTop.received);
+
+        // Cancelling a fee-on-top ticket refunds the prepaid fee as well
+        const lockerBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const c = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 120), new anchor.BN(1), amount, new anchor.BN(500), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, null, null, true
+        );
+        const order = await program.account.universalOrder.fetch(c.orderPda);
+        expect(order.feeOnTop).to.be.true;
+        expect(order.reservedFees.toNumber()).to.eq(fee);
+        expect((await program.account.fillTicket.fetch(c.ticketPda)).prepaidFee.toNumber()).to.eq(fee);
+        await cancelTicket(
+            program, fiatGuy, c.orderPda, tokenSetup.mint, c.vaultPda, c.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(lockerBefore);
+        expect(await connection.getAccountInfo(c.orderPda)).to.be.null;
+        console.log("✓ Fee-on-top cancel refunded amount + prepaid fee");
+    });
+
+    it("🌊 WSOL settlement with and without unwrap_to_sol", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        const amount = new anchor.BN(20_000_000); // 0.02 SOL
+        const cryptoGuyWsol = await createWrappedNativeAccount(connection, payer, cryptoGuy.publicKey, 2 * amount.toNumber());
+        const adminWsol = (await getOrCreateAssociatedTokenAccount(connection, payer, NATIVE_MINT, adminSigner.publicKey)).address;
+        const settledEvent = async (sig: string) =>
+            (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+
+        // Default: payout stays wrapped in the FiatGuy's WSOL account
+        const fiatGuyWsol = (await getOrCreateAssociatedTokenAccount(connection, payer, NATIVE_MINT, fiatGuy.publicKey)).address;
+        const wrappedBefore = await getTokenBalance(connection, fiatGuyWsol);
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 105), new anchor.BN(1), amount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy, cryptoGuyWsol, NATIVE_MINT, adminSigner
+        );
+        await signTicket(program, fiatGuy, a.orderPda, NATIVE_MINT, a.vaultPda, a.ticketPda, fiatGuyWsol, adminWsol, adminSigner);
+        await waitForCooldown();
+        const wrapped = await settledEvent(
+            await signTicket(program, cryptoGuy, a.orderPda, NATIVE_MINT, a.vaultPda, a.ticketPda, fiatGuyWsol, adminWsol, adminSigner)
+        );
+        expect(wrapped.data.unwrapped).to.be.false;
+        expect(await getTokenBalance(connection, fiatGuyWsol) - wrappedBefore).to.eq(wrapped.data.netAmount.toNumber());
+        console.log("✓ WSOL delivered to the FiatGuy's token account");
+
+        // Unwrap: FiatGuy without any WSOL account receives native SOL
+        const solBuyer = Keypair.generate();
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 106), new anchor.BN(1), amount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, solBuyer.publicKey, cryptoGuy, cryptoGuyWsol, NATIVE_MINT, adminSigner
+        );
+        await setUnwrapToSol(program, solBuyer, b.orderPda, b.ticketPda, true);
+        expect((await program.account.fillTicket.fetch(b.ticketPda)).unwrapToSol).to.be.true;
+        expect(await connection.getAccountInfo(getAssociatedTokenAddressSync(NATIVE_MINT, solBuyer.publicKey))).to.be.null;
+
+        await signTicket(program, solBuyer, b.orderPda, NATIVE_MINT, b.vaultPda, b.ticketPda, null, adminWsol, adminSigner);
+        await waitForCooldown();
+        const unwrapped = await settledEvent(
+            await signTicket(
+                program, cryptoGuy, b.orderPda, NATIVE_MINT, b.vaultPda, b.ticketPda, null, adminWsol, adminSigner,
+                TOKEN_PROGRAM_ID, solBuyer.publicKey
+            )
+        );
+        expect(unwrapped.data.unwrapped).to.be.true;
+        expect(unwrapped.data.payoutDestination.toBase58()).to.eq(solBuyer.publicKey.toBase58());
+        expect(await connection.getBalance(solBuyer.publicKey)).to.eq(unwrapped.data.netAmount.toNumber());
+        expect(await connection.getAccountInfo(deriveUnwrapPda(program.programId, b.ticketPda))).to.be.null;
+        expect(await connection.getAccountInfo(b.orderPda)).to.be.null;
+        console.log("✓ Native SOL delivered, temporary WSOL account closed");
+
+        // Only WSOL orders can opt in
+        const c = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 107), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy, cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        try {
+            await setUnwrapToSol(program, fiatGuy, c.orderPda, c.ticketPda, true);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidMint");
+        }
+        await cancelTicket(program, fiatGuy, c.orderPda, tokenSetup.mint, c.vaultPda, c.ticketPda, cryptoGuyTokenAccount, adminSigner);
+    });
+
+    it("🔖 ticket reference round-trips into TicketSettled and TicketCancelled", async () => {
+        const reference = Array.from(createHash("sha256").update("psp-payment-42").digest());
+        const settled = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 64), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, reference
+        );
+        const ticket = await program.account.fillTicket.fetch(settled.ticketPda);
+        expect(Array.from(ticket.reference)).to.deep.eq(reference);
+
+        await signTicket(
+            program, fiatGuy, settled.orderPda, tokenSetup.mint, settled.vaultPda, settled.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        const settleSig = await signTicket(
+            program, cryptoGuy, settled.orderPda, tokenSetup.mint, settled.vaultPda, settled.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const settledEvent = (await parseEvents(program, connection, settleSig)).find(e => e.name === "ticketSettled")!;
+        expect(Array.from(settledEvent.data.reference)).to.deep.eq(reference);
+        console.log("✓ Reference carried into TicketSettled");
+
+        const cancelled = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 65), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, null, null, reference
+        );
+        const cancelSig = await cancelTicket(
+            program, fiatGuy, cancelled.orderPda, tokenSetup.mint, cancelled.vaultPda, cancelled.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        const cancelledEvent = (await parseEvents(program, connection, cancelSig)).find(e => e.name === "ticketCancelled")!;
+        expect(Array.from(cancelledEvent.data.reference)).to.deep.eq(reference);
+        console.log("✓ Reference carried into TicketCancelled");
+    });
+
+    it("🪙 sign/cancel reject a mint other than the order's", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 55), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const otherMint = (await createTestToken(connection, provider.wallet.payer as Keypair, DECIMALS)).mint;
+
+        try {
+            await signTicket(
+                program, fiatGuy, orderPda, otherMint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidMint");
+        }
+        try {
+            await cancelTicket(
+                program, fiatGuy, orderPda, otherMint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidMint");
+        }
+        console.log("✓ Substituted mint rejected by sign and cancel");
+
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("🚫 BUY: program-owned acceptor is rejected as unsignable", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        // Account owned by another program (stands in for a PDA acceptor)
+        const programOwned = Keypair.generate();
+        await provider.sendAndConfirm(
+            new Transaction().add(SystemProgram.createAccount({
+                fromPubkey: payer.publicKey,
+                newAccountPubkey: programOwned.publicKey,
+                space: 0,
+                lamports: await connection.getMinimumBalanceForRentExemption(0),
+                programId: program.programId,
+            })),
+            [payer, programOwned]
+        );
+        const { tokenAccount } = await setupUserWithTokens(connection, payer, programOwned, tokenSetup, usdc(1).toNumber());
+
+        try {
+            await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 57), new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+                fiatGuy.publicKey, programOwned.publicKey, programOwned,
+                tokenAccount, tokenSetup.mint, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("AcceptorNotSignable");
+            console.log("✓ Program-owned BUY acceptor rejected");
+        }
+    });
+
+    it("🔁 Replayed client_nonce is DuplicateRequest, a genuine double-sign stays RaceCondition", async () => {
+        const nonce = new anchor.BN(42);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 133), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const sign = (signer: Keypair, clientNonce: anchor.BN | null) => signTicket(
+            program, signer, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner, TOKEN_PROGRAM_ID, null, clientNonce
+        );
+
+        await sign(fiatGuy, nonce);
+        expect((await program.account.fillTicket.fetch(ticketPda)).clientNonce.toNumber()).to.eq(42);
+
+        // Backend retry after an RPC timeout: same nonce -> already done
+        try {
+            await sign(fiatGuy, nonce);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("DuplicateRequest");
+        }
+
+        await waitForCooldown();
+        // A second, different attempt is still a race
+        for (const other of [new anchor.BN(43), null]) {
+            try {
+                await sign(fiatGuy, other);
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("RaceCondition");
+            }
+        }
+        console.log("✓ Idempotent retry distinguished from a double-sign");
+
+        // The nonce only covers the signer's own signature: CryptoGuy settles normally
+        await sign(cryptoGuy, nonce);
+        expect(await connection.getAccountInfo(ticketPda)).to.be.null;
+    });
+
+    it("🧾 Settlement receipt survives ticket closure until close_receipt", async () => {
+        const amount = usdc(1);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 134), new anchor.BN(1), amount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        const sig = await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner, TOKEN_PROGRAM_ID, null, null, true
+        );
+        const settled = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+
+        // Ticket and order are gone, the receipt stays
+        expect(await connection.getAccountInfo(ticketPda)).to.be.null;
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        const receiptPda = deriveReceiptPda(program.programId, ticketPda);
+        const receipt = await program.account.settlementReceipt.fetch(receiptPda);
+        expect(receipt.ticket.toBase58()).to.eq(ticketPda.toBase58());
+        expect(receipt.order.toBase58()).to.eq(orderPda.toBase58());
+        expect(receipt.amount.toString()).to.eq(amount.toString());
+        expect(receipt.feeAmount.toString()).to.eq(settled.data.feeAmount.toString());
+        expect(receipt.fiatGuy.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+        expect(receipt.cryptoGuy.toBase58()).to.eq(cryptoGuy.publicKey.toBase58());
+        expect(receipt.timestamp.toNumber()).to.eq(settled.data.timestamp.toNumber());
+        console.log("✓ Receipt persisted after the ticket closed");
+
+        // Only the admin reclaims the rent
+        try {
+            await closeReceipt(program, fiatGuy, receiptPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+        await closeReceipt(program, adminSigner, receiptPda);
+        expect(await connection.getAccountInfo(receiptPda)).to.be.null;
+    });
+
+    it("⚡ BUY: accept_offer_and_sign locks + FiatGuy signs, CryptoGuy settles", async () => {
+        const amount = usdc(2);
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndSign(
+            program, new anchor.BN(Date.now() + 58), new anchor.BN(1), amount, new anchor.BN(200),
+            fiatGuy, cryptoGuy, cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const events = await parseEvents(program, connection, signature);
+        expect(events.some(e => e.name === "offerAccepted")).to.be.true;
+        const signed = events.find(e => e.name === "ticketSigned")!;
+        expect(signed.data.isFiatGuy).to.be.true;
+        expect(signed.data.bothSigned).to.be.false;
+
+        const ticket = await program.account.fillTicket.fetch(ticketPda);
+        expect(ticket.fiatGuySigned).to.be.true;
+        expect(ticket.cryptoGuySigned).to.be.false;
+
+        // FiatGuy can't sign twice
+        try {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.match(/RaceCondition|ActionTooFrequent/);
+        }
+
+        await waitForCooldown();
+        const fiatBefore = await getTokenBalance(connection, fiatGuyTokenAccount);
+        const sig = await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const settled = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+        expect(await getTokenBalance(connection, fiatGuyTokenAccount) - fiatBefore).to.eq(settled.data.netAmount.toNumber());
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Combined accept+sign settled by CryptoGuy's signature");
+    });
+
+    it("⚡ accept_offer_and_sign requires the BUY creator's signature", async () => {
+        const orderId = new anchor.BN(Date.now() + 59);
+        const { orderPda, vaultPda } = deriveOrderPdas(program.programId, fiatGuy.publicKey, tokenSetup.mint, orderId);
+        try {
+            await (program.methods as any)
+                .acceptOfferAndSign(orderId, new anchor.BN(1), usdc(1), new anchor.BN(100), false, fiatGuy.publicKey)
+                .accounts({
+                    feePayer: adminSigner.publicKey,
+                    locker: cryptoGuy.publicKey,
+                    creatorSigner: null,
+                    config: deriveConfigPda(program.programId),
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    ticket: deriveTicketPda(program.programId, orderPda, new anchor.BN(0)),
+                    lockerTokenAccount: cryptoGuyTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    associatedTokenProgram: anchor.utils.token.ASSOCIATED_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .signers([adminSigner, cryptoGuy])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("SignatureRequired");
+            console.log("✓ Missing FiatGuy signature rejected");
+        }
+    });
+
+    it("📦 accept_offer_and_lock returns order/vault/ticket PDAs", async () => {
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 11), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        const tx = await connection.getTransaction(signature, {
+            commitment: "confirmed",
+            maxSupportedTransactionVersion: 0,
+        });
+        const [data] = tx!.meta!.returnData!.data;
+        const returned = program.coder.types.decode("offerPdas", Buffer.from(data, "base64"));
+        expect(returned.order.toBase58()).to.eq(orderPda.toBase58());
+        expect(returned.vault.toBase58()).to.eq(vaultPda.toBase58());
+        expect(returned.ticket.toBase58()).to.eq(ticketPda.toBase58());
+        console.log("✓ Return data matches derived PDAs");
+
+        await waitForCooldown();
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("⚙️ Config: require_immutable_fee_owner rejects Token-2022 fee accounts without ImmutableOwner", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        const { token, accounts } = await setupUniversalTestToken(
+            connection, payer, [cryptoGuy, fiatGuy, adminSigner], true, DECIMALS, usdc(100).toNumber()
+        );
+        const cryptoAta = accounts.get(cryptoGuy.publicKey.toBase58())!;
+        const fiatAta = accounts.get(fiatGuy.publicKey.toBase58())!;
+        // Token-2022 ATAs get ImmutableOwner from the ATA program; plain keypair accounts don't
+        const immutableFeeAta = accounts.get(adminSigner.publicKey.toBase58())!;
+        const mutableFeeAccount = await createAccount(
+            connection, payer, token.mint, adminSigner.publicKey, Keypair.generate(), undefined, TOKEN_2022_PROGRAM_ID
+        );
+
+        await setRequireImmutableFeeOwner(program, adminSigner, true);
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 67), new anchor.BN(1), usdc(10), new anchor.BN(1000), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoAta, token.mint, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            await signTicket(
+                program, fiatGuy, orderPda, token.mint, vaultPda, ticketPda,
+                fiatAta, mutableFeeAccount, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            await waitForCooldown();
+
+            try {
+                await signTicket(
+                    program, cryptoGuy, orderPda, token.mint, vaultPda, ticketPda,
+                    fiatAta, mutableFeeAccount, adminSigner, TOKEN_2022_PROGRAM_ID
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("FeeAccountOwnerMutable");
+                console.log("✓ Fee account without ImmutableOwner rejected");
+            }
+
+            const feeBalance = async () => Number((await connection.getTokenAccountBalance(immutableFeeAta)).value.amount);
+            const beforeFee = await feeBalance();
+            await signTicket(
+                program, cryptoGuy, orderPda, token.mint, vaultPda, ticketPda,
+                fiatAta, immutableFeeAta, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            const fee = Math.floor(usdc(10).toNumber() * 20 / 10_000);
+            expect(await feeBalance() - beforeFee).to.eq(fee);
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            console.log("✓ Fee settled to the immutable-owner ATA");
+        } finally {
+            await setRequireImmutableFeeOwner(program, adminSigner, false);
+        }
+    });
+
+    it("💸 Transfer-fee mints are rejected: the vault must receive the full locked amount", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        // 1% transfer fee: the vault would receive 99% of what the locker sends
+        const token = await createToken2022WithTransferFee(connection, payer, 100, BigInt(usdc(1).toNumber()));
+        const cryptoAta = await createUniversalTokenAccount(
+            connection, payer, token.mint, cryptoGuy.publicKey, TOKEN_2022_PROGRAM_ID
+        );
+        await mintUniversalTokens(
+            connection, payer, token.mint, cryptoAta, token.mintAuthority, usdc(20).toNumber(), TOKEN_2022_PROGRAM_ID
+        );
+
+        const orderId = new anchor.BN(Date.now() + 121);
+        try {
+            await acceptOfferAndLock(
+                program, orderId, new anchor.BN(1), usdc(10), new anchor.BN(1000), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoAta, token.mint, adminSigner, TOKEN_2022_PROGRAM_ID
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("LockAmountMismatch");
+        }
+        // Whole transaction reverted: nothing locked, no order created
+        expect(Number((await connection.getTokenAccountBalance(cryptoAta)).value.amount)).to.eq(usdc(20).toNumber());
+        const { orderPda } = deriveOrderPdas(program.programId, cryptoGuy.publicKey, token.mint, orderId);
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Short-delivered lock rejected with LockAmountMismatch");
+    });
+
+    it("⚙️ Config: require_ata rejects non-ATA locker accounts", async () => {
+        const payer = provider.wallet.payer as Keypair;
+        const nonAta = await createAccount(
+            connection, payer, tokenSetup.mint, cryptoGuy.publicKey, Keypair.generate()
+        );
+        await transfer(connection, payer, cryptoGuyTokenAccount, nonAta, cryptoGuy, usdc(1).toNumber());
+        expect(cryptoGuyTokenAccount.toBase58()).to.eq(
+            getAssociatedTokenAddressSync(tokenSetup.mint, cryptoGuy.publicKey).toBase58()
+        );
+
+        await setRequireAta(program, adminSigner, true);
+        try {
+            try {
+                await acceptOfferAndLock(
+                    program, new anchor.BN(Date.now() + 12), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                    cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                    nonAta, tokenSetup.mint, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("InvalidTokenAccount");
+                console.log("✓ Non-ATA locker account rejected");
+            }
+
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 13), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            console.log("✓ Canonical ATA accepted");
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        } finally {
+            await setRequireAta(program, adminSigner, false);
+        }
+
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 14), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            nonAta, tokenSetup.mint, adminSigner
+        );
+        console.log("✓ Non-ATA accepted with require_ata=false");
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("✅ SELL crypto_first: CryptoGuy signs first, FiatGuy out of order is rejected", async () => {
+        const cryptoAmount = usdc(2);
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 15), new anchor.BN(1), cryptoAmount, new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner, TOKEN_PROGRAM_ID, true
+        );
+        const accepted = (await parseEvents(program, connection, signature))
+            .find(e => e.name === "offerAccepted")!;
+        expect(accepted.data.cryptoFirst).to.eq(true);
+
+        try {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("SignatureRequired");
+            console.log("✓ FiatGuy blocked from signing first");
+        }
+
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const afterFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        const fee = Math.floor(cryptoAmount.toNumber() * 20 / 10_000);
+        expect(afterFiat - beforeFiat).to.eq(cryptoAmount.toNumber() - fee);
+        console.log("✓ crypto_first order settled on FiatGuy signature");
+    });
+
+    it("✅ SELL: admin reassigns unsigned ticket, rejected after a signature", async () => {
+        const cryptoAmount = usdc(1);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 17), new anchor.BN(1), cryptoAmount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const newFiatGuy = Keypair.generate();
+
+        // Creator cannot become the acceptor
+        try {
+            await reassignTicket(program, orderPda, ticketPda, cryptoGuy.publicKey, adminSigner);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+
+        const sig = await reassignTicket(program, orderPda, ticketPda, newFiatGuy.publicKey, adminSigner);
+        const reassigned = (await parseEvents(program, connection, sig))
+            .find(e => e.name === "ticketReassigned")!;
+        expect(reassigned.data.oldAcceptor.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+        expect(reassigned.data.newAcceptor.toBase58()).to.eq(newFiatGuy.publicKey.toBase58());
+        let ticket = await program.account.fillTicket.fetch(ticketPda);
+        expect(ticket.acceptor.toBase58()).to.eq(newFiatGuy.publicKey.toBase58());
+        console.log("✓ Ticket reassigned pre-signature");
+
+        // Hand it back so the original FiatGuy can finish the flow
+        await reassignTicket(program, orderPda, ticketPda, fiatGuy.publicKey, adminSigner);
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        try {
+            await reassignTicket(program, orderPda, ticketPda, newFiatGuy.publicKey, adminSigner);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("TicketAlreadySigned");
+            console.log("✓ Reassignment rejected after signature");
+        }
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+    });
+
+    it("👥 max_distinct_acceptors: a third acceptor is rejected when the cap is 2", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 108), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda: second } = await splitTicket(program, orderPda, ticketPda, usdc(2), new anchor.BN(2), adminSigner);
+        const { newTicketPda: third } = await splitTicket(program, orderPda, ticketPda, usdc(1), new anchor.BN(3), adminSigner);
+        const secondBuyer = Keypair.generate();
+        const thirdBuyer = Keypair.generate();
+
+        await setMaxDistinctAcceptors(program, adminSigner, 2);
+        try {
+            // fiatGuy + secondBuyer = 2 distinct acceptors
+            await reassignTicket(program, orderPda, second, secondBuyer.publicKey, adminSigner);
+            try {
+                await reassignTicket(program, orderPda, third, thirdBuyer.publicKey, adminSigner);
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("TooManyAcceptors");
+                console.log("✓ Third distinct acceptor rejected");
+            }
+            // Existing acceptors can still take more tickets
+            await reassignTicket(program, orderPda, third, secondBuyer.publicKey, adminSigner);
+            const order = await program.account.universalOrder.fetch(orderPda);
+            expect(order.acceptorCount).to.eq(2);
+            expect(order.acceptors.slice(0, 2).map((k: PublicKey) => k.toBase58()))
+                .to.deep.eq([fiatGuy.publicKey.toBase58(), secondBuyer.publicKey.toBase58()]);
+        } finally {
+            await setMaxDistinctAcceptors(program, adminSigner, 4);
+        }
+
+        for (const [t, buyer] of [[ticketPda, fiatGuy], [second, secondBuyer], [third, secondBuyer]] as [PublicKey, Keypair][]) {
+            await cancelTicket(program, buyer, orderPda, tokenSetup.mint, vaultPda, t, cryptoGuyTokenAccount, adminSigner);
+        }
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("✂️ SELL: split ticket → both halves signed independently → auto-close", async () => {
+        const cryptoAmount = usdc(10);
+        const keepAmount = usdc(6);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 34), new anchor.BN(1), cryptoAmount, new anchor.BN(1000), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        const { signature, newTicketPda } = await splitTicket(
+            program, orderPda, ticketPda, keepAmount, new anchor.BN(2), adminSigner
+        );
+        const split = (await parseEvents(program, connection, signature))
+            .find(e => e.name === "ticketSplit")!;
+        expect(split.data.changeAmount.toNumber()).to.eq(cryptoAmount.toNumber() - keepAmount.toNumber());
+
+        const first = await program.account.fillTicket.fetch(ticketPda);
+        const second = await program.account.fillTicket.fetch(newTicketPda);
+        expect(first.amount.toNumber() + second.amount.toNumber()).to.eq(cryptoAmount.toNumber());
+        expect(second.acceptor.toBase58()).to.eq(first.acceptor.toBase58());
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.reservedAmount.toNumber()).to.eq(cryptoAmount.toNumber());
+        console.log("✓ Split:", first.amount.toNumber(), "+", second.amount.toNumber());
+
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        for (const t of [ticketPda, newTicketPda]) {
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, t,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+        }
+        const beforeSettle = await program.account.universalOrder.fetch(orderPda);
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const mid = await program.account.universalOrder.fetch(orderPda);
+        expect(mid.filledAmount.toNumber()).to.eq(keepAmount.toNumber());
+        expect(mid.updatedAt.toNumber()).to.be.greaterThan(beforeSettle.updatedAt.toNumber());
+        console.log("✓ First half settled, order still open with updated_at refreshed");
+
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, newTicketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const afterFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        const fees = Math.floor(keepAmount.toNumber() * 20 / 10_000)
+            + Math.floor((cryptoAmount.toNumber() - keepAmount.toNumber()) * 20 / 10_000);
+        expect(afterFiat - beforeFiat).to.eq(cryptoAmount.toNumber() - fees);
+
+        try {
+            await program.account.universalOrder.fetch(orderPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+            console.log("✓ Order auto-closed after both halves settled");
+        }
+    });
+
+    it("✂️ SELL: cancel one half, settle the other → auto-close", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 35), new anchor.BN(1), usdc(4), new anchor.BN(400), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda } = await splitTicket(
+            program, orderPda, ticketPda, usdc(1), new anchor.BN(2), adminSigner
+        );
+
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, newTicketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        const afterCancel = await program.account.universalOrder.fetch(orderPda);
+        expect(afterCancel.cryptoAmount.toNumber()).to.eq(usdc(1).toNumber());
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        try {
+            await program.account.universalOrder.fetch(orderPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+            console.log("✓ Refund shrank the target, remaining half completed the order");
+        }
+    });
+
+    it("❌ A vault that isn't the order's own PDA is rejected", async () => {
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 80), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 81), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        try {
+            await (program.methods as any)
+                .reconcileReserved(1)
+                .accounts({ admin: adminSigner.publicKey, order: a.orderPda, vault: b.vaultPda })
+                .remainingAccounts([{ pubkey: a.ticketPda, isSigner: false, isWritable: false }])
+                .signers([adminSigner])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("ConstraintSeeds");
+            console.log("✓ Foreign vault rejected");
+        }
+        for (const { orderPda, vaultPda, ticketPda } of [a, b]) {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        }
+    });
+
+    it("🩹 reconcile_reserved repairs reserved_amount drift", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 40), new anchor.BN(1), usdc(10), new anchor.BN(1000), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda } = await splitTicket(
+            program, orderPda, ticketPda, usdc(6), new anchor.BN(2), adminSigner
+        );
+        const reconcile = (tickets: PublicKey[], count = tickets.length) =>
+            (program.methods as any)
+                .reconcileReserved(count)
+                .accounts({ admin: adminSigner.publicKey, order: orderPda, vault: vaultPda })
+                .remainingAccounts(tickets.map(t => ({ pubkey: t, isSigner: false, isWritable: false })))
+                .signers([adminSigner])
+                .rpc();
+
+        // Normal instructions keep reserved in sync, so induce drift by reconciling against one ticket only
+        const driftSig = await reconcile([ticketPda]);
+        const drift = (await parseEvents(program, connection, driftSig))
+            .find(e => e.name === "reservedReconciled")!;
+        expect(drift.data.oldReserved.toNumber()).to.eq(usdc(10).toNumber());
+        expect(drift.data.newReserved.toNumber()).to.eq(usdc(6).toNumber());
+
+        try {
+            await reconcile([ticketPda, ticketPda]);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("DuplicateTicket");
+        }
+
+        const fixSig = await reconcile([ticketPda, newTicketPda]);
+        const fixed = (await parseEvents(program, connection, fixSig))
+            .find(e => e.name === "reservedReconciled")!;
+        expect(fixed.data.oldReserved.toNumber()).to.eq(usdc(6).toNumber());
+        expect(fixed.data.newReserved.toNumber()).to.eq(usdc(10).toNumber());
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.reservedAmount.toNumber()).to.eq(usdc(10).toNumber());
+        console.log("✓ reserved_amount restored to the open-ticket sum");
+
+        // Order still closes once both tickets are cancelled
+        for (const t of [ticketPda, newTicketPda]) {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, t,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        }
+        try {
+            await program.account.universalOrder.fetch(orderPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+            console.log("✓ Reconciled order auto-closed");
+        }
+    });
+
+
+    it("🧮 Refunding more than is reserved fails instead of clamping", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 68), new anchor.BN(1), usdc(10), new anchor.BN(1000), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda } = await splitTicket(
+            program, orderPda, ticketPda, usdc(6), new anchor.BN(2), adminSigner
+        );
+        const reconcile = (tickets: PublicKey[]) =>
+            (program.methods as any)
+                .reconcileReserved(tickets.length)
+                .accounts({ admin: adminSigner.publicKey, order: orderPda, vault: vaultPda })
+                .remainingAccounts(tickets.map(t => ({ pubkey: t, isSigner: false, isWritable: false })))
+                .signers([adminSigner])
+                .rpc();
+
+        // Induce drift: reserved = 4 while the first ticket still holds 6
+        await reconcile([newTicketPda]);
+
+        try {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+            console.log("✓ cancel_ticket over-refund rejected");
+        }
+
+        try {
+            await (program.methods as any)
+                .adminResolveUniversalTicket(false, ResolutionReason.Other)
+                .accounts({
+                    admin: adminSigner.publicKey,
+                    adminRentReceiver: adminSigner.publicKey,
+                    config: deriveConfigPda(program.programId),
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    ticket: ticketPda,
+                    fiatGuyTokenAccount: fiatGuyTokenAccount,
+                    cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                    adminFeeAccount: adminTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([adminSigner])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+            console.log("✓ admin refund over-refund rejected");
+        }
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.reservedAmount.toNumber()).to.eq(usdc(4).toNumber());
+
+        // Repair and clean up
+        await reconcile([ticketPda, newTicketPda]);
+        for (const t of [ticketPda, newTicketPda]) {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, t,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        }
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("🪜 create_orders_batch creates and funds three orders in one transaction", async () => {
+        const base = Date.now() + 86;
+        const ladder = [1, 2, 3].map((n, i) => ({
+            orderId: new anchor.BN(base + i),
+            cryptoAmount: usdc(n),
+            fiatAmount: new anchor.BN(n * 100),
+        }));
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+
+        const { signature, pdas } = await createOrdersBatch(
+            program, ladder, new anchor.BN(1), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        for (const [i, { orderPda, vaultPda, ticketPda }] of pdas.entries()) {
+            expect(await getTokenBalance(connection, vaultPda)).to.eq(ladder[i].cryptoAmount.toNumber());
+            const order = await program.account.universalOrder.fetch(orderPda);
+            expect(order.cryptoAmount.toNumber()).to.eq(ladder[i].cryptoAmount.toNumber());
+            expect(order.reservedAmount.toNumber()).to.eq(ladder[i].cryptoAmount.toNumber());
+            expect(order.vaultAuthority.toBase58()).to.eq(orderPda.toBase58());
+            const ticket = await program.account.fillTicket.fetch(ticketPda);
+            expect(ticket.acceptor.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+        }
+        expect(beforeCrypto - await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(usdc(6).toNumber());
+        const accepted = (await parseEvents(program, connection, signature)).filter(e => e.name === "offerAccepted");
+        expect(accepted.length).to.eq(3);
+        console.log("✓ Three vaults funded in one transaction");
+
+        // Capped at 4 orders per batch
+        try {
+            await createOrdersBatch(
+                program,
+                [0, 1, 2, 3, 4].map(i => ({ orderId: new anchor.BN(base + 10 + i), cryptoAmount: usdc(1), fiatAmount: new anchor.BN(100) })),
+                new anchor.BN(1), true, cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidBatchSize");
+        }
+
+        // Batch-created orders behave like single ones
+        for (const { orderPda, vaultPda, ticketPda } of pdas) {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        }
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(beforeCrypto);
+    });
+
+    it("🪜 create_orders_batch shares OfferOptions; fee-on-top prepaid fee computed per rung", async () => {
+        const base = Date.now() + 143;
+        const ladder = [1, 2].map((n, i) => ({
+            orderId: new anchor.BN(base + i),
+            cryptoAmount: usdc(n),
+            fiatAmount: new anchor.BN(n * 100),
+        }));
+        const pool = Keypair.generate();
+        const reference = Array.from({ length: 32 }, (_, i) => i + 1);
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+
+        // A vault authority can't be every rung's order PDA
+        try {
+            await createOrdersBatch(
+                program, ladder, new anchor.BN(1), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+                TOKEN_PROGRAM_ID, false, null, { vaultAuthority: Keypair.generate().publicKey }
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidVaultAuthority");
+        }
+
+        const { pdas } = await createOrdersBatch(
+            program, ladder, new anchor.BN(1), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner,
+            TOKEN_PROGRAM_ID, false, null, { feeOnTop: true, feeDestination: pool.publicKey, reference }
+        );
+
+        let totalLocked = 0;
+        for (const [i, { orderPda, vaultPda, ticketPda }] of pdas.entries()) {
+            const amount = ladder[i].cryptoAmount.toNumber();
+            const fee = Math.floor(amount * 20 / 10_000);
+            const order = await program.account.universalOrder.fetch(orderPda);
+            const ticket = await program.account.fillTicket.fetch(ticketPda);
+            expect(order.feeOnTop).to.eq(true);
+            expect(order.feeDestination.toBase58()).to.eq(pool.publicKey.toBase58());
+            expect(order.reservedFees.toNumber()).to.eq(fee);
+            expect(ticket.prepaidFee.toNumber()).to.eq(fee);
+            expect(ticket.reference).to.deep.eq(reference);
+            expect(await getTokenBalance(connection, vaultPda)).to.eq(amount + fee);
+            totalLocked += amount + fee;
+        }
+        expect(beforeCrypto - await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(totalLocked);
+        console.log("✓ Options applied to every rung, prepaid fees locked per rung");
+
+        for (const { orderPda, vaultPda, ticketPda } of pdas) {
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        }
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(beforeCrypto);
+    });
+
+    it("🧭 roles match the locker for every direction × creation path", async () => {
+        const cases = [
+            { name: "SELL / accept_offer_and_lock", isSell: true, batch: false },
+            { name: "BUY / accept_offer_and_lock", isSell: false, batch: false },
+            { name: "SELL / create_orders_batch", isSell: true, batch: true },
+            { name: "BUY / create_orders_batch", isSell: false, batch: true },
+        ];
+        for (const [i, c] of cases.entries()) {
+            const orderId = new anchor.BN(Date.now() + 101 + i);
+            const amount = usdc(1);
+            // SELL: CryptoGuy creates and locks; BUY: FiatGuy creates, CryptoGuy accepts and locks
+            const creator = c.isSell ? cryptoGuy.publicKey : fiatGuy.publicKey;
+            const lockerBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+
+            const { orderPda, vaultPda, ticketPda } = c.batch
+                ? (await createOrdersBatch(
+                    program, [{ orderId, cryptoAmount: amount, fiatAmount: new anchor.BN(100) }], new anchor.BN(1), c.isSell,
+                    creator, fiatGuy.publicKey, cryptoGuy, cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                )).pdas[0]
+                : await acceptOfferAndLock(
+                    program, orderId, new anchor.BN(1), amount, new anchor.BN(100), c.isSell,
+                    creator, fiatGuy.publicKey, cryptoGuy, cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+            expect(lockerBefore - await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(amount.toNumber());
+            const ticket = await program.account.fillTicket.fetch(ticketPda);
+            expect(ticket.acceptor.toBase58()).to.eq((c.isSell ? fiatGuy : cryptoGuy).publicKey.toBase58());
+
+            // The locker is treated as CryptoGuy: fiat-first, so it can't sign first
+            try {
+                await signTicket(program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda, fiatGuyTokenAccount, adminTokenAccount, adminSigner);
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("SignatureRequired");
+            }
+            await signTicket(program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda, fiatGuyTokenAccount, adminTokenAccount, adminSigner);
+            await waitForCooldown();
+            const sig = await signTicket(program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda, fiatGuyTokenAccount, adminTokenAccount, adminSigner);
+            const settled = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketSettled")!;
+            expect(settled.data.cryptoGuy.toBase58()).to.eq(cryptoGuy.publicKey.toBase58());
+            expect(settled.data.fiatGuy.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+            console.log(`✓ ${c.name}: locker = CryptoGuy, payout to FiatGuy`);
+        }
+    });
+
+    it("🧹 cancel_all_unsigned refunds and closes three unsigned tickets in one call", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 49), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda: second } = await splitTicket(
+            program, orderPda, ticketPda, usdc(2), new anchor.BN(2), adminSigner
+        );
+        const { newTicketPda: third } = await splitTicket(
+            program, orderPda, ticketPda, usdc(1), new anchor.BN(3), adminSigner
+        );
+        const tickets = [ticketPda, second, third];
+
+        // Only the creator may clean up
+        try {
+            await cancelAllUnsigned(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, tickets,
+                fiatGuyTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+
+        const before = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const sig = await cancelAllUnsigned(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, tickets,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount) - before).to.eq(usdc(3).toNumber());
+
+        const events = await parseEvents(program, connection, sig);
+        expect(events.filter(e => e.name === "ticketCancelled").length).to.eq(3);
+        const closed = events.find(e => e.name === "orderClosed")!;
+        expect(closed.data.refundCount).to.eq(3);
+        for (const pda of [...tickets, orderPda, vaultPda]) {
+            expect(await connection.getAccountInfo(pda)).to.be.null;
+        }
+        console.log("✓ Reserved dropped to zero, order + vault + 3 tickets closed");
+    });
+
+    it("🔢 Tickets are enumerable by sequential index (no program-account scan)", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 72), new anchor.BN(7), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        expect(ticketPda.equals(deriveTicketPda(program.programId, orderPda, new anchor.BN(0)))).to.be.true;
+        const { newTicketPda: second } = await splitTicket(
+            program, orderPda, ticketPda, usdc(2), new anchor.BN(42), adminSigner
+        );
+        const { newTicketPda: third } = await splitTicket(
+            program, orderPda, ticketPda, usdc(1), new anchor.BN(43), adminSigner
+        );
+
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.ticketIndex.toNumber()).to.eq(3);
+        const pdas = await deriveOrderTicketPdas(program, orderPda);
+        expect(pdas.map(p => p.toBase58())).to.deep.eq([ticketPda, second, third].map(p => p.toBase58()));
+
+        // ticket_id stays a client reference, index drives the address
+        const tickets = await Promise.all(pdas.map(p => program.account.fillTicket.fetch(p)));
+        expect(tickets.map(t => t.index.toNumber())).to.deep.eq([0, 1, 2]);
+        expect(tickets.map(t => t.ticketId.toNumber())).to.deep.eq([7, 42, 43]);
+
+        await cancelAllUnsigned(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, pdas,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Ticket PDAs derived from 0..ticket_index");
+    });
+
+    it("✂️ split enforces the minimum fill fraction on large and small orders", async () => {
+        const original = (await program.account.config.fetch(deriveConfigPda(program.programId))).minFillDenominator;
+        await setMinFillDenominator(program, adminSigner, 4);
+        try {
+            const cases = [
+                { offset: 74, total: usdc(10) },              // min 2.5 USDC
+                { offset: 75, total: new anchor.BN(10_000) }, // min 2_500 base units
+            ];
+            for (const { offset, total } of cases) {
+                const min = total.divn(4);
+                const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                    program, new anchor.BN(Date.now() + offset), new anchor.BN(1), total, new anchor.BN(100), true,
+                    cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                    cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+                );
+
+                // Kept half one unit short, then change half one unit short
+                for (const keep of [min.subn(1), total.sub(min).addn(1)]) {
+                    try {
+                        await splitTicket(program, orderPda, ticketPda, keep, new anchor.BN(2), adminSigner);
+                        throw new Error("Should fail");
+                    } catch (e: any) {
+                        expect(e.message).to.include("FillTooSmall");
+                    }
+                }
+
+                const { newTicketPda } = await splitTicket(program, orderPda, ticketPda, min, new anchor.BN(2), adminSigner);
+                expect((await program.account.fillTicket.fetch(ticketPda)).amount.toString()).to.eq(min.toString());
+                expect((await program.account.fillTicket.fetch(newTicketPda)).amount.toString()).to.eq(total.sub(min).toString());
+
+                await cancelAllUnsigned(
+                    program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, [ticketPda, newTicketPda],
+                    cryptoGuyTokenAccount, adminSigner
+                );
+            }
+            console.log("✓ Split at the minimum accepted, one unit below rejected");
+        } finally {
+            await setMinFillDenominator(program, adminSigner, original);
+        }
+    });
+
+    it("📊 fill_budget decreases with each settlement; the budget is informational only", async () => {
+        const original = await program.account.config.fetch(deriveConfigPda(program.programId));
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 82), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda: second } = await splitTicket(program, orderPda, ticketPda, usdc(2), new anchor.BN(2), adminSigner);
+
+        const fresh = await getFillBudget(program, orderPda);
+        expect(fresh.fillsToday).to.eq(0);
+        expect(fresh.remainingFills).to.eq(original.maxFillsPerDay);
+        expect(fresh.windowResetsIn.toNumber()).to.eq(0);
+
+        const settle = async (t: PublicKey) => {
+            await signTicket(program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, t, fiatGuyTokenAccount, adminTokenAccount, adminSigner);
+            await waitForCooldown();
+            await signTicket(program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, t, fiatGuyTokenAccount, adminTokenAccount, adminSigner);
+        };
+
+        try {
+            await setMaxFillsPerDay(program, adminSigner, 0);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+        }
+
+        await setMaxFillsPerDay(program, adminSigner, 2);
+        try {
+            await settle(second);
+            let budget = await getFillBudget(program, orderPda);
+            expect(budget.fillsToday).to.eq(1);
+            expect(budget.remainingFills).to.eq(1);
+            // Window opened by the first settlement lasts a day
+            expect(budget.windowResetsIn.toNumber()).to.be.within(24 * 60 * 60 - 60, 24 * 60 * 60);
+
+            const { newTicketPda: third } = await splitTicket(program, orderPda, ticketPda, usdc(1), new anchor.BN(3), adminSigner);
+            await settle(third);
+            budget = await getFillBudget(program, orderPda);
+            expect(budget.remainingFills).to.eq(0);
+
+            // Budget used up: the view bottoms out at 0, nothing is blocked
+            const { newTicketPda: fourth } = await splitTicket(program, orderPda, ticketPda, usdc(0.5), new anchor.BN(4), adminSigner);
+            await settle(fourth);
+            budget = await getFillBudget(program, orderPda);
+            expect(budget.fillsToday).to.eq(3);
+            expect(budget.remainingFills).to.eq(0);
+            console.log("✓ Split and settlement past the budget still go through");
+
+            await settle(ticketPda);
+            expect(await connection.getAccountInfo(orderPda)).to.be.null;
+            console.log("✓ Last ticket settled, order closed");
+        } finally {
+            await setMaxFillsPerDay(program, adminSigner, original.maxFillsPerDay);
+        }
+    });
+
+    it("🧹 cancel_all_unsigned skips signed tickets", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 50), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const { newTicketPda } = await splitTicket(
+            program, orderPda, ticketPda, usdc(1), new anchor.BN(2), adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        await cancelAllUnsigned(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, [ticketPda, newTicketPda],
+            cryptoGuyTokenAccount, adminSigner
+        );
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(order.reservedAmount.toNumber()).to.eq(usdc(1).toNumber());
+        expect(order.cryptoAmount.toNumber()).to.eq(usdc(1).toNumber());
+        expect(await connection.getAccountInfo(newTicketPda)).to.be.null;
+        expect(await connection.getAccountInfo(ticketPda)).to.not.be.null;
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ Signed ticket kept and settled after cleanup");
+    });
+
+    it("🧯 admin_force_close_order requires the vault to be gone", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 52), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        try {
+            await adminForceCloseOrder(program, fiatGuy, orderPda, vaultPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+
+        // A live vault is never orphaned
+        try {
+            await adminForceCloseOrder(program, adminSigner, orderPda, vaultPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("VaultStillOpen");
+            console.log("✓ Force close refused while the vault exists");
+        }
+
+        // Settle with a stray token in the vault so the order stays open with nothing reserved
+        await transfer(connection, provider.wallet.payer as Keypair, cryptoGuyTokenAccount, vaultPda, cryptoGuy, 1);
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await adminRecoverExcess(program, adminSigner, orderPda, tokenSetup.mint, vaultPda, cryptoGuyTokenAccount);
+        expect((await program.account.universalOrder.fetch(orderPda)).reservedAmount.toNumber()).to.eq(0);
+
+        // Nothing reserved isn't enough: the (empty) vault still exists. Vaults can't be closed from
+        // outside the program on a live cluster, so the success path isn't reachable here
+        try {
+            await adminForceCloseOrder(program, adminSigner, orderPda, vaultPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("VaultStillOpen");
+        }
+        expect(await connection.getAccountInfo(orderPda)).to.not.be.null;
+        console.log("✓ Force close refused while an empty vault exists");
+    });
+    it("❌ BUY: ticket reassignment is rejected", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 18), new anchor.BN(1), usdc(1), new anchor.BN(100), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        try {
+            await reassignTicket(program, orderPda, ticketPda, Keypair.generate().publicKey, adminSigner);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidOrderType");
+            console.log("✓ BUY acceptor (CryptoGuy) cannot be reassigned");
+        }
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("📡 TicketSigned carries order progress after a first signature", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 36), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const sig = await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const signed = (await parseEvents(program, connection, sig))
+            .find(e => e.name === "ticketSigned")!;
+        const order = await program.account.universalOrder.fetch(orderPda);
+        expect(signed.data.bothSigned).to.eq(false);
+        expect(signed.data.filledAmount.toNumber()).to.eq(order.filledAmount.toNumber());
+        expect(signed.data.reservedAmount.toNumber()).to.eq(order.reservedAmount.toNumber());
+        console.log("✓ TicketSigned progress:", signed.data.filledAmount.toNumber(), "/", signed.data.reservedAmount.toNumber());
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+    });
+
+    it("❌ fiat_amount above MAX_FIAT_AMOUNT is rejected", async () => {
+        const MAX_FIAT_AMOUNT = new anchor.BN("1000000000000000");
+
+        const atCap = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 37), new anchor.BN(1), usdc(1), MAX_FIAT_AMOUNT, true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const order = await program.account.universalOrder.fetch(atCap.orderPda);
+        expect(order.fiatAmount.toString()).to.eq(MAX_FIAT_AMOUNT.toString());
+        console.log("✓ fiat_amount at cap accepted");
+
+        try {
+            await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 38), new anchor.BN(1), usdc(1), MAX_FIAT_AMOUNT.addn(1), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+            console.log("✓ fiat_amount above cap rejected");
+        }
+
+        await cancelTicket(
+            program, fiatGuy, atCap.orderPda, tokenSetup.mint, atCap.vaultPda, atCap.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("❌ SELL default order: CryptoGuy cannot sign first", async () => {
+        const { signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 16), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const accepted = (await parseEvents(program, connection, signature))
+            .find(e => e.name === "offerAccepted")!;
+        expect(accepted.data.cryptoFirst).to.eq(false);
+
+        try {
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("SignatureRequired");
+            console.log("✓ CryptoGuy blocked from signing first");
+        }
+
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("❌ Settlement paths reject a vault that isn't the order's", async () => {
+        const a = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 32), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const b = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 33), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        // Another order's vault and a plain token account owned by a user
+        for (const badVault of [b.vaultPda, fiatGuyTokenAccount]) {
+            try {
+                await signTicket(
+                    program, fiatGuy, a.orderPda, tokenSetup.mint, badVault, a.ticketPda,
+                    fiatGuyTokenAccount, adminTokenAccount, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.not.eq("Should fail");
+            }
+            try {
+                await cancelTicket(
+                    program, fiatGuy, a.orderPda, tokenSetup.mint, badVault, a.ticketPda,
+                    cryptoGuyTokenAccount, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.not.eq("Should fail");
+            }
+        }
+        console.log("✓ Mismatched vaults rejected");
+
+        for (const o of [a, b]) {
+            await cancelTicket(
+                program, fiatGuy, o.orderPda, tokenSetup.mint, o.vaultPda, o.ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        }
+    });
+
+    it("✅ BUY: CryptoGuy withdraws before FiatGuy signs → refund + auto-close", async () => {
+        const cryptoAmount = usdc(2);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 101), new anchor.BN(1), cryptoAmount, new anchor.BN(200), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await waitForCooldown();
+
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        await withdrawTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+        const afterCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        expect(afterCrypto - beforeCrypto).to.eq(cryptoAmount.toNumber());
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ CryptoGuy withdrew BUY ticket");
+    });
+
+    it("❌ BUY: CryptoGuy cannot withdraw after FiatGuy signs", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 102), new anchor.BN(1), usdc(2), new anchor.BN(200), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        try {
+            await withdrawTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("CannotCancel");
+            console.log("✓ Withdrawal after FiatGuy signature blocked");
+        }
+
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+    });
+
+    it("↩️ FiatGuy can unsign before CryptoGuy signs, not after", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 123), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        // CryptoGuy hasn't signed anything to withdraw
+        await waitForCooldown();
+        try {
+            await unsignTicket(program, cryptoGuy, orderPda, ticketPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("NotSigned");
+        }
+
+        const tx = await unsignTicket(program, fiatGuy, orderPda, ticketPda);
+        const unsigned = (await parseEvents(program, connection, tx))
+            .find(e => e.name === "ticketUnsigned")!;
+        expect(unsigned.data.signer.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+        expect(unsigned.data.isCryptoGuy).to.eq(false);
+        const ticket = await program.account.fillTicket.fetch(ticketPda);
+        expect(ticket.fiatGuySigned).to.eq(false);
+        console.log("✓ FiatGuy signature withdrawn");
+
+        // Back to the start: CryptoGuy can't sign until FiatGuy signs again
+        await waitForCooldown();
+        try {
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("SignatureRequired");
+        }
+
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        // Second signature settled and closed the ticket: nothing left to unsign
+        try {
+            await unsignTicket(program, fiatGuy, orderPda, ticketPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("AccountNotInitialized");
+            console.log("✓ Unsign after CryptoGuy's signature rejected");
+        }
+    });
+
+    it("⏰ sign_or_timeout refunds CryptoGuy only after the FiatGuy grace period", async () => {
+        await setFiatSignGrace(program, adminSigner, 3);
+        try {
+            const cryptoAmount = usdc(2);
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 62), new anchor.BN(1), cryptoAmount, new anchor.BN(200), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+
+            try {
+                await signOrTimeout(
+                    program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                    cryptoGuyTokenAccount, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("SignGracePeriodActive");
+                console.log("✓ Timeout rejected inside the grace period");
+            }
+
+            await waitForCooldown();
+            await waitForCooldown();
+            const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+            await signOrTimeout(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            const afterCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+            expect(afterCrypto - beforeCrypto).to.eq(cryptoAmount.toNumber());
+            for (const pda of [vaultPda, orderPda, ticketPda]) {
+                expect(await connection.getAccountInfo(pda)).to.be.null;
+            }
+            console.log("✓ CryptoGuy refunded after the grace period");
+
+            // Once FiatGuy has signed, the timeout path is closed
+            const signed = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 63), new anchor.BN(1), cryptoAmount, new anchor.BN(200), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            await signTicket(
+                program, fiatGuy, signed.orderPda, tokenSetup.mint, signed.vaultPda, signed.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            try {
+                await signOrTimeout(
+                    program, cryptoGuy, signed.orderPda, tokenSetup.mint, signed.vaultPda, signed.ticketPda,
+                    cryptoGuyTokenAccount, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("CannotCancel");
+                console.log("✓ Timeout rejected after FiatGuy signed");
+            }
+            await signTicket(
+                program, cryptoGuy, signed.orderPda, tokenSetup.mint, signed.vaultPda, signed.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+        } finally {
+            await setFiatSignGrace(program, adminSigner, 24 * 60 * 60);
+        }
+    });
+
+    it("⏳ extend_ticket pushes the FiatGuy deadline out, capped by config", async () => {
+        await setFiatSignGrace(program, adminSigner, 3);
+        await setMaxTicketExtension(program, adminSigner, 10);
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 73), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            const ticket = await program.account.fillTicket.fetch(ticketPda);
+            const expiresAt = ticket.createdAt.addn(3);
+
+            // Past the cap
+            try {
+                await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(11));
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("ExtensionTooLong");
+            }
+
+            // CryptoGuy can't buy the FiatGuy time
+            try {
+                await extendTicket(program, cryptoGuy, orderPda, ticketPda, expiresAt.addn(5));
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("Unauthorized");
+            }
+
+            const sig = await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(10));
+            expect((await program.account.fillTicket.fetch(ticketPda)).extendedUntil.toNumber())
+                .to.eq(expiresAt.addn(10).toNumber());
+            const extended = (await parseEvents(program, connection, sig)).find(e => e.name === "ticketExtended")!;
+            expect(extended.data.oldExpiresAt.toNumber()).to.eq(expiresAt.toNumber());
+            expect(extended.data.extendedBy.toBase58()).to.eq(fiatGuy.publicKey.toBase58());
+            console.log("✓ Valid extension accepted, over-cap extension rejected");
+
+            // Original grace is over, but the extension keeps the ticket alive
+            await waitForCooldown();
+            await waitForCooldown();
+            try {
+                await signOrTimeout(
+                    program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                    cryptoGuyTokenAccount, adminSigner
+                );
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("SignGracePeriodActive");
+                console.log("✓ Timeout blocked until the extended deadline");
+            }
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        } finally {
+            await setFiatSignGrace(program, adminSigner, 24 * 60 * 60);
+            await setMaxTicketExtension(program, adminSigner, 24 * 60 * 60);
+        }
+    });
+
+    it("⏳ extend_ticket: repeated extensions can't pass creation + grace + max extension", async () => {
+        await setFiatSignGrace(program, adminSigner, 60);
+        await setMaxTicketExtension(program, adminSigner, 10);
+        try {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 145), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            const ticket = await program.account.fillTicket.fetch(ticketPda);
+            const expiresAt = ticket.createdAt.addn(60);
+
+            await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(4));
+
+            // Same deadline again is not an extension
+            try {
+                await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(4));
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("ExtensionNotLater");
+            }
+
+            // Second extension: only 7s past the current expiry, but 11s past the original deadline
+            try {
+                await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(11));
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("ExtensionTooLong");
+                console.log("✓ Second extension past the absolute cap rejected");
+            }
+
+            await extendTicket(program, fiatGuy, orderPda, ticketPda, expiresAt.addn(10));
+            expect((await program.account.fillTicket.fetch(ticketPda)).extendedUntil.toNumber())
+                .to.eq(expiresAt.addn(10).toNumber());
+            console.log("✓ Extensions up to the cap accepted");
+
+            await cancelTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+        } finally {
+            await setFiatSignGrace(program, adminSigner, 24 * 60 * 60);
+            await setMaxTicketExtension(program, adminSigner, 24 * 60 * 60);
+        }
+    });
+
+    it("❌ SELL: withdraw is not available", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 103), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        try {
+            await withdrawTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                cryptoGuyTokenAccount, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidOrderType");
+        }
+
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("📡 OrderClosed reports the fees collected by each settlement path", async () => {
+        // One ticket per order: settle one via signatures, one via admin payout
+        const signed = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 19), new anchor.BN(1), usdc(3), new anchor.BN(300), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, signed.orderPda, tokenSetup.mint, signed.vaultPda, signed.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        const settleSig = await signTicket(
+            program, cryptoGuy, signed.orderPda, tokenSetup.mint, signed.vaultPda, signed.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        const settleEvents = await parseEvents(program, connection, settleSig);
+        const settled = settleEvents.find(e => e.name === "ticketSettled")!;
+        const closedBySign = settleEvents.find(e => e.name === "orderClosed")!;
+        expect(closedBySign.data.totalFeesCollected.toNumber()).to.eq(settled.data.feeAmount.toNumber());
+
+        const resolved = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 20), new anchor.BN(1), usdc(5), new anchor.BN(500), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const resolveSig = await (program.methods as any)
+            .adminResolveUniversalTicket(true, ResolutionReason.FiatConfirmed)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: resolved.orderPda,
+                mint: tokenSetup.mint,
+                vault: resolved.vaultPda,
+                ticket: resolved.ticketPda,
+                fiatGuyTokenAccount: fiatGuyTokenAccount,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: adminTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+        const closedByAdmin = (await parseEvents(program, connection, resolveSig))
+            .find(e => e.name === "orderClosed")!;
+        const adminFee = Math.floor(usdc(5).toNumber() * 20 / 10_000);
+        expect(closedByAdmin.data.totalFeesCollected.toNumber()).to.eq(adminFee);
+
+        const total = closedBySign.data.totalFeesCollected.toNumber() + closedByAdmin.data.totalFeesCollected.toNumber();
+        expect(total).to.eq(settled.data.feeAmount.toNumber() + adminFee);
+        console.log("✓ Fees per order:", closedBySign.data.totalFeesCollected.toNumber(), closedByAdmin.data.totalFeesCollected.toNumber());
+    });
+
+    it("💸 Fee account is required only when a fee is charged", async () => {
+        // Fee-bearing settlement: omitting the fee account fails
+        const paid = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 24), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, paid.orderPda, tokenSetup.mint, paid.vaultPda, paid.ticketPda,
+            fiatGuyTokenAccount, null, adminSigner
+        );
+        await waitForCooldown();
+        try {
+            await signTicket(
+                program, cryptoGuy, paid.orderPda, tokenSetup.mint, paid.vaultPda, paid.ticketPda,
+                fiatGuyTokenAccount, null, adminSigner
+            );
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("TokenAccountRequired");
+            console.log("✓ Fee account required when fee > 0");
+        }
+        await signTicket(
+            program, cryptoGuy, paid.orderPda, tokenSetup.mint, paid.vaultPda, paid.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+
+        // Dust settlement: fee rounds to 0, fee account may be omitted
+        const dustAmount = new anchor.BN(100);
+        const dust = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 25), new anchor.BN(1), dustAmount, new anchor.BN(1), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, dust.orderPda, tokenSetup.mint, dust.vaultPda, dust.ticketPda,
+            fiatGuyTokenAccount, null, adminSigner
+        );
+        await waitForCooldown();
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        await signTicket(
+            program, cryptoGuy, dust.orderPda, tokenSetup.mint, dust.vaultPda, dust.ticketPda,
+            fiatGuyTokenAccount, null, adminSigner
+        );
+        const afterFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        expect(afterFiat - beforeFiat).to.eq(dustAmount.toNumber());
+        console.log("✓ Dust settled without fee account");
+    });
+
+    it("📊 OrderClosed reports settle/refund counters", async () => {
+        const closedEvent = async (sig: string) =>
+            (await parseEvents(program, connection, sig)).find(e => e.name === "orderClosed")!;
+
+        // Settled by signatures
+        const settled = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 26), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await signTicket(
+            program, fiatGuy, settled.orderPda, tokenSetup.mint, settled.vaultPda, settled.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        const settleClosed = await closedEvent(await signTicket(
+            program, cryptoGuy, settled.orderPda, tokenSetup.mint, settled.vaultPda, settled.ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        ));
+        expect(settleClosed.data.settleCount).to.eq(1);
+        expect(settleClosed.data.refundCount).to.eq(0);
+
+        // Refunded by cancel
+        const cancelled = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 27), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const cancelClosed = await closedEvent(await cancelTicket(
+            program, fiatGuy, cancelled.orderPda, tokenSetup.mint, cancelled.vaultPda, cancelled.ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        ));
+        expect(cancelClosed.data.settleCount).to.eq(0);
+        expect(cancelClosed.data.refundCount).to.eq(1);
+
+        // Split resolve counts as both
+        const split = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 28), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const splitClosed = await closedEvent(await (program.methods as any)
+            .adminSplitResolveUniversalTicket(usdc(1), ResolutionReason.Other)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: split.orderPda,
+                mint: tokenSetup.mint,
+                vault: split.vaultPda,
+                ticket: split.ticketPda,
+                fiatGuyTokenAccount: fiatGuyTokenAccount,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: adminTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc());
+        expect(splitClosed.data.settleCount).to.eq(1);
+        expect(splitClosed.data.refundCount).to.eq(1);
+        console.log("✓ Counters: settle 1/0, cancel 0/1, split 1/1");
+    });
+
+    it("🧹 sweep_fees accumulates vault dust in the protocol fee vault", async () => {
+        const feeVault = await initializeProtocolFeeVault(program, adminSigner, tokenSetup.mint);
+        const startBalance = await getTokenBalance(connection, feeVault);
+        const dustPerOrder = [7, 11];
+
+        for (const [i, dust] of dustPerOrder.entries()) {
+            const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + 29 + i), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+                cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            // Stray tokens sent straight to the vault keep it from emptying on settle
+            await transfer(connection, provider.wallet.payer as Keypair, cryptoGuyTokenAccount, vaultPda, cryptoGuy, dust);
+
+            await signTicket(
+                program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+            await signTicket(
+                program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            expect(await getTokenBalance(connection, vaultPda)).to.eq(dust);
+
+            const sig = await sweepFees(program, adminSigner, orderPda, tokenSetup.mint, vaultPda);
+            const events = await parseEvents(program, connection, sig);
+            expect(events.find(e => e.name === "feesSwept")!.data.amount.toNumber()).to.eq(dust);
+            expect(events.find(e => e.name === "orderClosed")!.data.dustAmount.toNumber()).to.eq(dust);
+            try {
+                await program.account.universalOrder.fetch(orderPda);
+                throw new Error("Should be closed");
+            } catch (e: any) {
+                expect(e.message).to.include("Account does not exist");
+            }
+        }
+
+        const endBalance = await getTokenBalance(connection, feeVault);
+        expect(endBalance - startBalance).to.eq(dustPerOrder.reduce((a, b) => a + b, 0));
+        console.log("✓ Protocol fee vault accumulated", endBalance - startBalance, "base units");
+    });
+
+    it("❌ sweep_fees rejects orders with an active ticket", async () => {
+        await initializeProtocolFeeVault(program, adminSigner, tokenSetup.mint);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 31), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        try {
+            await sweepFees(program, adminSigner, orderPda, tokenSetup.mint, vaultPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("OrderHasActiveTickets");
+            console.log("✓ Active ticket funds cannot be swept");
+        }
+        await cancelTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            cryptoGuyTokenAccount, adminSigner
+        );
+    });
+
+    it("🛟 admin_recover_excess returns only tokens beyond reserved_amount", async () => {
+        const cryptoAmount = usdc(1);
+        const stray = 1_234;
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 76), new anchor.BN(1), cryptoAmount, new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        // Nothing to recover while the vault holds exactly the reservation
+        try {
+            await adminRecoverExcess(program, adminSigner, orderPda, tokenSetup.mint, vaultPda, cryptoGuyTokenAccount);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+        }
+
+        // Only the admin
+        try {
+            await (program.methods as any)
+                .adminRecoverExcess()
+                .accounts({
+                    admin: cryptoGuy.publicKey,
+                    order: orderPda,
+                    mint: tokenSetup.mint,
+                    vault: vaultPda,
+                    recipientTokenAccount: cryptoGuyTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([cryptoGuy])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("Unauthorized");
+        }
+
+        await transfer(connection, provider.wallet.payer as Keypair, cryptoGuyTokenAccount, vaultPda, cryptoGuy, stray);
+        const before = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const sig = await adminRecoverExcess(program, adminSigner, orderPda, tokenSetup.mint, vaultPda, cryptoGuyTokenAccount);
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount) - before).to.eq(stray);
+        expect(await getTokenBalance(connection, vaultPda)).to.eq(cryptoAmount.toNumber());
+        const recovered = (await parseEvents(program, connection, sig)).find(e => e.name === "excessRecovered")!;
+        expect(recovered.data.amount.toNumber()).to.eq(stray);
+        console.log("✓ Recovered", stray, "stray base units; reservation untouched");
+
+        // Ticket still settles normally and the order auto-closes
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+    });
+
+    it("♻️ reclaim_rent closes a settled order left open, only once its vault is empty", async () => {
+        const stray = 5;
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 132), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        // Stray tokens keep the vault from emptying, so settle skips auto-close
+        await transfer(connection, provider.wallet.payer as Keypair, cryptoGuyTokenAccount, vaultPda, cryptoGuy, stray);
+        await signTicket(
+            program, fiatGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        await waitForCooldown();
+        await signTicket(
+            program, cryptoGuy, orderPda, tokenSetup.mint, vaultPda, ticketPda,
+            fiatGuyTokenAccount, adminTokenAccount, adminSigner
+        );
+        expect(await connection.getAccountInfo(orderPda)).to.not.be.null;
+
+        // Never closes over tokens
+        try {
+            await reclaimRent(program, fiatGuy, orderPda, vaultPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("VaultNotEmpty");
+        }
+
+        // Dust recovered -> order is leaked: filled, nothing reserved, empty vault, still open
+        await adminRecoverExcess(program, adminSigner, orderPda, tokenSetup.mint, vaultPda, cryptoGuyTokenAccount);
+        expect(await getTokenBalance(connection, vaultPda)).to.eq(0);
+        expect(await connection.getAccountInfo(orderPda)).to.not.be.null;
+
+        // Anyone can clean it up; rent goes to the configured receiver
+        const sig = await reclaimRent(program, fiatGuy, orderPda, vaultPda);
+        const closed = (await parseEvents(program, connection, sig)).find(e => e.name === "orderClosed")!;
+        expect(closed.data.dustAmount.toNumber()).to.eq(0);
+        expect(closed.data.rentReturnedTo.toBase58()).to.eq((await getRentReceiver(program)).toBase58());
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+        console.log("✓ Leaked order and vault closed by a non-admin caller");
+    });
+
+    it("✅ Admin split resolve 60/40 (fee-inclusive) → both paid, order closed", async () => {
+        const cryptoAmount = usdc(10);
+        const settleAmount = usdc(6);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 21), new anchor.BN(1), cryptoAmount, new anchor.BN(1000), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const beforeAdmin = await getTokenBalance(connection, adminTokenAccount);
+
+        const accounts = {
+            admin: adminSigner.publicKey,
+            adminRentReceiver: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: tokenSetup.mint,
+            vault: vaultPda,
+            ticket: ticketPda,
+            fiatGuyTokenAccount: fiatGuyTokenAccount,
+            cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+            adminFeeAccount: adminTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+
+        // Full amount is not a split
+        try {
+            await (program.methods as any)
+                .adminSplitResolveUniversalTicket(cryptoAmount, ResolutionReason.Other)
+                .accounts(accounts)
+                .signers([adminSigner])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidAmount");
+        }
+
+        // Reason code must be a ResolutionReason
+        try {
+            await (program.methods as any)
+                .adminSplitResolveUniversalTicket(settleAmount, 99)
+                .accounts(accounts)
+                .signers([adminSigner])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidReasonCode");
+        }
+
+        const sig = await (program.methods as any)
+            .adminSplitResolveUniversalTicket(settleAmount, ResolutionReason.FiatConfirmed)
+            .accounts(accounts)
+            .signers([adminSigner])
+            .rpc();
+
+        const fee = Math.floor(settleAmount.toNumber() * 20 / 10_000);
+        const refund = cryptoAmount.toNumber() - settleAmount.toNumber();
+        expect(await getTokenBalance(connection, fiatGuyTokenAccount) - beforeFiat).to.eq(settleAmount.toNumber() - fee);
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount) - beforeCrypto).to.eq(refund);
+        expect(await getTokenBalance(connection, adminTokenAccount) - beforeAdmin).to.eq(fee);
+        console.log("✓ Split paid: fiat", (settleAmount.toNumber() - fee) / 1_000_000, "crypto", refund / 1_000_000);
+
+        const events = await parseEvents(program, connection, sig);
+        const resolved = events.find(e => e.name === "universalAdminResolved")!;
+        expect(resolved.data.resolutionType).to.eq("ticket_split");
+        expect(resolved.data.reasonCode).to.eq(ResolutionReason.FiatConfirmed);
+        expect(resolved.data.amount.toNumber()).to.eq(cryptoAmount.toNumber());
+        const splitEvent = events.find(e => e.name === "ticketSplitResolved")!;
+        expect(splitEvent.data.settleAmount.toNumber()).to.eq(settleAmount.toNumber());
+        expect(splitEvent.data.feeAmount.toNumber()).to.eq(fee);
+        expect(splitEvent.data.netAmount.toNumber()).to.eq(settleAmount.toNumber() - fee);
+        expect(splitEvent.data.refundAmount.toNumber()).to.eq(refund);
+        expect(splitEvent.data.seq.toNumber()).to.be.greaterThan(0);
+        console.log("✓ UniversalAdminResolved (ticket_split) and TicketSplitResolved emitted");
+
+        // Order counters at close time: filled = settled part, target shrunk by refund
+        const closed = events.find(e => e.name === "orderClosed")!;
+        expect(closed.data.totalFeesCollected.toNumber()).to.eq(fee);
+        try {
+            await program.account.fillTicket.fetch(ticketPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+        }
+        try {
+            await program.account.universalOrder.fetch(orderPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+            console.log("✓ Ticket, vault and order closed after split");
+        }
+    });
+
+    it("⚖️ Admin three-way resolve 50/30/20 → fiat, crypto and treasury paid, order closed", async () => {
+        const cryptoAmount = usdc(10);
+        const toFiat = usdc(5);
+        const toCrypto = usdc(3);
+        const penalty = usdc(2);
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 128), new anchor.BN(1), cryptoAmount, new anchor.BN(1000), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const beforeAdmin = await getTokenBalance(connection, adminTokenAccount);
+
+        const accounts = {
+            admin: adminSigner.publicKey,
+            adminRentReceiver: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: tokenSetup.mint,
+            vault: vaultPda,
+            ticket: ticketPda,
+            fiatGuyTokenAccount: fiatGuyTokenAccount,
+            cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+            adminFeeAccount: adminTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+        };
+
+        // Portions must add up to the ticket amount exactly
+        for (const wrongPenalty of [penalty.subn(1), penalty.addn(1)]) {
+            try {
+                await (program.methods as any)
+                    .adminThreeWayResolve(toFiat, toCrypto, wrongPenalty, ResolutionReason.Other)
+                    .accounts(accounts)
+                    .signers([adminSigner])
+                    .rpc();
+                throw new Error("Should fail");
+            } catch (e: any) {
+                expect(e.message).to.include("InvalidAmount");
+            }
+        }
+        console.log("✓ Mismatched sums rejected");
+
+        try {
+            await (program.methods as any)
+                .adminThreeWayResolve(toFiat, toCrypto, penalty, 99)
+                .accounts(accounts)
+                .signers([adminSigner])
+                .rpc();
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidReasonCode");
+            console.log("✓ Unknown reason code rejected");
+        }
+
+        const sig = await (program.methods as any)
+            .adminThreeWayResolve(toFiat, toCrypto, penalty, ResolutionReason.Other)
+            .accounts(accounts)
+            .signers([adminSigner])
+            .rpc();
+
+        expect(await getTokenBalance(connection, fiatGuyTokenAccount) - beforeFiat).to.eq(toFiat.toNumber());
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount) - beforeCrypto).to.eq(toCrypto.toNumber());
+        expect(await getTokenBalance(connection, adminTokenAccount) - beforeAdmin).to.eq(penalty.toNumber());
+        console.log("✓ Paid: fiat 5, crypto 3, treasury 2");
+
+        const events = await parseEvents(program, connection, sig);
+        const resolved = events.find(e => e.name === "universalAdminResolved")!;
+        expect(resolved.data.resolutionType).to.eq("ticket_three_way");
+        expect(resolved.data.reasonCode).to.eq(ResolutionReason.Other);
+        expect(resolved.data.amount.toNumber()).to.eq(cryptoAmount.toNumber());
+        expect(resolved.data.recipient.toBase58()).to.eq(fiatGuyTokenAccount.toBase58());
+        const threeWay = events.find(e => e.name === "ticketThreeWayResolved")!;
+        expect(threeWay.data.toFiat.toNumber()).to.eq(toFiat.toNumber());
+        expect(threeWay.data.toCrypto.toNumber()).to.eq(toCrypto.toNumber());
+        expect(threeWay.data.prepaidFeeRefunded.toNumber()).to.eq(0);
+        expect(threeWay.data.penalty.toNumber()).to.eq(penalty.toNumber());
+        expect(threeWay.data.seq.toNumber()).to.be.greaterThan(0);
+        console.log("✓ UniversalAdminResolved (ticket_three_way) and TicketThreeWayResolved emitted");
+
+        // Penalty is reported as the order's fee take; everything closed
+        const closed = events.find(e => e.name === "orderClosed")!;
+        expect(closed.data.totalFeesCollected.toNumber()).to.eq(penalty.toNumber());
+        try {
+            await program.account.universalOrder.fetch(orderPda);
+            throw new Error("Should be closed");
+        } catch (e: any) {
+            expect(e.message).to.include("Account does not exist");
+            console.log("✓ Ticket, vault and order closed after three-way resolve");
+        }
+    });
+
+    it("🏷️ Admin resolution reason code propagates to UniversalAdminResolved", async () => {
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 79), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const resolve = (reasonCode: number) => (program.methods as any)
+            .adminResolveUniversalTicket(false, reasonCode)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: orderPda,
+                mint: tokenSetup.mint,
+                vault: vaultPda,
+                ticket: ticketPda,
+                fiatGuyTokenAccount: null,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: null,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+
+        try {
+            await resolve(9);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidReasonCode");
+        }
+
+        const sig = await resolve(ResolutionReason.Timeout);
+        const resolved = (await parseEvents(program, connection, sig)).find(e => e.name === "universalAdminResolved")!;
+        expect(resolved.data.reasonCode).to.eq(ResolutionReason.Timeout);
+        expect(resolved.data.resolutionType).to.eq("ticket_refund");
+        console.log("✓ Reason code recorded in the audit event");
+    });
+
+    it("🚩 admin_resolve_ticket_to redirects funds to a compliance account", async () => {
+        const compliance = Keypair.generate();
+        const complianceAta = (await getOrCreateAssociatedTokenAccount(
+            connection, provider.wallet.payer as Keypair, tokenSetup.mint, compliance.publicKey
+        )).address;
+        const resolveTo = async (
+            destination: PublicKey, releaseToFiatGuy: boolean,
+            orderPda: PublicKey, vaultPda: PublicKey, ticketPda: PublicKey
+        ) => (program.methods as any)
+            .adminResolveTicketTo(destination, releaseToFiatGuy, ResolutionReason.FraudRefund)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: orderPda,
+                mint: tokenSetup.mint,
+                vault: vaultPda,
+                ticket: ticketPda,
+                fiatGuyTokenAccount: releaseToFiatGuy ? complianceAta : null,
+                cryptoGuyTokenAccount: releaseToFiatGuy ? null : complianceAta,
+                adminFeeAccount: adminTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+
+        // Refund direction: flagged CryptoGuy's refund lands in the compliance account
+        const refund = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 77), new anchor.BN(1), usdc(2), new anchor.BN(200), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+
+        // Destination must be exactly the account passed
+        try {
+            await resolveTo(cryptoGuyTokenAccount, false, refund.orderPda, refund.vaultPda, refund.ticketPda);
+            throw new Error("Should fail");
+        } catch (e: any) {
+            expect(e.message).to.include("InvalidTokenAccount");
+        }
+
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        const sig = await resolveTo(complianceAta, false, refund.orderPda, refund.vaultPda, refund.ticketPda);
+        expect(await getTokenBalance(connection, complianceAta)).to.eq(usdc(2).toNumber());
+        expect(await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(beforeCrypto);
+        const resolved = (await parseEvents(program, connection, sig)).find(e => e.name === "universalAdminResolved")!;
+        expect(resolved.data.recipient.toBase58()).to.eq(complianceAta.toBase58());
+        expect(resolved.data.resolutionType).to.eq("ticket_refund_to");
+        expect(resolved.data.reasonCode).to.eq(ResolutionReason.FraudRefund);
+        expect(await connection.getAccountInfo(refund.orderPda)).to.be.null;
+
+        // Payout direction: net goes to compliance, fee still to admin
+        const payout = await acceptOfferAndLock(
+            program, new anchor.BN(Date.now() + 78), new anchor.BN(1), usdc(1), new anchor.BN(100), true,
+            cryptoGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        const beforeCompliance = await getTokenBalance(connection, complianceAta);
+        const beforeFiat = await getTokenBalance(connection, fiatGuyTokenAccount);
+        await resolveTo(complianceAta, true, payout.orderPda, payout.vaultPda, payout.ticketPda);
+        expect(await getTokenBalance(connection, complianceAta) - beforeCompliance).to.eq(998_000); // 20 bps fee
+        expect(await getTokenBalance(connection, fiatGuyTokenAccount)).to.eq(beforeFiat);
+        console.log("✓ Refund and payout redirected to the compliance account");
+    });
+
+    it("✅ Admin refund BUY → ticket, vault and order closed", async () => {
+        const orderId = new anchor.BN(Date.now() + 202);
+        const ticketId = new anchor.BN(1);
+        const cryptoAmount = usdc(3);
+
+        const { orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+            program, orderId, ticketId, cryptoAmount, new anchor.BN(300), false,
+            fiatGuy.publicKey, cryptoGuy.publicKey, cryptoGuy,
+            cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+        );
+        await waitForCooldown();
+
+        const beforeCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+
+        await (program.methods as any)
+            .adminResolveUniversalTicket(false, ResolutionReason.Other)
+            .accounts({
+                admin: adminSigner.publicKey,
+                adminRentReceiver: adminSigner.publicKey,
+                config: deriveConfigPda(program.programId),
+                order: orderPda,
+                mint: tokenSetup.mint,
+                vault: vaultPda,
+                ticket: ticketPda,
+                fiatGuyTokenAccount: fiatGuyTokenAccount,
+                cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                adminFeeAccount: adminTokenAccount,
+                tokenProgram: TOKEN_PROGRAM_ID,
+            })
+            .signers([adminSigner])
+            .rpc();
+        await waitForCooldown();
+
+        const afterCrypto = await getTokenBalance(connection, cryptoGuyTokenAccount);
+        expect(afterCrypto - beforeCrypto).to.eq(cryptoAmount.toNumber());
+
+        expect(await connection.getAccountInfo(ticketPda)).to.be.null;
+        expect(await connection.getAccountInfo(vaultPda)).to.be.null;
+        expect(await connection.getAccountInfo(orderPda)).to.be.null;
+        console.log("✓ BUY refund closed ticket, vault and order");
+    });
+
+    describe("🔁 Lifecycle: every transition for SELL and BUY", () => {
+        const DIRECTIONS = [
+            { name: "SELL", isSell: true },
+            { name: "BUY", isSell: false },
+        ];
+        const feeOf = (amount: anchor.BN) => Math.floor(amount.toNumber() * 20 / 10_000);
+        let nextOrderOffset = 109;
+
+        type Pdas = { orderPda: PublicKey; vaultPda: PublicKey; ticketPda: PublicKey };
+
+        // Created: CryptoGuy locked the full amount, ticket 0 reserves all of it, nobody signed.
+        // SELL: CryptoGuy creates and locks; BUY: FiatGuy creates, CryptoGuy accepts and locks
+        const create = async (isSell: boolean, amount: anchor.BN): Promise<Pdas> => {
+            const lockerBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+            const pdas = await acceptOfferAndLock(
+                program, new anchor.BN(Date.now() + nextOrderOffset++), new anchor.BN(1), amount, new anchor.BN(100), isSell,
+                isSell ? cryptoGuy.publicKey : fiatGuy.publicKey, fiatGuy.publicKey, cryptoGuy,
+                cryptoGuyTokenAccount, tokenSetup.mint, adminSigner
+            );
+            await waitForCooldown();
+
+            expect(lockerBefore - await getTokenBalance(connection, cryptoGuyTokenAccount)).to.eq(amount.toNumber());
+            expect(await getTokenBalance(connection, pdas.vaultPda)).to.eq(amount.toNumber());
+
+            const order = await program.account.universalOrder.fetch(pdas.orderPda);
+            const ticket = await program.account.fillTicket.fetch(pdas.ticketPda);
+            expect(order.isSellOrder).to.eq(isSell);
+            expect(order.reservedAmount.toNumber()).to.eq(amount.toNumber());
+            expect(order.filledAmount.toNumber()).to.eq(0);
+            expect(order.ticketIndex.toNumber()).to.eq(1);
+            expect(order.vault.toBase58()).to.eq(pdas.vaultPda.toBase58());
+            // Legacy slot, never written: acceptors live on the tickets and in order.acceptors
+            expect(order.acceptor).to.be.null;
+            expect(order.acceptorCount).to.eq(1);
+            expect(order.acceptors[0].toBase58()).to.eq(ticket.acceptor.toBase58());
+            expect(ticket.acceptor.toBase58()).to.eq((isSell ? fiatGuy : cryptoGuy).publicKey.toBase58());
+            expect(ticket.amount.toNumber()).to.eq(amount.toNumber());
+            expect(ticket.fiatGuySigned).to.be.false;
+            expect(ticket.cryptoGuySigned).to.be.false;
+            return pdas;
+        };
+
+        // FiatGuy signed: only the flag moves, funds and reservation stay put
+        const fiatGuySigns = async (pdas: Pdas, amount: anchor.BN) => {
+            const fiatBefore = await getTokenBalance(connection, fiatGuyTokenAccount);
+            await signTicket(
+                program, fiatGuy, pdas.orderPda, tokenSetup.mint, pdas.vaultPda, pdas.ticketPda,
+                fiatGuyTokenAccount, adminTokenAccount, adminSigner
+            );
+            await waitForCooldown();
+
+            const ticket = await program.account.fillTicket.fetch(pdas.ticketPda);
+            expect(ticket.fiatGuySigned).to.be.true;
+            expect(ticket.cryptoGuySigned).to.be.false;
+            const order = await program.account.universalOrder.fetch(pdas.orderPda);
+            expect(order.reservedAmount.toNumber()).to.eq(amount.toNumber());
+            expect(order.filledAmount.toNumber()).to.eq(0);
+            expect(order.settleCount).to.eq(0);
+            expect(await getTokenBalance(connection, pdas.vaultPda)).to.eq(amount.toNumber());
+            expect(await getTokenBalance(connection, fiatGuyTokenAccount)).to.eq(fiatBefore);
+        };
+
+        // Terminal: ticket, vault and order are all gone
+        const expectClosed = async (pdas: Pdas) => {
+            for (const pda of [pdas.ticketPda, pdas.vaultPda, pdas.orderPda]) {
+                expect(await connection.getAccountInfo(pda)).to.be.null;
+            }
+        };
+
+        const adminResolve = async (pdas: Pdas, releaseToFiatGuy: boolean) => {
+            await (program.methods as any)
+                .adminResolveUniversalTicket(releaseToFiatGuy, ResolutionReason.Other)
+                .accounts({
+                    admin: adminSigner.publicKey,
+                    adminRentReceiver: await getRentReceiver(program),
+                    config: deriveConfigPda(program.programId),
+                    order: pdas.orderPda,
+                    mint: tokenSetup.mint,
+                    vault: pdas.vaultPda,
+                    ticket: pdas.ticketPda,
+                    fiatGuyTokenAccount: fiatGuyTokenAccount,
+                    cryptoGuyTokenAccount: cryptoGuyTokenAccount,
+                    adminFeeAccount: adminTokenAccount,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .signers([adminSigner])
+                .rpc();
+            await waitForCooldown();
+        };
+
+        for (const d of DIRECTIONS) {
+            it(`${d.name}: created → FiatGuy signs → CryptoGuy signs → settled → closed`, async () => {
+                const amount = usdc(3);
+                const pdas = await create(d.isSell, amount);
+                await fiatGuySigns(pdas, amount);
+
+                const fiatBefore = await getTokenBalance(connection, fiatGuyTokenAccount);
+                const feeBefore = await getTokenBalance(connection, adminTokenAccount);
+                await signTicket(
+                    program, cryptoGuy, pdas.orderPda, tokenSetup.mint, pdas.vaultPda, pdas.ticketPda,
+                    fiatGuyTokenAccount, adminTokenAccount, adminSigner
+                );
+                await waitForCooldown();
+
+                expect(await getTokenBalance(connection, fiatGuyTokenAccount) - fiatBefore).to.eq(amount.toNumber() - feeOf(amount));
+                expect(await getTokenBalance(connection, adminTokenAccount) - feeBefore).to.eq(feeOf(amount));
+                await expectClosed(pdas);
+
+                // Closed is final: a late signature finds no order
+                try {
+                    await signTicket(
+                        program, fiatGuy, pdas.orderPda, tokenSetup.mint, pdas.vaultPda, pdas.ticketPda,
+                        fiatGuyTokenAccount, adminTokenAccount, adminSigner
+                    );
+                    throw new Error("Should fail");
+                } catch (e: any) {
+                    expect(isOrderNotFoundError(e)).to.be.true;
+                }
+                console.log(`✓ ${d.name}: settled and closed`);
+            });
+
+            it(`${d.name}: created → cancelled before any signature → refunded → closed`, async () => {
+                const amount = usdc(2);
+                // FiatGuy can cancel in both directions; on BUY the CryptoGuy can also withdraw
+                const refunds = d.isSell
+                    ? [{ name: "FiatGuy cancel", run: cancelTicket, signer: fiatGuy }]
+                    : [
+                        { name: "FiatGuy cancel", run: cancelTicket, signer: fiatGuy },
+                        { name: "CryptoGuy withdraw", run: withdrawTicket, signer: cryptoGuy },
+                    ];
+                for (const r of refunds) {
+                    const pdas = await create(d.isSell, amount);
+                    const cryptoBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+                    await r.run(
+                        program, r.signer, pdas.orderPda, tokenSetup.mint, pdas.vaultPda, pdas.ticketPda,
+                        cryptoGuyTokenAccount, adminSigner
+                    );
+                    await waitForCooldown();
+
+                    expect(await getTokenBalance(connection, cryptoGuyTokenAccount) - cryptoBefore).to.eq(amount.toNumber());
+                    await expectClosed(pdas);
+                    console.log(`✓ ${d.name}: ${r.name} refunded and closed`);
+                }
+            });
+
+            for (const releaseToFiatGuy of [true, false]) {
+                const outcome = releaseToFiatGuy ? "admin pays FiatGuy" : "admin refunds CryptoGuy";
+                it(`${d.name}: FiatGuy signed → dispute → ${outcome} → closed`, async () => {
+                    const amount = usdc(2);
+                    const pdas = await create(d.isSell, amount);
+                    await fiatGuySigns(pdas, amount);
+
+                    // Once FiatGuy has signed, the parties can no longer back out
+                    const backOut = d.isSell ? cancelTicket : withdrawTicket;
+                    try {
+                        await backOut(
+                            program, d.isSell ? fiatGuy : cryptoGuy, pdas.orderPda, tokenSetup.mint,
+                            pdas.vaultPda, pdas.ticketPda, cryptoGuyTokenAccount, adminSigner
+                        );
+                        throw new Error("Should fail");
+                    } catch (e: any) {
+                        expect(e.message).to.include("CannotCancel");
+                    }
+
+                    const fiatBefore = await getTokenBalance(connection, fiatGuyTokenAccount);
+                    const cryptoBefore = await getTokenBalance(connection, cryptoGuyTokenAccount);
+                    const feeBefore = await getTokenBalance(connection, adminTokenAccount);
+                    await adminResolve(pdas, releaseToFiatGuy);
+
+                    const fiatDelta = await getTokenBalance(connection, fiatGuyTokenAccount) - fiatBefore;
+                    const cryptoDelta = await getTokenBalance(connection, cryptoGuyTokenAccount) - cryptoBefore;
+                    const feeDelta = await getTokenBalance(connection, adminTokenAccount) - feeBefore;
+                    if (releaseToFiatGuy) {
+                        expect(fiatDelta).to.eq(amount.toNumber() - feeOf(amount));
+                        expect(feeDelta).to.eq(feeOf(amount));
+                        expect(cryptoDelta).to.eq(0);
+                    } else {
+                        // Refunds are fee-free
+                        expect(cryptoDelta).to.eq(amount.toNumber());
+                        expect(feeDelta).to.eq(0);
+                        expect(fiatDelta).to.eq(0);
+                    }
+                    await expectClosed(pdas);
+                    console.log(`✓ ${d.name}: ${outcome}, closed`);
+                });
+            }
+        }
+    });
+});
+
+
+describe.only("Universal Orders - Token Support", () => {
+    const provider = anchor.AnchorProvider.env();
+    anchor.setProvider(provider);
+    const program = anchor.workspace.Ddd as anchor.Program<Ddd>;
+    
+    // Test users
+    let adminSigner: Keypair;
+    let cryptoGuy: Keypair;
+    let fiatGuy: Keypair;
+    
+    // Will be set up for each test
+    let token: TestToken;
+    let accounts: Map<string, PublicKey>;
+    
+    before(async () => {
+        // Use configured admin wallet that matches on-chain constant
+        // This ensures address checks against ADMIN_PUBKEY pass.
+        const { TEST_WALLETS } = await import("../utils/testConfig");
+        adminSigner = TEST_WALLETS.buyer; // must match constants::ADMIN_PUBKEY
+
+        // Fresh test users for each run
+        cryptoGuy = Keypair.generate();
+        fiatGuy = Keypair.generate();
+
+        // Ensure both the tx fee payer (provider wallet) and the admin payer have SOL
+        const targets = [provider.wallet.publicKey, adminSigner.publicKey];
+        for (const pk of targets) {
+            try {
+                const sig = await provider.connection.requestAirdrop(
+                    pk,
+                    10 * anchor.web3.LAMPORTS_PER_SOL
+                );
+                await provider.connection.confirmTransaction(sig);
+            } catch (e) {
+                // Ignore if on a cluster without airdrop; tests may still pass if wallets are funded
+            }
+        }
+
+        console.log("✅ Test users initialized");
+        console.log("   Admin:", adminSigner.publicKey.toBase58());
+        console.log("   CryptoGuy:", cryptoGuy.publicKey.toBase58());
+        console.log("   FiatGuy:", fiatGuy.publicKey.toBase58());
+
+        await initializeConfig(program, adminSigner);
+    });
+    
+    /**
+     * Helper function to run the full order flow
+     */
+    async function testFullOrderFlow(tokenType: "SPL" | "Token-2022") {
+        const isToken2022 = tokenType === "Token-2022";
+        
+        console.log(`\n🧪 Testing with ${tokenType}...`);
+        
+        // Setup token and accounts
+        const setup = await setupUniversalTestToken(
+            provider.connection,
+            adminSigner,
+            [cryptoGuy, fiatGuy, adminSigner], // Create accounts for all parties
+            isToken2022,
+            6,
+            1_000_000_000 // 1000 tokens
+        );
+        
+        token = setup.token;
+        accounts = setup.accounts;
+        
+    console.log(`✅ ${tokenType} setup complete`);
+    console.log("   Mint:", token.mint.toBase58());
+    console.log("   Token Program:", token.tokenProgram.toBase58());
+        
+        // Test parameters
+        const orderId = new anchor.BN(Date.now());
+        const ticketId = new anchor.BN(1);
+        const cryptoAmount = new anchor.BN(100_000_000); // 100 tokens
+        const fiatAmount = new anchor.BN(100_00); // $100 (2 decimals)
+        const isSellOrder = true; // CryptoGuy sells, FiatGuy buys
+        const creator = cryptoGuy.publicKey; // CryptoGuy creates SELL order
+        
+        const cryptoGuyAta = accounts.get(cryptoGuy.publicKey.toBase58())!;
+        const fiatGuyAta = accounts.get(fiatGuy.publicKey.toBase58())!;
+        const adminAta = accounts.get(adminSigner.publicKey.toBase58())!;
+        
+    // Capture initial balances (admin may have been pre-minted in setup)
+    const adminInitialBal = await provider.connection.getTokenAccountBalance(adminAta).catch(() => null);
+    const adminInitialUi = adminInitialBal?.value?.uiAmount ?? 0;
+    console.log("   Admin initial fee account:", adminInitialUi, "tokens");
+
+    // Step 1: Accept offer and lock
+        console.log("\n📝 Step 1: Accept offer and lock tokens...");
+        let signature: string, orderPda: PublicKey, vaultPda: PublicKey, ticketPda: PublicKey;
+        try {
+            ({ signature, orderPda, vaultPda, ticketPda } = await acceptOfferAndLock(
+                program,
+                orderId,
+                ticketId,
+                cryptoAmount,
+                fiatAmount,
+                isSellOrder,
+                creator,
+                fiatGuy.publicKey,
+                cryptoGuy,
+                cryptoGuyAta,
+                token.mint,
+                adminSigner,
+                token.tokenProgram // Pass the correct token program
+            ));
+        } catch (e: any) {
+            if (typeof e?.getLogs === "function") {
+                console.error("acceptOfferAndLock logs:", await e.getLogs());
+            }
+            throw e;
+        }
+        
+        console.log("✅ Tokens locked");
+        console.log("   Signature:", signature);
+        console.log("   Order PDA:", orderPda.toBase58());
+        console.log("   Vault PDA:", vaultPda.toBase58());
+        console.log("   Ticket PDA:", ticketPda.toBase58());
+        
+        // Step 2: FiatGuy signs (first signature)
+        console.log("\n📝 Step 2: FiatGuy signs ticket...");
+        let sig1: string;
+        try {
+            sig1 = await signTicket(
+                program,
+                fiatGuy,
+                orderPda,
+                token.mint,
+                vaultPda,
+                ticketPda,
+                fiatGuyAta,
+                adminAta,
+                adminSigner,
+                token.tokenProgram
+            );
+        } catch (e: any) {
+            if (typeof e?.getLogs === "function") {
+                console.error("signTicket (fiat) logs:", await e.getLogs());
+            }
+            throw e;
+        }
+        console.log("✅ FiatGuy signed:", sig1);
+        await waitForCooldown();
         
         // Step 3: CryptoGuy signs (second signature - triggers settlement)
         console.log("\n📝 Step 3: CryptoGuy signs ticket (triggers settlement)...");
@@ -1,8 +1,18 @@
 import * as anchor from "@coral-xyz/anchor";
-import { PublicKey, Keypair, SystemProgram } from "@solana/web3.js";
+import { PublicKey, Keypair, SystemProgram, Ed25519Program, SYSVAR_INSTRUCTIONS_PUBKEY, ComputeBudgetProgram } from "@solana/web3.js";
 import { TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, ASSOCIATED_TOKEN_PROGRAM_ID } from "@solana/spl-token";
 import { Ddd } from "../target/types/ddd";
 
+/**
+ * Standard admin resolution reasons (`reason_code` of admin_resolve_universal_ticket / admin_resolve_ticket_to)
+ */
+export const ResolutionReason = {
+    FraudRefund: 0,
+    FiatConfirmed: 1,
+    Timeout: 2,
+    Other: 3,
+} as const;
+
 /**
  * Accept offer and lock crypto (creates order + vault + ticket, locks tokens)
  * This is the FIRST blockchain transaction when counterparty accepts a DB offer
@@ -20,6 +30,16 @@ import { Ddd } from "../target/types/ddd";
  * @param mint - Token mint (USDC, etc.)
  * @param adminSigner - Admin keypair (pays rent)
  * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @param cryptoFirst - true = CryptoGuy must sign first (default: FiatGuy first)
+ * @param creatorSigner - Creator keypair co-signing a BUY order (needed when config.require_creator_sig; must be null for SELL)
+ * @param vaultAuthority - Vault authority (null = order PDA, the only value accepted for now)
+ * @param payoutDestination - Wallet that must own the payout token account (null = FiatGuy)
+ * @param reference - 32-byte off-chain payment reference echoed in settle/cancel events (null = zeroes)
+ * @param feeDestination - Wallet that must own the fee token account on settlement (null = admin)
+ * @param feeOnTop - true = lock cryptoAmount + fee so FiatGuy receives the full cryptoAmount
+ * @param cryptoGuySigner - Key that signs the ticket for the CryptoGuy (null = the CryptoGuy wallet)
+ * @param fiatGuySigner - Key that signs the ticket for the FiatGuy (null = the FiatGuy wallet)
+ * @param allowedTakers - Private order: up to 4 wallets allowed as the ticket acceptor ([] = anyone)
  * @returns Transaction signature
  */
 export async function acceptOfferAndLock(
@@ -35,7 +55,17 @@ export async function acceptOfferAndLock(
     cryptoGuyAta: PublicKey,
     mint: PublicKey,
     adminSigner: Keypair,
-    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+    cryptoFirst: boolean = false,
+    creatorSigner: Keypair | null = null,
+    vaultAuthority: PublicKey | null = null,
+    payoutDestination: PublicKey | null = null,
+    reference: number[] | null = null,
+    feeDestination: PublicKey | null = null,
+    feeOnTop: boolean = false,
+    cryptoGuySigner: PublicKey | null = null,
+    fiatGuySigner: PublicKey | null = null,
+    allowedTakers: PublicKey[] = []
 ): Promise<{ signature: string; orderPda: PublicKey; vaultPda: PublicKey; ticketPda: PublicKey }> {
     // Derive PDAs
     const orderIdBuf = orderId.toArrayLike(Buffer, "le", 8);
@@ -47,11 +77,8 @@ export async function acceptOfferAndLock(
         [Buffer.from("vault"), orderPda.toBuffer()],
         program.programId
     );
-    const ticketIdBuf = ticketId.toArrayLike(Buffer, "le", 8);
-    const [ticketPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("ticket"), orderPda.toBuffer(), ticketIdBuf],
-        program.programId
-    );
+    // First ticket always sits at index 0 (ticketId is a client reference only)
+    const ticketPda = deriveTicketPda(program.programId, orderPda, new anchor.BN(0));
 
     const signature = await (program.methods as any)
         .acceptOfferAndLock(
@@ -61,11 +88,24 @@ export async function acceptOfferAndLock(
             fiatAmount,
             isSellOrder,
             creator,
-            fiatGuy
+            {
+                fiatGuy,
+                cryptoFirst,
+                vaultAuthority,
+                payoutDestination,
+                reference: reference ?? Array(32).fill(0),
+                feeDestination,
+                feeOnTop,
+                cryptoGuySigner,
+                fiatGuySigner,
+                allowedTakers,
+            }
         )
         .accounts({
             feePayer: adminSigner.publicKey,
             locker: cryptoGuy.publicKey,
+            creatorSigner: creatorSigner ? creatorSigner.publicKey : null,
+            config: deriveConfigPda(program.programId),
             order: orderPda,
             mint: mint,
             vault: vaultPda,
@@ -75,7 +115,139 @@ export async function acceptOfferAndLock(
             associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
             systemProgram: SystemProgram.programId,
         })
-        .signers([adminSigner, cryptoGuy])
+        .signers(creatorSigner ? [adminSigner, cryptoGuy, creatorSigner] : [adminSigner, cryptoGuy])
+        .rpc();
+
+    return { signature, orderPda, vaultPda, ticketPda };
+}
+
+/**
+ * Market-maker ladder: create up to 4 orders (order + vault + ticket each) and lock crypto in one transaction
+ * All orders share creator, fiatGuy, direction, ticketId and the offer options; PDAs go in remaining_accounts
+ *
+ * @param orders - Per-order { orderId, cryptoAmount, fiatAmount }
+ * @param options - Offer options shared by every order, as in acceptOfferAndLock (vaultAuthority must stay unset)
+ * @returns Transaction signature and the created PDAs, in batch order
+ */
+export async function createOrdersBatch(
+    program: anchor.Program<Ddd>,
+    orders: { orderId: anchor.BN; cryptoAmount: anchor.BN; fiatAmount: anchor.BN }[],
+    ticketId: anchor.BN,
+    isSellOrder: boolean,
+    creator: PublicKey,
+    fiatGuy: PublicKey,
+    cryptoGuy: Keypair,
+    cryptoGuyAta: PublicKey,
+    mint: PublicKey,
+    adminSigner: Keypair,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+    cryptoFirst: boolean = false,
+    creatorSigner: Keypair | null = null,
+    options: {
+        vaultAuthority?: PublicKey | null;
+        payoutDestination?: PublicKey | null;
+        reference?: number[] | null;
+        feeDestination?: PublicKey | null;
+        feeOnTop?: boolean;
+        cryptoGuySigner?: PublicKey | null;
+        fiatGuySigner?: PublicKey | null;
+        allowedTakers?: PublicKey[];
+    } = {}
+): Promise<{ signature: string; pdas: { orderPda: PublicKey; vaultPda: PublicKey; ticketPda: PublicKey }[] }> {
+    const pdas = orders.map(o => {
+        const { orderPda, vaultPda } = deriveOrderPdas(program.programId, creator, mint, o.orderId);
+        return { orderPda, vaultPda, ticketPda: deriveTicketPda(program.programId, orderPda, new anchor.BN(0)) };
+    });
+
+    const signature = await (program.methods as any)
+        .createOrdersBatch(
+            orders.map(o => ({ orderId: o.orderId, cryptoAmount: o.cryptoAmount, fiatAmount: o.fiatAmount })),
+            ticketId,
+            isSellOrder,
+            creator,
+            {
+                fiatGuy,
+                cryptoFirst,
+                vaultAuthority: options.vaultAuthority ?? null,
+                payoutDestination: options.payoutDestination ?? null,
+                reference: options.reference ?? Array(32).fill(0),
+                feeDestination: options.feeDestination ?? null,
+                feeOnTop: options.feeOnTop ?? false,
+                cryptoGuySigner: options.cryptoGuySigner ?? null,
+                fiatGuySigner: options.fiatGuySigner ?? null,
+                allowedTakers: options.allowedTakers ?? [],
+            }
+        )
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            locker: cryptoGuy.publicKey,
+            creatorSigner: creatorSigner ? creatorSigner.publicKey : null,
+            config: deriveConfigPda(program.programId),
+            mint: mint,
+            lockerTokenAccount: cryptoGuyAta,
+            tokenProgram: tokenProgram,
+            systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts(pdas.flatMap(p => [p.orderPda, p.vaultPda, p.ticketPda].map(pubkey => ({
+            pubkey, isSigner: false, isWritable: true,
+        }))))
+        .preInstructions([ComputeBudgetProgram.setComputeUnitLimit({ units: 600_000 })])
+        .signers(creatorSigner ? [adminSigner, cryptoGuy, creatorSigner] : [adminSigner, cryptoGuy])
+        .rpc();
+
+    return { signature, pdas };
+}
+
+/**
+ * BUY fast path: accept offer, lock crypto and give the FiatGuy's (creator's) first signature in one instruction
+ * CryptoGuy settles later with signTicket
+ * 
+ * @param program - Anchor program instance
+ * @param orderId - Blockchain order ID (u64, generated by backend)
+ * @param ticketId - Ticket ID (u64)
+ * @param cryptoAmount - Amount of crypto tokens (u64)
+ * @param fiatAmount - Amount of fiat currency (u64)
+ * @param fiatGuy - BUY order creator (co-signs)
+ * @param cryptoGuy - Who locks crypto tokens (seller)
+ * @param cryptoGuyAta - CryptoGuy's token account
+ * @param mint - Token mint (USDC, etc.)
+ * @param adminSigner - Admin keypair (pays rent)
+ * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @returns Transaction signature and created PDAs
+ */
+export async function acceptOfferAndSign(
+    program: anchor.Program<Ddd>,
+    orderId: anchor.BN,
+    ticketId: anchor.BN,
+    cryptoAmount: anchor.BN,
+    fiatAmount: anchor.BN,
+    fiatGuy: Keypair,
+    cryptoGuy: Keypair,
+    cryptoGuyAta: PublicKey,
+    mint: PublicKey,
+    adminSigner: Keypair,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<{ signature: string; orderPda: PublicKey; vaultPda: PublicKey; ticketPda: PublicKey }> {
+    const { orderPda, vaultPda } = deriveOrderPdas(program.programId, fiatGuy.publicKey, mint, orderId);
+    const ticketPda = deriveTicketPda(program.programId, orderPda, new anchor.BN(0));
+
+    const signature = await (program.methods as any)
+        .acceptOfferAndSign(orderId, ticketId, cryptoAmount, fiatAmount, false, fiatGuy.publicKey)
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            locker: cryptoGuy.publicKey,
+            creatorSigner: fiatGuy.publicKey,
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            ticket: ticketPda,
+            lockerTokenAccount: cryptoGuyAta,
+            tokenProgram: tokenProgram,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([adminSigner, cryptoGuy, fiatGuy])
         .rpc();
 
     return { signature, orderPda, vaultPda, ticketPda };
@@ -93,10 +265,13 @@ export async function acceptOfferAndLock(
  * @param mint - Token mint
  * @param vaultPda - Vault PDA
  * @param ticketPda - Ticket PDA
- * @param fiatGuyAta - FiatGuy's token account (receives crypto)
- * @param adminTokenAccount - Admin's token account (receives fee)
+ * @param fiatGuyAta - FiatGuy's token account (receives crypto; null when unwrapping to SOL)
+ * @param adminTokenAccount - Admin's token account (receives fee; null allowed when the fee rounds to 0)
  * @param adminSigner - Admin keypair (pays transaction fee)
  * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @param payoutWallet - Wallet receiving native SOL on a WSOL ticket with unwrap_to_sol (null = no unwrap)
+ * @param clientNonce - Retry key: resending the same nonce after a success fails with DuplicateRequest (null = none)
+ * @param createReceipt - On the settling signature, write a SettlementReceipt PDA that outlives the ticket
  * @returns Transaction signature
  */
 export async function signTicket(
@@ -106,29 +281,118 @@ export async function signTicket(
     mint: PublicKey,
     vaultPda: PublicKey,
     ticketPda: PublicKey,
-    fiatGuyAta: PublicKey,
-    adminTokenAccount: PublicKey,
+    fiatGuyAta: PublicKey | null,
+    adminTokenAccount: PublicKey | null,
     adminSigner: Keypair,
-    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID,
+    payoutWallet: PublicKey | null = null,
+    clientNonce: anchor.BN | null = null,
+    createReceipt: boolean = false
 ): Promise<string> {
     return await (program.methods as any)
-        .signUniversalTicket()
+        .signUniversalTicket(clientNonce, createReceipt)
         .accounts({
             feePayer: adminSigner.publicKey,
             signer: signer.publicKey,
-            adminRentReceiver: adminSigner.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
             order: orderPda,
             mint: mint,
             vault: vaultPda,
             ticket: ticketPda,
             fiatGuyTokenAccount: fiatGuyAta,
             adminFeeAccount: adminTokenAccount,
+            payoutWallet: payoutWallet,
+            unwrapAccount: payoutWallet ? deriveUnwrapPda(program.programId, ticketPda) : null,
+            receipt: createReceipt ? deriveReceiptPda(program.programId, ticketPda) : null,
             tokenProgram: tokenProgram,
+            systemProgram: payoutWallet || createReceipt ? SystemProgram.programId : null,
         })
         .signers([adminSigner, signer])
         .rpc();
 }
 
+/**
+ * Message FiatGuy signs off-chain to authorize a ticket:
+ * ticket pubkey || created_at (i64 LE) || last_sign_ts (i64 LE)
+ * Any sign/unsign moves last_sign_ts, so fetch right before signing
+ */
+export async function ticketAuthorizationMessage(
+    program: anchor.Program<Ddd>,
+    ticketPda: PublicKey
+): Promise<Buffer> {
+    const ticket = await program.account.fillTicket.fetch(ticketPda);
+    return Buffer.concat([
+        ticketPda.toBuffer(),
+        ticket.createdAt.toArrayLike(Buffer, "le", 8),
+        ticket.lastSignTs.toArrayLike(Buffer, "le", 8),
+    ]);
+}
+
+/**
+ * Submit FiatGuy's off-chain ed25519 authorization as their first signature (admin pays)
+ * Prepends the Ed25519 verify instruction the program introspects
+ * 
+ * @param program - Anchor program instance
+ * @param authorizer - Keypair signing the message off-chain (must be the FiatGuy)
+ * @param orderPda - Order PDA
+ * @param ticketPda - Ticket PDA
+ * @param adminSigner - Admin keypair (submits and pays transaction fee)
+ * @param message - Signed message (default: ticketAuthorizationMessage)
+ * @returns Transaction signature
+ */
+export async function signTicketWithEd25519(
+    program: anchor.Program<Ddd>,
+    authorizer: Keypair,
+    orderPda: PublicKey,
+    ticketPda: PublicKey,
+    adminSigner: Keypair,
+    message: Buffer | null = null
+): Promise<string> {
+    const verifyIx = Ed25519Program.createInstructionWithPrivateKey({
+        privateKey: authorizer.secretKey,
+        message: message ?? await ticketAuthorizationMessage(program, ticketPda),
+    });
+    return await (program.methods as any)
+        .signTicketWithEd25519()
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            ticket: ticketPda,
+            instructions: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .preInstructions([verifyIx])
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Withdraw a signature while the other party hasn't signed yet
+ * @param program - Anchor program instance
+ * @param signer - Party whose signature is withdrawn (CryptoGuy or FiatGuy)
+ * @param orderPda - Order PDA
+ * @param ticketPda - Ticket PDA
+ * @returns Transaction signature
+ */
+export async function unsignTicket(
+    program: anchor.Program<Ddd>,
+    signer: Keypair,
+    orderPda: PublicKey,
+    ticketPda: PublicKey
+): Promise<string> {
+    return await (program.methods as any)
+        .unsignTicket()
+        .accounts({
+            signer: signer.publicKey,
+            order: orderPda,
+            ticket: ticketPda,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([signer])
+        .rpc();
+}
+
 /**
  * Cancel a ticket (FiatGuy only, before signing)
  * Refunds tokens to CryptoGuy and auto-closes order + vault
@@ -160,7 +424,8 @@ export async function cancelTicket(
         .accounts({
             feePayer: adminSigner.publicKey,
             canceller: canceller.publicKey,
-            adminRentReceiver: adminSigner.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
             order: orderPda,
             mint: mint,
             vault: vaultPda,
@@ -173,42 +438,858 @@ export async function cancelTicket(
 }
 
 /**
- * Derive Order and Vault PDAs
+ * Withdraw a BUY-order ticket (CryptoGuy only, before FiatGuy signs)
+ * Refunds tokens to CryptoGuy and auto-closes order + vault
+ * 
+ * @param program - Anchor program instance
+ * @param withdrawer - CryptoGuy who funded the ticket
+ * @param orderPda - Order PDA
+ * @param mint - Token mint
+ * @param vaultPda - Vault PDA
+ * @param ticketPda - Ticket PDA
+ * @param cryptoGuyAta - CryptoGuy's token account (receives refund)
+ * @param adminSigner - Admin keypair (pays transaction fee)
+ * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @returns Transaction signature
  */
-export function deriveOrderPdas(
-    programId: PublicKey,
-    creator: PublicKey,
+export async function withdrawTicket(
+    program: anchor.Program<Ddd>,
+    withdrawer: Keypair,
+    orderPda: PublicKey,
     mint: PublicKey,
-    orderId: anchor.BN
-): { orderPda: PublicKey; vaultPda: PublicKey } {
-    const orderSeed = Buffer.from("universal_order");
-    const vaultSeed = Buffer.from("vault");
-    const orderIdBuf = orderId.toArrayLike(Buffer, "le", 8);
+    vaultPda: PublicKey,
+    ticketPda: PublicKey,
+    cryptoGuyAta: PublicKey,
+    adminSigner: Keypair,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .withdrawUniversalTicket()
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            withdrawer: withdrawer.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            ticket: ticketPda,
+            cryptoGuyTokenAccount: cryptoGuyAta,
+            tokenProgram: tokenProgram,
+        })
+        .signers([adminSigner, withdrawer])
+        .rpc();
+}
 
-    const [orderPda] = PublicKey.findProgramAddressSync(
-        [orderSeed, creator.toBuffer(), mint.toBuffer(), orderIdBuf],
-        programId
-    );
+/**
+ * Time out a ticket FiatGuy never signed (CryptoGuy only, after config.fiat_sign_grace_secs)
+ * Refunds tokens to CryptoGuy and auto-closes order + vault
+ * 
+ * @param program - Anchor program instance
+ * @param cryptoGuy - CryptoGuy reclaiming the ticket
+ * @param orderPda - Order PDA
+ * @param mint - Token mint
+ * @param vaultPda - Vault PDA
+ * @param ticketPda - Ticket PDA
+ * @param cryptoGuyAta - CryptoGuy's token account (receives refund)
+ * @param adminSigner - Admin keypair (pays transaction fee)
+ * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @returns Transaction signature
+ */
+export async function signOrTimeout(
+    program: anchor.Program<Ddd>,
+    cryptoGuy: Keypair,
+    orderPda: PublicKey,
+    mint: PublicKey,
+    vaultPda: PublicKey,
+    ticketPda: PublicKey,
+    cryptoGuyAta: PublicKey,
+    adminSigner: Keypair,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .signOrTimeout()
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            signer: cryptoGuy.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            ticket: ticketPda,
+            config: deriveConfigPda(program.programId),
+            cryptoGuyTokenAccount: cryptoGuyAta,
+            tokenProgram: tokenProgram,
+        })
+        .signers([adminSigner, cryptoGuy])
+        .rpc();
+}
 
-    const [vaultPda] = PublicKey.findProgramAddressSync(
-        [vaultSeed, orderPda.toBuffer()],
-        programId
-    );
+/**
+ * Push an unexpired ticket's FiatGuy signing deadline out (FiatGuy or admin only)
+ * Capped per call by config.max_ticket_extension_secs
+ * 
+ * @param program - Anchor program instance
+ * @param signer - FiatGuy or admin keypair
+ * @param orderPda - Order PDA
+ * @param ticketPda - Ticket PDA
+ * @param newExpiresAt - New deadline (unix seconds), later than the current one
+ * @returns Transaction signature
+ */
+export async function extendTicket(
+    program: anchor.Program<Ddd>,
+    signer: Keypair,
+    orderPda: PublicKey,
+    ticketPda: PublicKey,
+    newExpiresAt: anchor.BN
+): Promise<string> {
+    return await (program.methods as any)
+        .extendTicket(newExpiresAt)
+        .accounts({
+            signer: signer.publicKey,
+            order: orderPda,
+            ticket: ticketPda,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([signer])
+        .rpc();
+}
 
-    return { orderPda, vaultPda };
+/**
+ * Receive a WSOL ticket's payout as native SOL (FiatGuy only, WSOL orders only)
+ * Settle afterwards with signTicket(..., payoutWallet)
+ */
+export async function setUnwrapToSol(
+    program: anchor.Program<Ddd>,
+    fiatGuy: Keypair,
+    orderPda: PublicKey,
+    ticketPda: PublicKey,
+    unwrapToSol: boolean
+): Promise<string> {
+    return await (program.methods as any)
+        .setUnwrapToSol(unwrapToSol)
+        .accounts({
+            fiatGuy: fiatGuy.publicKey,
+            order: orderPda,
+            ticket: ticketPda,
+        })
+        .signers([fiatGuy])
+        .rpc();
 }
 
 /**
- * Derive Ticket PDA
+ * Cancel every unsigned ticket of a SELL order in one call (order creator only)
+ * Signed tickets are skipped; refunds go to the creator; auto-closes order + vault when nothing is reserved
+ * 
+ * @param program - Anchor program instance
+ * @param creator - Order creator (SELL maker / CryptoGuy)
+ * @param orderPda - Order PDA
+ * @param mint - Token mint
+ * @param vaultPda - Vault PDA
+ * @param ticketPdas - Tickets to cancel (passed as writable remaining accounts)
+ * @param creatorAta - Creator's token account (receives refunds)
+ * @param adminSigner - Admin keypair (pays transaction fee)
+ * @param tokenProgram - Token program ID (SPL Token or Token-2022)
+ * @returns Transaction signature
  */
-export function deriveTicketPda(
-    programId: PublicKey,
+export async function cancelAllUnsigned(
+    program: anchor.Program<Ddd>,
+    creator: Keypair,
     orderPda: PublicKey,
-    ticketId: anchor.BN
-): PublicKey {
-    const [ticketPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from("ticket"), orderPda.toBuffer(), ticketId.toArrayLike(Buffer, "le", 8)],
-        programId
+    mint: PublicKey,
+    vaultPda: PublicKey,
+    ticketPdas: PublicKey[],
+    creatorAta: PublicKey,
+    adminSigner: Keypair,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .cancelAllUnsigned(ticketPdas.length)
+        .accounts({
+            feePayer: adminSigner.publicKey,
+            creator: creator.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            creatorTokenAccount: creatorAta,
+            tokenProgram: tokenProgram,
+        })
+        .remainingAccounts(ticketPdas.map(t => ({ pubkey: t, isSigner: false, isWritable: true })))
+        .signers([adminSigner, creator])
+        .rpc();
+}
+
+/**
+ * Reassign an unsigned SELL-order ticket to a new FiatGuy (admin only)
+ * 
+ * @param program - Anchor program instance
+ * @param orderPda - Order PDA
+ * @param ticketPda - Ticket PDA
+ * @param newAcceptor - New FiatGuy public key
+ * @param adminSigner - Admin keypair
+ * @returns Transaction signature
+ */
+export async function reassignTicket(
+    program: anchor.Program<Ddd>,
+    orderPda: PublicKey,
+    ticketPda: PublicKey,
+    newAcceptor: PublicKey,
+    adminSigner: Keypair
+): Promise<string> {
+    return await (program.methods as any)
+        .reassignUniversalTicket(newAcceptor)
+        .accounts({
+            admin: adminSigner.publicKey,
+            order: orderPda,
+            ticket: ticketPda,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Split an unsigned ticket into `keepAmount` + a new change ticket (admin only)
+ * 
+ * @param program - Anchor program instance
+ * @param orderPda - Order PDA
+ * @param ticketPda - Ticket PDA to split
+ * @param keepAmount - Amount kept on the original ticket (both halves must be >= crypto_amount / config.min_fill_denominator)
+ * @param newTicketId - Client reference of the change ticket (u64, > 0); its PDA uses the order's next ticket index
+ * @param adminSigner - Admin keypair (pays rent for the change ticket)
+ * @returns Transaction signature and change ticket PDA
+ */
+export async function splitTicket(
+    program: anchor.Program<Ddd>,
+    orderPda: PublicKey,
+    ticketPda: PublicKey,
+    keepAmount: anchor.BN,
+    newTicketId: anchor.BN,
+    adminSigner: Keypair
+): Promise<{ signature: string; newTicketPda: PublicKey }> {
+    const order = await program.account.universalOrder.fetch(orderPda);
+    const newTicketPda = deriveTicketPda(program.programId, orderPda, order.ticketIndex);
+    const signature = await (program.methods as any)
+        .splitUniversalTicket(keepAmount, newTicketId)
+        .accounts({
+            admin: adminSigner.publicKey,
+            order: orderPda,
+            ticket: ticketPda,
+            newTicket: newTicketPda,
+            config: deriveConfigPda(program.programId),
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([adminSigner])
+        .rpc();
+    return { signature, newTicketPda };
+}
+
+/**
+ * Close an order whose vault account no longer exists (admin only)
+ * The program checks `vaultPda` against the order's vault seeds and requires it to be closed
+ * (VaultStillOpen) and the order to have nothing reserved (OrderHasActiveTickets)
+ */
+export async function adminForceCloseOrder(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    orderPda: PublicKey,
+    vaultPda: PublicKey
+): Promise<string> {
+    return await (program.methods as any)
+        .adminForceCloseOrder()
+        .accounts({
+            admin: adminSigner.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            vault: vaultPda,
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Initialize the global Config PDA if it doesn't exist yet (admin only)
+ * 
+ * @param program - Anchor program instance
+ * @param adminSigner - Admin keypair (pays rent)
+ * @returns Config PDA
+ */
+export async function initializeConfig(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair
+): Promise<PublicKey> {
+    const configPda = deriveConfigPda(program.programId);
+    const info = await program.provider.connection.getAccountInfo(configPda);
+    if (info) return configPda;
+
+    await (program.methods as any)
+        .initializeConfig()
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: configPda,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([adminSigner])
+        .rpc();
+
+    return configPda;
+}
+
+/**
+ * Update the minimum interval between ticket signatures (admin only)
+ */
+export async function setFillCooldown(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    fillCooldownSecs: anchor.BN
+): Promise<string> {
+    return await (program.methods as any)
+        .setFillCooldown(fillCooldownSecs)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Update the max fills per order per day window (admin only)
+ */
+export async function setMaxFillsPerDay(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    maxFillsPerDay: number
+): Promise<string> {
+    return await (program.methods as any)
+        .setMaxFillsPerDay(maxFillsPerDay)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Toggle canonical-ATA enforcement for locker token accounts (admin only)
+ */
+export async function setRequireAta(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    requireAta: boolean
+): Promise<string> {
+    return await (program.methods as any)
+        .setRequireAta(requireAta)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Create the per-mint protocol fee vault if it doesn't exist yet (admin only)
+ */
+export async function initializeProtocolFeeVault(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    mint: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<PublicKey> {
+    const feeVaultPda = deriveProtocolFeeVaultPda(program.programId, mint);
+    const info = await program.provider.connection.getAccountInfo(feeVaultPda);
+    if (info) return feeVaultPda;
+
+    await (program.methods as any)
+        .initializeProtocolFeeVault()
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+            mint: mint,
+            protocolFeeVault: feeVaultPda,
+            tokenProgram: tokenProgram,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([adminSigner])
+        .rpc();
+
+    return feeVaultPda;
+}
+
+/**
+ * Recover tokens sent directly to a vault (vault.amount - reserved_amount) to `recipientAta` (admin only)
+ */
+export async function adminRecoverExcess(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    orderPda: PublicKey,
+    mint: PublicKey,
+    vaultPda: PublicKey,
+    recipientAta: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .adminRecoverExcess()
+        .accounts({
+            admin: adminSigner.publicKey,
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            recipientTokenAccount: recipientAta,
+            tokenProgram: tokenProgram,
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Sweep residual vault dust of an idle order into the protocol fee vault and close it (admin only)
+ */
+export async function sweepFees(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    orderPda: PublicKey,
+    mint: PublicKey,
+    vaultPda: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .sweepFees()
+        .accounts({
+            admin: adminSigner.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            mint: mint,
+            vault: vaultPda,
+            protocolFeeVault: deriveProtocolFeeVaultPda(program.programId, mint),
+            tokenProgram: tokenProgram,
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Close a settlement receipt, rent to config.rent_receiver (admin only)
+ */
+export async function closeReceipt(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    receiptPda: PublicKey
+): Promise<string> {
+    return await (program.methods as any)
+        .closeReceipt()
+        .accounts({
+            admin: adminSigner.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            receipt: receiptPda,
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Close a fully settled order that was left open with an empty vault (anyone)
+ * Rent goes to config.rent_receiver, the caller only pays the transaction fee
+ */
+export async function reclaimRent(
+    program: anchor.Program<Ddd>,
+    caller: Keypair,
+    orderPda: PublicKey,
+    vaultPda: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<string> {
+    return await (program.methods as any)
+        .reclaimRent()
+        .accounts({
+            caller: caller.publicKey,
+            adminRentReceiver: await getRentReceiver(program),
+            config: deriveConfigPda(program.programId),
+            order: orderPda,
+            vault: vaultPda,
+            tokenProgram: tokenProgram,
+        })
+        .signers([caller])
+        .rpc();
+}
+
+/**
+ * Set the minimum fiat_amount a new offer must carry, 0 = off (admin only)
+ * Advisory: fiat_amount is reported by the client, the program can't verify it
+ */
+export async function setMinFiatAmount(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    minFiatAmount: anchor.BN
+): Promise<string> {
+    return await (program.methods as any)
+        .setMinFiatAmount(minFiatAmount)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Make order consistency warnings fail the instruction instead of only logging (admin only)
+ */
+export async function setStrictInvariants(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    strictInvariants: boolean
+): Promise<string> {
+    return await (program.methods as any)
+        .setStrictInvariants(strictInvariants)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set the flat fee floor applied on settlements (admin only)
+ */
+export async function setMinFee(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    minFee: anchor.BN
+): Promise<string> {
+    return await (program.methods as any)
+        .setMinFee(minFee)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set the wallet that receives rent from closed tickets/vaults/orders (admin only)
+ */
+export async function setRentReceiver(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    rentReceiver: PublicKey
+): Promise<string> {
+    return await (program.methods as any)
+        .setRentReceiver(rentReceiver)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Toggle the ImmutableOwner requirement for Token-2022 fee accounts (admin only)
+ */
+export async function setRequireImmutableFeeOwner(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    requireImmutableFeeOwner: boolean
+): Promise<string> {
+    return await (program.methods as any)
+        .setRequireImmutableFeeOwner(requireImmutableFeeOwner)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set how long FiatGuy has to sign before CryptoGuy may time a ticket out (admin only)
+ */
+export async function setFiatSignGrace(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    graceSecs: number
+): Promise<string> {
+    return await (program.methods as any)
+        .setFiatSignGrace(new anchor.BN(graceSecs))
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Remaining settlements an order can take in its current daily window (read-only simulation)
+ * @returns { maxFillsPerDay, fillsToday, remainingFills, windowResetsIn }
+ */
+export async function getFillBudget(
+    program: anchor.Program<Ddd>,
+    orderPda: PublicKey
+): Promise<any> {
+    return await (program.methods as any)
+        .fillBudget()
+        .accounts({
+            order: orderPda,
+            config: deriveConfigPda(program.programId),
+        })
+        .view();
+}
+
+/**
+ * Rent returned to the rent receiver when the order closes out (read-only simulation)
+ * @returns { orderRent, vaultRent, ticketRent, total } - total = order + vault + one ticket
+ */
+export async function getRentEstimate(
+    program: anchor.Program<Ddd>,
+    orderPda: PublicKey,
+    vaultPda: PublicKey
+): Promise<any> {
+    return await (program.methods as any)
+        .rentEstimate()
+        .accounts({
+            order: orderPda,
+            vault: vaultPda,
+        })
+        .view();
+}
+
+/**
+ * Set the minimum ticket size as a fraction of its order, 0 = off (admin only)
+ */
+export async function setMinFillDenominator(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    denominator: number
+): Promise<string> {
+    return await (program.methods as any)
+        .setMinFillDenominator(denominator)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set the max distinct acceptors per order, 1..=4 (admin only)
+ */
+export async function setMaxDistinctAcceptors(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    maxDistinctAcceptors: number
+): Promise<string> {
+    return await (program.methods as any)
+        .setMaxDistinctAcceptors(maxDistinctAcceptors)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set the per-call cap for extend_ticket (admin only)
+ */
+export async function setMaxTicketExtension(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    maxExtensionSecs: number
+): Promise<string> {
+    return await (program.methods as any)
+        .setMaxTicketExtension(new anchor.BN(maxExtensionSecs))
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Toggle the creator co-signature requirement for BUY orders (admin only)
+ */
+export async function setRequireCreatorSig(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    requireCreatorSig: boolean
+): Promise<string> {
+    return await (program.methods as any)
+        .setRequireCreatorSig(requireCreatorSig)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Set a per-mint fee bps and fee floor override (admin only)
+ * minFee is in the mint's base units (at most 1 whole token) and replaces the default min_fee for it
+ */
+export async function setMintFee(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    mint: PublicKey,
+    feeBps: number,
+    minFee: anchor.BN = new anchor.BN(0)
+): Promise<string> {
+    return await (program.methods as any)
+        .setMintFee(feeBps, minFee)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+            mint,
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Remove a per-mint fee bps override (admin only)
+ */
+export async function removeMintFee(
+    program: anchor.Program<Ddd>,
+    adminSigner: Keypair,
+    mint: PublicKey
+): Promise<string> {
+    return await (program.methods as any)
+        .removeMintFee(mint)
+        .accounts({
+            admin: adminSigner.publicKey,
+            config: deriveConfigPda(program.programId),
+        })
+        .signers([adminSigner])
+        .rpc();
+}
+
+/**
+ * Derive global Config PDA
+ */
+export function deriveConfigPda(programId: PublicKey): PublicKey {
+    const [configPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        programId
+    );
+    return configPda;
+}
+
+/**
+ * Wallet that receives rent when tickets/vaults/orders close (config.rent_receiver)
+ */
+export async function getRentReceiver(program: anchor.Program<Ddd>): Promise<PublicKey> {
+    const config = await program.account.config.fetch(deriveConfigPda(program.programId));
+    return config.rentReceiver;
+}
+
+/**
+ * Derive per-mint protocol fee vault PDA
+ */
+export function deriveProtocolFeeVaultPda(programId: PublicKey, mint: PublicKey): PublicKey {
+    const [feeVaultPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("protocol_fee_vault"), mint.toBuffer()],
+        programId
+    );
+    return feeVaultPda;
+}
+
+/**
+ * Derive Order and Vault PDAs
+ */
+export function deriveOrderPdas(
+    programId: PublicKey,
+    creator: PublicKey,
+    mint: PublicKey,
+    orderId: anchor.BN
+): { orderPda: PublicKey; vaultPda: PublicKey } {
+    const orderSeed = Buffer.from("universal_order");
+    const vaultSeed = Buffer.from("vault");
+    const orderIdBuf = orderId.toArrayLike(Buffer, "le", 8);
+
+    const [orderPda] = PublicKey.findProgramAddressSync(
+        [orderSeed, creator.toBuffer(), mint.toBuffer(), orderIdBuf],
+        programId
+    );
+
+    const [vaultPda] = PublicKey.findProgramAddressSync(
+        [vaultSeed, orderPda.toBuffer()],
+        programId
+    );
+
+    return { orderPda, vaultPda };
+}
+
+/**
+ * Derive Ticket PDA from its sequential per-order index (0 = first ticket)
+ */
+export function deriveTicketPda(
+    programId: PublicKey,
+    orderPda: PublicKey,
+    index: anchor.BN
+): PublicKey {
+    const [ticketPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("ticket"), orderPda.toBuffer(), index.toArrayLike(Buffer, "le", 8)],
+        programId
     );
     return ticketPda;
 }
+
+/**
+ * Derive the temporary WSOL account PDA used to unwrap a ticket's settlement
+ */
+export function deriveUnwrapPda(programId: PublicKey, ticketPda: PublicKey): PublicKey {
+    const [unwrapPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("unwrap"), ticketPda.toBuffer()],
+        programId
+    );
+    return unwrapPda;
+}
+
+/**
+ * Derive the SettlementReceipt PDA of a ticket
+ */
+export function deriveReceiptPda(programId: PublicKey, ticketPda: PublicKey): PublicKey {
+    const [receiptPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from("receipt"), ticketPda.toBuffer()],
+        programId
+    );
+    return receiptPda;
+}
+
+/**
+ * Enumerate an order's ticket PDAs without a getProgramAccounts scan: [0, order.ticketIndex)
+ * Closed tickets (settled / cancelled) are included - check getAccountInfo for null
+ */
+export async function deriveOrderTicketPdas(
+    program: anchor.Program<Ddd>,
+    orderPda: PublicKey
+): Promise<PublicKey[]> {
+    const order = await program.account.universalOrder.fetch(orderPda);
+    const pdas: PublicKey[] = [];
+    for (let i = 0; i < order.ticketIndex.toNumber(); i++) {
+        pdas.push(deriveTicketPda(program.programId, orderPda, new anchor.BN(i)));
+    }
+    return pdas;
+}
+
+/**
+ * True if a transaction failed because the order PDA no longer exists (auto-closed or never created)
+ * Anchor rejects the account before the instruction runs, so the program can't return
+ * UniversalOrderError::OrderNotFound itself - clients map AccountNotInitialized (3012) on `order` to it
+ */
+export function isOrderNotFoundError(e: any): boolean {
+    const message: string = e?.message ?? "";
+    return message.includes("AccountNotInitialized") && message.includes("caused by account: order.");
+}
@@ -25,6 +25,8 @@ import {
     getMintLen,
     ASSOCIATED_TOKEN_PROGRAM_ID,
     createMintToInstruction,
+    createInitializeTransferFeeConfigInstruction,
+    ExtensionType,
 } from "@solana/spl-token";
 
 // Завантажуємо змінні середовища з .env файлу
@@ -303,6 +305,60 @@ export async function createToken2022(
     };
 }
 
+/**
+ * Створює Token-2022 з розширенням TransferFeeConfig (комісія з кожного переказу)
+ */
+export async function createToken2022WithTransferFee(
+    connection: Connection,
+    payer: Keypair,
+    feeBasisPoints: number,
+    maxFee: bigint,
+    decimals: number = 6
+): Promise<TestToken> {
+    const mintAuthority = Keypair.generate();
+    const mintKeypair = Keypair.generate();
+    const mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await connection.getMinimumBalanceForRentExemption(mintLen);
+
+    const transaction = new Transaction().add(
+        SystemProgram.createAccount({
+            fromPubkey: payer.publicKey,
+            newAccountPubkey: mintKeypair.publicKey,
+            space: mintLen,
+            lamports,
+            programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+            mintKeypair.publicKey,
+            mintAuthority.publicKey,
+            mintAuthority.publicKey,
+            feeBasisPoints,
+            maxFee,
+            TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+            mintKeypair.publicKey,
+            decimals,
+            mintAuthority.publicKey,
+            null,
+            TOKEN_2022_PROGRAM_ID
+        )
+    );
+
+    await connection.sendTransaction(transaction, [payer, mintKeypair]);
+    await sleep(1000);
+
+    console.log("✅ Token-2022 with transfer fee created:", mintKeypair.publicKey.toBase58());
+
+    return {
+        mint: mintKeypair.publicKey,
+        mintAuthority,
+        decimals,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+        isToken2022: true,
+    };
+}
+
 /**
  * Wrapper для створення SPL Token з однаковим інтерфейсом
  */